@@ -0,0 +1,166 @@
+//! Answers confirmed on the cached puzzle inputs, later runs are checked
+//! against them
+
+use core::fmt;
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{Display, Formatter},
+    fs,
+    io::ErrorKind,
+    path::Path,
+};
+
+/// Name of the answers file, in the input cache directory
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: &str) {
+        self.answers.insert((day, part), answer.to_owned());
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    /// Check an answer against the confirmed one, an answer that wasn't
+    /// confirmed yet is recorded with `confirm`
+    pub fn check(
+        &mut self,
+        day: u8,
+        part: u8,
+        answer: String,
+        confirm: bool,
+    ) -> Result<String, String> {
+        match self.get(day, part) {
+            Some(confirmed) if confirmed != answer => Err(format!(
+                "answer `{}` differs from the confirmed one, `{}`",
+                answer, confirmed
+            )),
+            Some(_) => Ok(answer),
+            None => {
+                if confirm {
+                    self.insert(day, part, &answer);
+                }
+                Ok(answer)
+            }
+        }
+    }
+
+    /// Load answers saved by [`Answers::save`], none if the file doesn't exist
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::new()),
+            Err(e) => return Err(format!("{}: {}", path.display(), e).into()),
+        };
+        Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_string()).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    // One `day part answer` line per answer, `#` starts a comment
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = Self::new();
+
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let err = || format!("line {}: expected `<day> <part> <answer>`", idx + 1);
+
+            let mut words = line.splitn(3, ' ');
+            let (Some(day), Some(part), Some(answer)) = (words.next(), words.next(), words.next())
+            else {
+                return Err(err());
+            };
+
+            let day = day.parse().map_err(|_| err())?;
+            let part = part.parse().map_err(|_| err())?;
+            answers.insert(day, part, answer.trim());
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# Confirmed answers of the cached inputs: day, part, answer"
+        )?;
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{} {} {}", day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_parse_test() {
+        let mut answers = Answers::new();
+        answers.insert(17, 1, "4,6,3,5");
+        answers.insert(2, 2, "612");
+        answers.insert(2, 1, "564");
+
+        let text = answers.to_string();
+        let exp = "# Confirmed answers of the cached inputs: day, part, answer\n\
+                   2 1 564\n2 2 612\n17 1 4,6,3,5\n";
+        assert_eq!(text, exp);
+        assert_eq!(Answers::parse(&text), Ok(answers));
+
+        assert!(Answers::parse("2 1").is_err());
+        assert!(Answers::parse("two 1 564").is_err());
+    }
+
+    #[test]
+    fn check_test() {
+        let mut answers = Answers::new();
+        answers.insert(5, 1, "143");
+
+        let tests = [
+            ((5, 1, "143", false), Ok("143")),
+            (
+                (5, 1, "144", true),
+                Err("answer `144` differs from the confirmed one, `143`"),
+            ),
+            ((5, 2, "123", false), Ok("123")),
+            ((5, 2, "123", true), Ok("123")),
+            (
+                (5, 2, "124", false),
+                Err("answer `124` differs from the confirmed one, `123`"),
+            ),
+        ];
+
+        for ((day, part, answer, confirm), exp) in tests {
+            let res = answers.check(day, part, answer.to_owned(), confirm);
+            assert_eq!(res, exp.map(str::to_owned).map_err(str::to_owned));
+        }
+        assert_eq!(answers.len(), 2);
+    }
+}
@@ -0,0 +1,980 @@
+use common::{
+    diag::{Crop, RecordSettings},
+    input::Source,
+    Params,
+};
+use core::fmt;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    ops::RangeInclusive,
+};
+
+pub const USAGE: &str =
+    "Usage: aoc <DAYS> [--part <PART>] [--input <PATH>] [--param <NAME=VALUE>]...
+       aoc verify [--inputs-dir <DIR>] [--threads <N>] [--confirm]
+
+Arguments:
+  <DAYS>                     Day to run (e.g. 5) or inclusive range of days (e.g. 1-20)
+  verify                     Run both parts of every day from the cache and print
+                             a table comparing them with the confirmed answers
+
+Options:
+  -p, --part <PART>          Only run part 1 or 2 (default: both)
+  -i, --input <SOURCE>       Input file, `-` for stdin (default: -), or a day number
+                             for that day's cached input, e.g. `5` reads
+                             `inputs/day05.txt`
+                             When running multiple days, `{day}` in the input is
+                             replaced by the day number, `-i {day}` reads all of
+                             them from the cache
+      --inputs-dir <DIR>     Directory of cached inputs (default: $AOC_INPUTS,
+                             else `inputs`)
+  -P, --param <NAME=VALUE>   Override a puzzle parameter of a single day, e.g.
+                             `-P width=11 -P height=7` for the day 14 example
+  -j, --threads <N>          Spread the work of days 6, 7, 13, 19 and 20 over N
+                             threads (default: 1), answers don't change
+  -b, --bench <RUNS>         Time parsing and each part over RUNS runs instead of
+                             printing answers, reporting medians and deviations
+      --baseline <PATH>      Compare benchmark medians with a saved baseline
+      --save-baseline <PATH> Save benchmark medians as a baseline
+      --json                 Print one JSON object per part, with the day, part,
+                             answer or error, time in nanoseconds and warnings
+  -v, --verbose              Print debug renderings of solvers to stderr
+      --images <DIR>         Save images rendered by solvers to DIR, as SVG and PNG
+      --record <DIR>         Save the steps of simulations to DIR, as animated GIF
+                             and asciicast files
+      --stride <N>           Only record every Nth step (default: 1)
+      --crop <X,Y,W,H>       Only record a region of the grid, in cells
+  -s, --step                 Step through the simulation of a day's part (default:
+                             part 1) or debug the day 17 program interactively,
+                             reading commands from stdin
+  -g, --generate <SEED>      Print a random input for the day generated from SEED
+                             instead of solving it, `-P` sets its size
+      --disassemble          Print the day 17 input program as assembly, with the
+                             pseudo-code of each instruction
+      --assemble             Read a day 17 program as assembly from the input and
+                             print it as a puzzle input, registers are set with
+                             `.reg A 729` lines
+      --confirm              Record answers of cached inputs in the answers file
+                             of the cache when none was confirmed yet, later runs
+                             fail on a different answer
+  -h, --help                 Print this help";
+
+/// Placeholder replaced by the day number in input paths
+pub const DAY_PLACEHOLDER: &str = "{day}";
+
+#[derive(Debug, PartialEq)]
+pub enum ArgsError {
+    MissingDays,
+    InvalidDays(String),
+    InvalidPart(String),
+    MissingValue(String),
+    UnexpectedArgument(String),
+    InvalidParam(String),
+    InvalidRuns(String),
+    BaselineWithoutBench,
+    JsonBench,
+    StdinMultipleDays,
+    ParamsMultipleDays,
+    MissingPlaceholder,
+    InvalidStride(String),
+    InvalidCrop(String),
+    RecordOptionsWithoutRecord,
+    StepMultipleDays,
+    StepStdin,
+    InvalidThreads(String),
+    InvalidSeed(String),
+    GenerateMultipleDays,
+    DisassembleMultipleDays,
+    AssembleMultipleDays,
+    ConfirmNotCached,
+    ConfirmBench,
+    ConfirmParams,
+    VerifyOptions,
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDays => write!(f, "missing days to run"),
+            Self::InvalidDays(days) => write!(f, "invalid days: `{}`", days),
+            Self::InvalidPart(part) => write!(f, "invalid part: `{}`", part),
+            Self::MissingValue(opt) => write!(f, "missing value for `{}`", opt),
+            Self::UnexpectedArgument(arg) => write!(f, "unexpected argument: `{}`", arg),
+            Self::InvalidParam(param) => {
+                write!(f, "invalid parameter: `{}`, expected `NAME=VALUE`", param)
+            }
+            Self::InvalidRuns(runs) => write!(f, "invalid number of runs: `{}`", runs),
+            Self::BaselineWithoutBench => write!(f, "baselines can only be used with `--bench`"),
+            Self::JsonBench => write!(f, "`--json` can't be combined with `--bench`"),
+            Self::StdinMultipleDays => {
+                write!(f, "can't read input from stdin when running multiple days")
+            }
+            Self::ParamsMultipleDays => {
+                write!(f, "parameters can only be set when running a single day")
+            }
+            Self::MissingPlaceholder => write!(
+                f,
+                "input path must contain `{}` when running multiple days",
+                DAY_PLACEHOLDER
+            ),
+            Self::InvalidStride(stride) => write!(f, "invalid stride: `{}`", stride),
+            Self::InvalidCrop(crop) => {
+                write!(f, "invalid crop region: `{}`, expected `X,Y,W,H`", crop)
+            }
+            Self::RecordOptionsWithoutRecord => {
+                write!(
+                    f,
+                    "`--stride` and `--crop` can only be used with `--record`"
+                )
+            }
+            Self::StepMultipleDays => write!(f, "`--step` can only be used with a single day"),
+            Self::InvalidThreads(threads) => write!(f, "invalid number of threads: `{}`", threads),
+            Self::StepStdin => write!(f, "`--step` reads commands from stdin, use `--input`"),
+            Self::InvalidSeed(seed) => write!(f, "invalid seed: `{}`", seed),
+            Self::GenerateMultipleDays => {
+                write!(f, "`--generate` can only be used with a single day")
+            }
+            Self::DisassembleMultipleDays => {
+                write!(f, "`--disassemble` can only be used with a single day")
+            }
+            Self::AssembleMultipleDays => {
+                write!(f, "`--assemble` can only be used with a single day")
+            }
+            Self::ConfirmNotCached => write!(
+                f,
+                "`--confirm` only records answers of a day's own cached input, use `--input <DAY>`"
+            ),
+            Self::ConfirmBench => write!(f, "`--confirm` can't be combined with `--bench`"),
+            Self::ConfirmParams => write!(
+                f,
+                "`--confirm` only records answers with the puzzle parameters, remove `-P`"
+            ),
+            Self::VerifyOptions => write!(
+                f,
+                "`verify` only takes `--inputs-dir`, `--threads` and `--confirm`"
+            ),
+        }
+    }
+}
+
+impl Error for ArgsError {}
+
+/// Where the puzzle input is read from
+#[derive(Debug, PartialEq)]
+pub enum Input {
+    Stdin,
+    Path(String),
+}
+
+impl Input {
+    /// Get where the input of a day is read from
+    pub fn source_for(&self, day: u8) -> Source {
+        match self {
+            Self::Stdin => Source::Stdin,
+            Self::Path(path) => Source::parse(&path.replace(DAY_PLACEHOLDER, &day.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub days: RangeInclusive<u8>,
+    pub part: Option<u8>,
+    pub input: Input,
+    // Directory of cached inputs, if not the default one
+    pub inputs_dir: Option<String>,
+    pub params: Params,
+    pub threads: usize,
+    pub bench: Option<Bench>,
+    pub json: bool,
+    pub verbose: bool,
+    pub images: Option<String>,
+    pub record: Option<Record>,
+    pub step: bool,
+    // Seed to generate an input from
+    pub generate: Option<u64>,
+    pub disassemble: bool,
+    pub assemble: bool,
+    // Record answers that weren't confirmed yet
+    pub confirm: bool,
+    // Compare every part with its confirmed answer
+    pub verify: bool,
+}
+
+impl Args {
+    /// Parts to run for every day
+    pub fn parts(&self) -> RangeInclusive<u8> {
+        match self.part {
+            Some(part) => part..=part,
+            None => 1..=2,
+        }
+    }
+
+    /// Check a day's answers against the confirmed ones, only those of the
+    /// day's own cached input solved with the puzzle parameters were confirmed
+    pub fn checks_answers(&self, day: u8) -> bool {
+        let cached = self.input.source_for(day) == Source::Day(day);
+        cached && self.params.is_empty() && self.bench.is_none()
+    }
+}
+
+/// Benchmark settings
+#[derive(Debug, PartialEq)]
+pub struct Bench {
+    pub runs: usize,
+    // Baseline to compare with
+    pub baseline: Option<String>,
+    // Where to save the new baseline
+    pub save_baseline: Option<String>,
+}
+
+/// Recording settings
+#[derive(Debug, PartialEq)]
+pub struct Record {
+    // Where animations are saved
+    pub dir: String,
+    pub settings: RecordSettings,
+}
+
+/// Parse command line arguments, `None` if help was requested
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Args>, ArgsError> {
+    let mut days = None;
+    let mut part = None;
+    let mut input = Input::Stdin;
+    let mut inputs_dir = None;
+    let mut params = Params::new();
+    let mut threads = 1;
+    let mut runs = None;
+    let mut baseline = None;
+    let mut save_baseline = None;
+    let mut json = false;
+    let mut verbose = false;
+    let mut images = None;
+    let mut record = None;
+    let mut stride = None;
+    let mut crop = None;
+    let mut step = false;
+    let mut generate = None;
+    let mut disassemble = false;
+    let mut assemble = false;
+    let mut confirm = false;
+    let mut verify = false;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-p" | "--part" => {
+                let val = args.next().ok_or(ArgsError::MissingValue(arg))?;
+                part = Some(parse_part(&val)?);
+            }
+            "-i" | "--input" => {
+                let val = args.next().ok_or(ArgsError::MissingValue(arg))?;
+                input = if val == "-" {
+                    Input::Stdin
+                } else {
+                    Input::Path(val)
+                };
+            }
+            "--inputs-dir" => inputs_dir = Some(args.next().ok_or(ArgsError::MissingValue(arg))?),
+            "-P" | "--param" => {
+                let val = args.next().ok_or(ArgsError::MissingValue(arg))?;
+                match val.split_once('=') {
+                    Some((name, value)) if !name.is_empty() => params.insert(name, value),
+                    _ => return Err(ArgsError::InvalidParam(val)),
+                }
+            }
+            "-j" | "--threads" => {
+                let val = args.next().ok_or(ArgsError::MissingValue(arg))?;
+                match val.parse() {
+                    Ok(n) if n > 0 => threads = n,
+                    _ => return Err(ArgsError::InvalidThreads(val)),
+                }
+            }
+            "-b" | "--bench" => {
+                let val = args.next().ok_or(ArgsError::MissingValue(arg))?;
+                match val.parse() {
+                    Ok(n) if n > 0 => runs = Some(n),
+                    _ => return Err(ArgsError::InvalidRuns(val)),
+                }
+            }
+            "--baseline" => baseline = Some(args.next().ok_or(ArgsError::MissingValue(arg))?),
+            "--save-baseline" => {
+                save_baseline = Some(args.next().ok_or(ArgsError::MissingValue(arg))?)
+            }
+            "--json" => json = true,
+            "-v" | "--verbose" => verbose = true,
+            "--images" => images = Some(args.next().ok_or(ArgsError::MissingValue(arg))?),
+            "--record" => record = Some(args.next().ok_or(ArgsError::MissingValue(arg))?),
+            "--stride" => {
+                let val = args.next().ok_or(ArgsError::MissingValue(arg))?;
+                match val.parse() {
+                    Ok(n) if n > 0 => stride = Some(n),
+                    _ => return Err(ArgsError::InvalidStride(val)),
+                }
+            }
+            "--crop" => {
+                let val = args.next().ok_or(ArgsError::MissingValue(arg))?;
+                crop = Some(parse_crop(&val)?);
+            }
+            "-s" | "--step" => step = true,
+            "-g" | "--generate" => {
+                let val = args.next().ok_or(ArgsError::MissingValue(arg))?;
+                match val.parse() {
+                    Ok(seed) => generate = Some(seed),
+                    Err(_) => return Err(ArgsError::InvalidSeed(val)),
+                }
+            }
+            "--disassemble" => disassemble = true,
+            "--assemble" => assemble = true,
+            "--confirm" => confirm = true,
+            "verify" if days.is_none() => {
+                verify = true;
+                days = Some(1..=20);
+            }
+            _ if days.is_none() && !arg.starts_with('-') => days = Some(parse_days(&arg)?),
+            _ => return Err(ArgsError::UnexpectedArgument(arg)),
+        }
+    }
+
+    let days = days.ok_or(ArgsError::MissingDays)?;
+
+    // Verifying runs everything from the cache as is
+    if verify {
+        let recording = record.is_some() || stride.is_some() || crop.is_some();
+        let benchmarking = runs.is_some() || baseline.is_some() || save_baseline.is_some();
+        if input != Input::Stdin
+            || part.is_some()
+            || !params.is_empty()
+            || benchmarking
+            || json
+            || verbose
+            || images.is_some()
+            || recording
+            || step
+            || generate.is_some()
+            || disassemble
+            || assemble
+        {
+            return Err(ArgsError::VerifyOptions);
+        }
+        input = Input::Path(DAY_PLACEHOLDER.to_owned());
+    }
+
+    // A single input can't be shared by multiple days
+    if days.start() != days.end() {
+        match &input {
+            Input::Stdin => return Err(ArgsError::StdinMultipleDays),
+            Input::Path(path) if !path.contains(DAY_PLACEHOLDER) => {
+                return Err(ArgsError::MissingPlaceholder)
+            }
+            _ => {}
+        }
+
+        // Parameters are specific to a day
+        if !params.is_empty() {
+            return Err(ArgsError::ParamsMultipleDays);
+        }
+    }
+
+    let bench = match runs {
+        Some(runs) => Some(Bench {
+            runs,
+            baseline,
+            save_baseline,
+        }),
+        None if baseline.is_some() || save_baseline.is_some() => {
+            return Err(ArgsError::BaselineWithoutBench)
+        }
+        None => None,
+    };
+
+    if json && bench.is_some() {
+        return Err(ArgsError::JsonBench);
+    }
+
+    if step {
+        if days.start() != days.end() {
+            return Err(ArgsError::StepMultipleDays);
+        }
+        if input == Input::Stdin {
+            return Err(ArgsError::StepStdin);
+        }
+    }
+
+    if generate.is_some() && days.start() != days.end() {
+        return Err(ArgsError::GenerateMultipleDays);
+    }
+
+    if disassemble && days.start() != days.end() {
+        return Err(ArgsError::DisassembleMultipleDays);
+    }
+
+    if assemble && days.start() != days.end() {
+        return Err(ArgsError::AssembleMultipleDays);
+    }
+
+    if confirm {
+        // Answers are recorded for the day run, from its own cached input
+        if days
+            .clone()
+            .any(|day| input.source_for(day) != Source::Day(day))
+        {
+            return Err(ArgsError::ConfirmNotCached);
+        }
+        if bench.is_some() {
+            return Err(ArgsError::ConfirmBench);
+        }
+        if !params.is_empty() {
+            return Err(ArgsError::ConfirmParams);
+        }
+    }
+
+    let record = match record {
+        Some(dir) => Some(Record {
+            dir,
+            settings: RecordSettings {
+                stride: stride.unwrap_or(1),
+                crop,
+            },
+        }),
+        None if stride.is_some() || crop.is_some() => {
+            return Err(ArgsError::RecordOptionsWithoutRecord)
+        }
+        None => None,
+    };
+
+    Ok(Some(Args {
+        days,
+        part,
+        input,
+        inputs_dir,
+        params,
+        threads,
+        bench,
+        json,
+        verbose,
+        images,
+        record,
+        step,
+        generate,
+        disassemble,
+        assemble,
+        confirm,
+        verify,
+    }))
+}
+
+/// Parse a single day or an inclusive range of days
+fn parse_days(val: &str) -> Result<RangeInclusive<u8>, ArgsError> {
+    let err = || ArgsError::InvalidDays(val.to_owned());
+
+    let (start, end) = match val.split_once('-') {
+        Some((start, end)) => (start, end),
+        None => (val, val),
+    };
+
+    let start: u8 = start.parse().map_err(|_| err())?;
+    let end: u8 = end.parse().map_err(|_| err())?;
+
+    if start == 0 || start > end {
+        return Err(err());
+    }
+
+    Ok(start..=end)
+}
+
+fn parse_part(val: &str) -> Result<u8, ArgsError> {
+    match val {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(ArgsError::InvalidPart(val.to_owned())),
+    }
+}
+
+/// Parse a crop region given as `X,Y,W,H`
+fn parse_crop(val: &str) -> Result<Crop, ArgsError> {
+    let err = || ArgsError::InvalidCrop(val.to_owned());
+
+    let nums: Vec<usize> = val
+        .split(',')
+        .map(|num| num.trim().parse().map_err(|_| err()))
+        .collect::<Result<_, _>>()?;
+
+    match nums[..] {
+        [x, y, width, height] if width > 0 && height > 0 => Ok(Crop {
+            x,
+            y,
+            width,
+            height,
+        }),
+        _ => Err(err()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> Result<Option<Args>, ArgsError> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parse_days_test() {
+        let tests = [
+            ("5", Ok(5..=5)),
+            ("1-20", Ok(1..=20)),
+            ("3-3", Ok(3..=3)),
+            ("0", Err(ArgsError::InvalidDays("0".to_owned()))),
+            ("7-2", Err(ArgsError::InvalidDays("7-2".to_owned()))),
+            ("a-2", Err(ArgsError::InvalidDays("a-2".to_owned()))),
+        ];
+
+        for (input, exp) in tests {
+            assert_eq!(parse_days(input), exp);
+        }
+    }
+
+    #[test]
+    fn parse_args_test() {
+        let tests = [
+            (
+                "5",
+                Ok(Some(Args {
+                    days: 5..=5,
+                    part: None,
+                    input: Input::Stdin,
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "5 -p 2 --input day5.txt",
+                Ok(Some(Args {
+                    days: 5..=5,
+                    part: Some(2),
+                    input: Input::Path("day5.txt".to_owned()),
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "1-3 -i inputs/{day}.txt",
+                Ok(Some(Args {
+                    days: 1..=3,
+                    part: None,
+                    input: Input::Path("inputs/{day}.txt".to_owned()),
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "14 -P width=11 --param height=7",
+                Ok(Some(Args {
+                    days: 14..=14,
+                    part: None,
+                    input: Input::Stdin,
+                    inputs_dir: None,
+                    params: {
+                        let mut params = Params::new();
+                        params.insert("width", "11");
+                        params.insert("height", "7");
+                        params
+                    },
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "1-20 -i {day} --inputs-dir cache",
+                Ok(Some(Args {
+                    days: 1..=20,
+                    part: None,
+                    input: Input::Path("{day}".to_owned()),
+                    inputs_dir: Some("cache".to_owned()),
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "1-20 -i {day}.txt -b 10 --save-baseline base.txt",
+                Ok(Some(Args {
+                    days: 1..=20,
+                    part: None,
+                    input: Input::Path("{day}.txt".to_owned()),
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: Some(Bench {
+                        runs: 10,
+                        baseline: None,
+                        save_baseline: Some("base.txt".to_owned()),
+                    }),
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "18 --json -v --images out -j 4",
+                Ok(Some(Args {
+                    days: 18..=18,
+                    part: None,
+                    input: Input::Stdin,
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 4,
+                    bench: None,
+                    json: true,
+                    verbose: true,
+                    images: Some("out".to_owned()),
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "6 --record out --stride 10 --crop 0,5,20,10",
+                Ok(Some(Args {
+                    days: 6..=6,
+                    part: None,
+                    input: Input::Stdin,
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: Some(Record {
+                        dir: "out".to_owned(),
+                        settings: RecordSettings {
+                            stride: 10,
+                            crop: Some(Crop {
+                                x: 0,
+                                y: 5,
+                                width: 20,
+                                height: 10,
+                            }),
+                        },
+                    }),
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            ("5 --help", Ok(None)),
+            ("", Err(ArgsError::MissingDays)),
+            ("5 -p 3", Err(ArgsError::InvalidPart("3".to_owned()))),
+            ("5 -p", Err(ArgsError::MissingValue("-p".to_owned()))),
+            ("5 6", Err(ArgsError::UnexpectedArgument("6".to_owned()))),
+            ("1-3", Err(ArgsError::StdinMultipleDays)),
+            ("1-3 -i input.txt", Err(ArgsError::MissingPlaceholder)),
+            (
+                "14 -P width",
+                Err(ArgsError::InvalidParam("width".to_owned())),
+            ),
+            ("14 -P =7", Err(ArgsError::InvalidParam("=7".to_owned()))),
+            ("5 -b 0", Err(ArgsError::InvalidRuns("0".to_owned()))),
+            (
+                "5 --baseline base.txt",
+                Err(ArgsError::BaselineWithoutBench),
+            ),
+            (
+                "1-3 -i {day}.txt -P width=11",
+                Err(ArgsError::ParamsMultipleDays),
+            ),
+            (
+                "15 -p 2 -i day15.txt --step",
+                Ok(Some(Args {
+                    days: 15..=15,
+                    part: Some(2),
+                    input: Input::Path("day15.txt".to_owned()),
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: true,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            ("6 --step", Err(ArgsError::StepStdin)),
+            (
+                "20 -g 7",
+                Ok(Some(Args {
+                    days: 20..=20,
+                    part: None,
+                    input: Input::Stdin,
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: Some(7),
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "20 --generate x",
+                Err(ArgsError::InvalidSeed("x".to_owned())),
+            ),
+            (
+                "1-3 -i {day}.txt -g 1",
+                Err(ArgsError::GenerateMultipleDays),
+            ),
+            (
+                "5 -i 5 --confirm",
+                Ok(Some(Args {
+                    days: 5..=5,
+                    part: None,
+                    input: Input::Path("5".to_owned()),
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: true,
+                    verify: false,
+                })),
+            ),
+            (
+                "verify -j 4 --inputs-dir cache",
+                Ok(Some(Args {
+                    days: 1..=20,
+                    part: None,
+                    input: Input::Path("{day}".to_owned()),
+                    inputs_dir: Some("cache".to_owned()),
+                    params: Params::new(),
+                    threads: 4,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: false,
+                    confirm: false,
+                    verify: true,
+                })),
+            ),
+            (
+                "17 -i 17 --disassemble",
+                Ok(Some(Args {
+                    days: 17..=17,
+                    part: None,
+                    input: Input::Path("17".to_owned()),
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: true,
+                    assemble: false,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "17 -i prog.asm --assemble",
+                Ok(Some(Args {
+                    days: 17..=17,
+                    part: None,
+                    input: Input::Path("prog.asm".to_owned()),
+                    inputs_dir: None,
+                    params: Params::new(),
+                    threads: 1,
+                    bench: None,
+                    json: false,
+                    verbose: false,
+                    images: None,
+                    record: None,
+                    step: false,
+                    generate: None,
+                    disassemble: false,
+                    assemble: true,
+                    confirm: false,
+                    verify: false,
+                })),
+            ),
+            (
+                "1-3 -i {day} --assemble",
+                Err(ArgsError::AssembleMultipleDays),
+            ),
+            (
+                "1-3 -i {day} --disassemble",
+                Err(ArgsError::DisassembleMultipleDays),
+            ),
+            ("5 -i day5.txt --confirm", Err(ArgsError::ConfirmNotCached)),
+            ("5 --confirm", Err(ArgsError::ConfirmNotCached)),
+            ("5 -i 17 --confirm", Err(ArgsError::ConfirmNotCached)),
+            ("5 -i 5 -b 3 --confirm", Err(ArgsError::ConfirmBench)),
+            (
+                "11 -i 11 -P blinks1=10 --confirm",
+                Err(ArgsError::ConfirmParams),
+            ),
+            ("verify -p 1", Err(ArgsError::VerifyOptions)),
+            ("verify -i {day}.txt", Err(ArgsError::VerifyOptions)),
+            (
+                "verify 5",
+                Err(ArgsError::UnexpectedArgument("5".to_owned())),
+            ),
+            ("7 -j 0", Err(ArgsError::InvalidThreads("0".to_owned()))),
+            ("1-6 -i {day}.txt -s", Err(ArgsError::StepMultipleDays)),
+            ("6 --stride 5", Err(ArgsError::RecordOptionsWithoutRecord)),
+            (
+                "6 --record out --stride 0",
+                Err(ArgsError::InvalidStride("0".to_owned())),
+            ),
+            (
+                "6 --record out --crop 1,2,3",
+                Err(ArgsError::InvalidCrop("1,2,3".to_owned())),
+            ),
+            (
+                "6 --record out --crop 1,2,0,4",
+                Err(ArgsError::InvalidCrop("1,2,0,4".to_owned())),
+            ),
+        ];
+
+        for (input, exp) in tests {
+            assert_eq!(args(input), exp);
+        }
+    }
+
+    #[test]
+    fn checks_answers_test() {
+        let tests = [
+            ("11 -i 11", true),
+            ("1-20 -i {day}", true),
+            ("11 -i day11.txt", false),
+            ("11", false),
+            ("5 -i 17", false),
+            ("11 -i 11 -P blinks1=10", false),
+            ("11 -i 11 -b 5", false),
+        ];
+
+        for (input, exp) in tests {
+            let args = args(input).unwrap().unwrap();
+            let res = args.days.clone().all(|day| args.checks_answers(day));
+            assert_eq!(res, exp, "{}", input);
+        }
+    }
+
+    #[test]
+    fn source_for_test() {
+        let tests = [
+            (Input::Stdin, Source::Stdin),
+            (Input::Path("{day}".to_owned()), Source::Day(7)),
+            (Input::Path("-".to_owned()), Source::Stdin),
+            (
+                Input::Path("ex/{day}.txt".to_owned()),
+                Source::Path("ex/7.txt".into()),
+            ),
+        ];
+
+        for (input, exp) in tests {
+            assert_eq!(input.source_for(7), exp);
+        }
+    }
+}
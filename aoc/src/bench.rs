@@ -0,0 +1,226 @@
+use core::fmt;
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{Display, Formatter},
+    fs,
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use common::DynSolution;
+
+/// Timed stage of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Stage {
+    /// Name of the stage in baseline files
+    fn key(&self) -> String {
+        match self {
+            Self::Parse => "parse".to_owned(),
+            Self::Part(part) => format!("part{}", part),
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "parse" => Some(Self::Parse),
+            "part1" => Some(Self::Part(1)),
+            "part2" => Some(Self::Part(2)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Summary of the timings of repeated runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Summarize timings, there must be at least one
+    pub fn new(samples: &mut [Duration]) -> Self {
+        samples.sort();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len() % 2 == 1 {
+            samples[mid]
+        } else {
+            (samples[mid - 1] + samples[mid]) / 2
+        };
+
+        let secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / secs.len() as f64;
+
+        Self {
+            median,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Time the parse stage and the requested parts of a day `runs` times each,
+/// after an untimed warm up run
+pub fn bench_day(
+    solution: &dyn DynSolution,
+    input: &[u8],
+    parts: RangeInclusive<u8>,
+    runs: usize,
+) -> Result<Vec<(Stage, Stats)>, Box<dyn Error>> {
+    let mut stages = vec![Stage::Parse];
+    stages.extend(parts.map(Stage::Part));
+
+    let mut samples = vec![Vec::with_capacity(runs); stages.len()];
+
+    for run in 0..=runs {
+        let start = Instant::now();
+        let parsed = solution.parse(&mut &input[..])?;
+        let mut timings = vec![start.elapsed()];
+
+        for stage in &stages[1..] {
+            let Stage::Part(part) = *stage else {
+                unreachable!()
+            };
+
+            let start = Instant::now();
+            solution.part(part, &parsed)?;
+            timings.push(start.elapsed());
+        }
+
+        // The first run warms up caches and is not counted
+        if run > 0 {
+            for (samples, timing) in samples.iter_mut().zip(timings) {
+                samples.push(timing);
+            }
+        }
+    }
+
+    Ok(stages
+        .into_iter()
+        .zip(samples)
+        .map(|(stage, mut samples)| (stage, Stats::new(&mut samples)))
+        .collect())
+}
+
+/// Median timings of a previous benchmark, to spot regressions
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, day: u8, stage: Stage, median: Duration) {
+        self.medians.insert((day, stage), median);
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+
+    /// Load a baseline saved by [`Baseline::save`]
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        Self::parse(&text).map_err(|e| format!("{}: {}", path, e).into())
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_string()).map_err(|e| format!("{}: {}", path, e).into())
+    }
+
+    // One `day stage nanoseconds` line per timing
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut baseline = Self::new();
+
+        for (idx, line) in text.lines().enumerate() {
+            let err = || format!("line {}: expected `<day> <stage> <nanoseconds>`", idx + 1);
+
+            let mut words = line.split_whitespace();
+            let (Some(day), Some(stage), Some(nanos), None) =
+                (words.next(), words.next(), words.next(), words.next())
+            else {
+                return Err(err());
+            };
+
+            let day = day.parse().map_err(|_| err())?;
+            let stage = Stage::from_key(stage).ok_or_else(err)?;
+            let nanos = nanos.parse().map_err(|_| err())?;
+            baseline.insert(day, stage, Duration::from_nanos(nanos));
+        }
+
+        Ok(baseline)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for ((day, stage), median) in &self.medians {
+            writeln!(f, "{} {} {}", day, stage.key(), median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+/// Relative change of `new` compared to `old`, in percent
+pub fn change(old: Duration, new: Duration) -> f64 {
+    (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn stats_test() {
+        let tests = [
+            (ms(&[3]), (3, 0.0)),
+            (ms(&[5, 1, 3]), (3, 1633.0)),
+            (ms(&[4, 2, 8, 6]), (5, 2236.0)),
+        ];
+
+        for (mut samples, (median, std_dev_us)) in tests {
+            let stats = Stats::new(&mut samples);
+            assert_eq!(stats.median, Duration::from_millis(median));
+            assert_eq!((stats.std_dev.as_secs_f64() * 1e6).round(), std_dev_us);
+        }
+    }
+
+    #[test]
+    fn baseline_test() {
+        let mut baseline = Baseline::new();
+        baseline.insert(9, Stage::Part(2), Duration::from_nanos(1500));
+        baseline.insert(9, Stage::Parse, Duration::from_nanos(20));
+        baseline.insert(10, Stage::Part(1), Duration::from_nanos(7));
+
+        let text = baseline.to_string();
+        assert_eq!(text, "9 parse 20\n9 part2 1500\n10 part1 7\n");
+        assert_eq!(Baseline::parse(&text), Ok(baseline));
+
+        assert!(Baseline::parse("9 part3 20").is_err());
+        assert!(Baseline::parse("9 parse").is_err());
+        assert!(Baseline::parse("9 parse 20 1").is_err());
+    }
+}
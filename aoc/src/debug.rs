@@ -0,0 +1,341 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io::{self, BufRead, IsTerminal, Write},
+};
+
+use day17::{disassemble, Registers, VirtualMachine};
+
+const HELP: &str = "Commands:
+  n [N]         Execute N instructions (default: 1), an empty line executes one
+  c             Continue until a breakpoint or watchpoint is hit or the program
+                halts
+  b <ADDR>      Break before executing the instruction at address ADDR
+  w <REG>       Break when register A, B or C changes
+  b             List breakpoints and watchpoints
+  d <N>         Delete breakpoint or watchpoint N
+  s <REG> <VAL> Set register A, B or C to VAL
+  h             Print this help
+  q             Quit";
+
+// Instructions executed by `c` before giving up on programs that never halt
+const MAX_CONTINUE_STEPS: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Register {
+    A,
+    B,
+    C,
+}
+
+impl Register {
+    fn parse(s: &str) -> Result<Self, String> {
+        match s.to_ascii_uppercase().as_str() {
+            "A" => Ok(Self::A),
+            "B" => Ok(Self::B),
+            "C" => Ok(Self::C),
+            _ => Err(format!("invalid register: `{}`", s)),
+        }
+    }
+
+    fn get(self, regs: &Registers) -> i64 {
+        match self {
+            Self::A => regs.a,
+            Self::B => regs.b,
+            Self::C => regs.c,
+        }
+    }
+
+    fn get_mut(self, regs: &mut Registers) -> &mut i64 {
+        match self {
+            Self::A => &mut regs.a,
+            Self::B => &mut regs.b,
+            Self::C => &mut regs.c,
+        }
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Condition stopping a program that's continued
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Stop {
+    /// The instruction pointer reached an address
+    Address(usize),
+    /// The value of a register changed
+    Watch(Register),
+}
+
+impl Display for Stop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address(addr) => write!(f, "address {}", addr),
+            Self::Watch(reg) => write!(f, "register {}", reg),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Next(usize),
+    Continue,
+    Break(Stop),
+    List,
+    Delete(usize),
+    Set(Register, i64),
+    Help,
+    Quit,
+}
+
+fn parse_command(line: &str) -> Result<Command, String> {
+    let (cmd, arg) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+    let arg = arg.trim();
+    let num = |arg: &str, default: Option<usize>| match (arg, default) {
+        ("", Some(default)) => Ok(default),
+        _ => arg
+            .parse()
+            .map_err(|_| format!("invalid number: `{}`", arg)),
+    };
+
+    match cmd {
+        "" | "n" => Ok(Command::Next(num(arg, Some(1))?)),
+        "c" => Ok(Command::Continue),
+        "b" if arg.is_empty() => Ok(Command::List),
+        "b" => Ok(Command::Break(Stop::Address(num(arg, None)?))),
+        "w" => Ok(Command::Break(Stop::Watch(Register::parse(arg)?))),
+        "d" => Ok(Command::Delete(num(arg, None)?)),
+        "s" => {
+            let (reg, val) = arg.split_once(' ').unwrap_or((arg, ""));
+            let val = val.trim();
+            let val = val
+                .parse()
+                .map_err(|_| format!("invalid register value: `{}`", val))?;
+            Ok(Command::Set(Register::parse(reg)?, val))
+        }
+        "h" => Ok(Command::Help),
+        "q" => Ok(Command::Quit),
+        _ => Err(format!("unknown command: `{}`, `h` for help", cmd)),
+    }
+}
+
+/// Debug a program interactively, reading commands from stdin
+pub fn run(mut vm: VirtualMachine) -> Result<(), Box<dyn Error>> {
+    let mut stops: Vec<Stop> = Vec::new();
+    // Redraw in place on terminals only
+    let ansi = io::stdout().is_terminal();
+    let mut message = "`h` for help".to_owned();
+
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        let mut stdout = io::stdout().lock();
+        if ansi {
+            write!(stdout, "\x1b[H\x1b[2J")?;
+        }
+        write!(stdout, "{}\n{}\n> ", screen(&vm, &stops), message)?;
+        stdout.flush()?;
+
+        let Some(line) = lines.next() else {
+            return Ok(());
+        };
+
+        message = match parse_command(&line?) {
+            Ok(Command::Quit) => return Ok(()),
+            Ok(cmd) => execute(&mut vm, &mut stops, cmd),
+            Err(e) => e,
+        };
+    }
+}
+
+// Run a command, returns the message to show
+fn execute(vm: &mut VirtualMachine, stops: &mut Vec<Stop>, cmd: Command) -> String {
+    match cmd {
+        Command::Next(n) => {
+            for _ in 0..n {
+                if let Some(msg) = step(vm, &[]) {
+                    return msg;
+                }
+            }
+            String::new()
+        }
+        Command::Continue => {
+            for _ in 0..MAX_CONTINUE_STEPS {
+                if let Some(msg) = step(vm, stops) {
+                    return msg;
+                }
+            }
+            format!("Still running after {} instructions", MAX_CONTINUE_STEPS)
+        }
+        Command::Break(stop) => {
+            stops.push(stop);
+            format!("Breakpoint {} added", stops.len() - 1)
+        }
+        Command::List if stops.is_empty() => "No breakpoints".to_owned(),
+        Command::List => stops
+            .iter()
+            .enumerate()
+            .map(|(idx, stop)| format!("{}: {}", idx, stop))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Command::Delete(idx) if idx < stops.len() => {
+            let stop = stops.remove(idx);
+            format!("Breakpoint {} deleted: {}", idx, stop)
+        }
+        Command::Delete(idx) => format!("No breakpoint {}", idx),
+        Command::Set(reg, val) => {
+            let mut regs = vm.registers();
+            *reg.get_mut(&mut regs) = val;
+            vm.set_registers(regs);
+            format!("Register {} set to {}", reg, val)
+        }
+        Command::Help => HELP.to_owned(),
+        Command::Quit => String::new(),
+    }
+}
+
+// Execute an instruction, returns why the program stopped if it did
+fn step(vm: &mut VirtualMachine, stops: &[Stop]) -> Option<String> {
+    let before = vm.registers();
+    match vm.step() {
+        Ok(true) => {}
+        Ok(false) => return Some(format!("Program halted after {} instructions", vm.steps())),
+        Err(e) => return Some(format!("Error: {}", e)),
+    }
+
+    let after = vm.registers();
+    stops
+        .iter()
+        .enumerate()
+        .find_map(|(idx, stop)| match *stop {
+            Stop::Address(addr) if vm.ip() == addr => {
+                Some(format!("Breakpoint {}: address {}", idx, addr))
+            }
+            Stop::Watch(reg) if reg.get(&before) != reg.get(&after) => Some(format!(
+                "Breakpoint {}: register {} changed from {} to {}",
+                idx,
+                reg,
+                reg.get(&before),
+                reg.get(&after)
+            )),
+            _ => None,
+        })
+}
+
+// Disassembled program with the next instruction marked by `>` and
+// breakpoints by `*`, followed by the registers and output
+fn screen(vm: &VirtualMachine, stops: &[Stop]) -> String {
+    let mut screen = String::new();
+
+    for line in disassemble(vm.program()).lines() {
+        // Instruction lines start with their address, labels don't
+        let addr = line
+            .split_whitespace()
+            .next()
+            .and_then(|word| word.parse().ok());
+        let marker = match addr {
+            Some(addr) if addr == vm.ip() && !vm.is_halted() => '>',
+            Some(addr) if stops.contains(&Stop::Address(addr)) => '*',
+            _ => ' ',
+        };
+        screen.push_str(&format!("{} {}\n", marker, line));
+    }
+
+    let regs = vm.registers();
+    let state = match vm.is_halted() {
+        true => "halted".to_owned(),
+        false => format!("next at address {}", vm.ip()),
+    };
+    screen.push_str(&format!(
+        "Step {}, {}: A={} B={} C={}\n",
+        vm.steps(),
+        state,
+        regs.a,
+        regs.b,
+        regs.c
+    ));
+    screen.push_str(&format!("Output: {}", vm.output_string()));
+
+    screen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_command_test() {
+        let tests = [
+            ("", Ok(Command::Next(1))),
+            ("n 5", Ok(Command::Next(5))),
+            ("c", Ok(Command::Continue)),
+            ("b 4", Ok(Command::Break(Stop::Address(4)))),
+            ("w a", Ok(Command::Break(Stop::Watch(Register::A)))),
+            ("b", Ok(Command::List)),
+            ("d 0", Ok(Command::Delete(0))),
+            ("s C -12", Ok(Command::Set(Register::C, -12))),
+            ("b x", Err("invalid number: `x`".to_owned())),
+            ("w D", Err("invalid register: `D`".to_owned())),
+            ("s A", Err("invalid register value: ``".to_owned())),
+            ("x", Err("unknown command: `x`, `h` for help".to_owned())),
+        ];
+
+        for (input, exp) in tests {
+            assert_eq!(parse_command(input), exp);
+        }
+    }
+
+    #[test]
+    fn execute_test() {
+        let input = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n";
+        let mut vm = aoc::virtual_machine(17, input.as_bytes()).unwrap();
+        let mut stops = Vec::new();
+
+        let tests = [
+            (Command::Break(Stop::Address(2)), "Breakpoint 0 added"),
+            (Command::Continue, "Breakpoint 0: address 2"),
+            (Command::Delete(0), "Breakpoint 0 deleted: address 2"),
+            (
+                Command::Break(Stop::Watch(Register::A)),
+                "Breakpoint 0 added",
+            ),
+            (
+                Command::Continue,
+                "Breakpoint 0: register A changed from 253 to 31",
+            ),
+            (Command::Set(Register::A, 8), "Register A set to 8"),
+            (Command::Delete(0), "Breakpoint 0 deleted: register A"),
+            (Command::Continue, "Program halted after 12 instructions"),
+            (Command::Next(1), "Program halted after 12 instructions"),
+        ];
+        for (cmd, exp) in tests {
+            assert_eq!(execute(&mut vm, &mut stops, cmd), exp);
+        }
+
+        let exp = "  L0:
+     0  adv 3      # A = A >> 3
+     2  out A      # out A % 8
+     4  jnz L0     # if A != 0 goto L0
+Step 12, halted: A=0 B=0 C=0
+Output: 5,0,1,0";
+        assert_eq!(screen(&vm, &stops), exp);
+
+        // Programs looping forever stop eventually
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n";
+        let mut vm = aoc::virtual_machine(17, input.as_bytes()).unwrap();
+        execute(&mut vm, &mut stops, Command::Break(Stop::Address(0)));
+        assert_eq!(
+            execute(&mut vm, &mut stops, Command::Continue),
+            "Breakpoint 0: address 0"
+        );
+        let exp = "  L0:\n>    0  jnz L0     # if A != 0 goto L0\nStep 1, next at address 0: A=1 B=0 C=0\nOutput: ";
+        assert_eq!(screen(&vm, &stops), exp);
+        stops.clear();
+        assert_eq!(
+            execute(&mut vm, &mut stops, Command::Continue),
+            "Still running after 1000000 instructions"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use core::fmt;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::Params;
+
+use crate::solution;
+
+/// Worked example of a day with its expected answers
+///
+/// Stored as `<day>/<name>.txt` next to `<day>/<name>.answers`, where the
+/// answers file has `part1 = ...` and `part2 = ...` lines. Any other
+/// `name = value` line is a puzzle parameter, `#` starts a comment.
+#[derive(Debug)]
+pub struct Fixture {
+    pub day: u8,
+    pub name: String,
+    pub input: Vec<u8>,
+    pub params: Params,
+    // Expected answers of part 1 and 2, `None` if the example has none
+    pub answers: [Option<String>; 2],
+}
+
+impl Fixture {
+    /// Run the real solver on the example, fails on the first wrong answer
+    pub fn check(&self) -> Result<(), Box<dyn Error>> {
+        let mut solution = solution(self.day).ok_or("day not solved")?;
+        solution.configure(&self.params)?;
+        let input = solution.parse(&mut self.input.as_slice())?;
+
+        for (part, expected) in (1..).zip(&self.answers) {
+            let Some(expected) = expected else {
+                continue;
+            };
+
+            let answer = solution.part(part, &input)?;
+            if &answer != expected {
+                return Err(
+                    format!("part {}: expected `{}`, got `{}`", part, expected, answer).into(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Fixture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "day{}/{}", self.day, self.name)
+    }
+}
+
+/// Directory of the fixtures of all days
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures")
+}
+
+/// Load all fixtures in `dir`, sorted by day and name
+pub fn load_fixtures(dir: &Path) -> Result<Vec<Fixture>, Box<dyn Error>> {
+    let mut fixtures = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let day = path
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse().ok())
+            .ok_or_else(|| format!("{}: expected a `day<N>` directory", path.display()))?;
+
+        for entry in fs::read_dir(&path)? {
+            let input_path = entry?.path();
+            if input_path.extension().is_some_and(|ext| ext == "answers") {
+                continue;
+            }
+
+            let name = input_path
+                .file_stem()
+                .and_then(|name| name.to_str())
+                .filter(|_| input_path.extension().is_some_and(|ext| ext == "txt"))
+                .ok_or_else(|| format!("{}: expected a `.txt` input", input_path.display()))?
+                .to_owned();
+
+            let answers_path = input_path.with_extension("answers");
+            let answers = fs::read_to_string(&answers_path)
+                .map_err(|e| format!("{}: {}", answers_path.display(), e))?;
+            let (params, answers) = parse_answers(&answers)
+                .map_err(|e| format!("{}: {}", answers_path.display(), e))?;
+
+            fixtures.push(Fixture {
+                day,
+                name,
+                input: fs::read(&input_path)?,
+                params,
+                answers,
+            });
+        }
+    }
+
+    fixtures.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+
+    Ok(fixtures)
+}
+
+/// Parse an answers file into the puzzle parameters and expected answers
+fn parse_answers(text: &str) -> Result<(Params, [Option<String>; 2]), String> {
+    let mut params = Params::new();
+    let mut answers = [None, None];
+
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (name, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected `name = value`", idx + 1))?;
+        let value = value.trim().to_owned();
+
+        match name.trim() {
+            "part1" => answers[0] = Some(value),
+            "part2" => answers[1] = Some(value),
+            name => params.insert(name, &value),
+        }
+    }
+
+    if answers.iter().all(Option::is_none) {
+        return Err("no expected answers".to_owned());
+    }
+
+    Ok((params, answers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers_test() {
+        let mut params = Params::new();
+        params.insert("width", "11");
+
+        let tests = [
+            (
+                "part1 = 12\npart2 = 6,1",
+                Ok((
+                    Params::new(),
+                    [Some("12".to_owned()), Some("6,1".to_owned())],
+                )),
+            ),
+            (
+                "# Smaller board\nwidth = 11\n\npart1=12",
+                Ok((params, [Some("12".to_owned()), None])),
+            ),
+            (
+                "part1 12",
+                Err("line 1: expected `name = value`".to_owned()),
+            ),
+            ("width = 11", Err("no expected answers".to_owned())),
+        ];
+
+        for (input, exp) in tests {
+            assert_eq!(parse_answers(input), exp);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::error::Error;
+
+use common::{DynSolution, Params};
+use grid::Simulation;
+
+pub mod answers;
+pub mod bench;
+pub mod fixtures;
+
+/// Get the solution of a day
+pub fn solution(day: u8) -> Option<Box<dyn DynSolution>> {
+    let solution: Box<dyn DynSolution> = match day {
+        1 => Box::new(day1::Day1),
+        2 => Box::new(day2::Day2),
+        3 => Box::new(day3::Day3),
+        4 => Box::new(day4::Day4),
+        5 => Box::new(day5::Day5),
+        6 => Box::new(day6::Day6),
+        7 => Box::new(day7::Day7),
+        8 => Box::new(day8::Day8),
+        9 => Box::new(day9::Day9),
+        10 => Box::new(day10::Day10),
+        11 => Box::new(day11::Day11::default()),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14::default()),
+        15 => Box::new(day15::Day15),
+        16 => Box::new(day16::Day16),
+        17 => Box::new(day17::Day17::default()),
+        18 => Box::new(day18::Day18::default()),
+        19 => Box::new(day19::Day19),
+        20 => Box::new(day20::Day20::default()),
+        _ => return None,
+    };
+
+    Some(solution)
+}
+
+/// Get a simulation of a day's part that can be stepped through
+pub fn simulation(day: u8, part: u8, input: &[u8]) -> Result<Box<dyn Simulation>, Box<dyn Error>> {
+    let simulation: Box<dyn Simulation> = match (day, part) {
+        (6, 1) => Box::new(day6::GuardWalk::new(day6::parse_input(input)?)),
+        (15, 1) => Box::new(day15::RobotMoves::new(day15::parse_input(input)?)),
+        (15, 2) => Box::new(day15::WideRobotMoves::new(day15::parse_input(input)?)),
+        _ => return Err(format!("day {} part {} can't be stepped through", day, part).into()),
+    };
+
+    Ok(simulation)
+}
+
+/// Virtual machine loaded with the program of a day's input, to debug it
+pub fn virtual_machine(day: u8, input: &[u8]) -> Result<day17::VirtualMachine, Box<dyn Error>> {
+    match day {
+        17 => Ok(day17::parse_input(input)?.into()),
+        _ => Err(format!("day {} has no program to debug", day).into()),
+    }
+}
+
+/// Generate a random input for a day from a seed, `params` set its size
+pub fn generate(day: u8, seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    match day {
+        1 => day1::generate(seed, params),
+        2 => day2::generate(seed, params),
+        3 => day3::generate(seed, params),
+        4 => day4::generate(seed, params),
+        5 => day5::generate(seed, params),
+        6 => day6::generate(seed, params),
+        7 => day7::generate(seed, params),
+        8 => day8::generate(seed, params),
+        9 => day9::generate(seed, params),
+        10 => day10::generate(seed, params),
+        11 => day11::generate(seed, params),
+        12 => day12::generate(seed, params),
+        13 => day13::generate(seed, params),
+        14 => day14::generate(seed, params),
+        15 => day15::generate(seed, params),
+        16 => day16::generate(seed, params),
+        17 => day17::generate(seed, params),
+        18 => day18::generate(seed, params),
+        19 => day19::generate(seed, params),
+        20 => day20::generate(seed, params),
+        _ => Err(format!("day {} has no input generator", day).into()),
+    }
+}
+
+/// Disassemble the program of a day's input
+pub fn disassemble(day: u8, input: &[u8]) -> Result<String, Box<dyn Error>> {
+    match day {
+        17 => {
+            let (_, program) = day17::parse_input(input)?;
+            Ok(day17::disassemble(&program))
+        }
+        _ => Err(format!("day {} has no program to disassemble", day).into()),
+    }
+}
+
+/// Assemble a program for a day, into the day's input format
+pub fn assemble(day: u8, source: &[u8]) -> Result<String, Box<dyn Error>> {
+    match day {
+        17 => {
+            let source = std::str::from_utf8(source).map_err(|_| "source is not valid UTF-8")?;
+            Ok(day17::format_input(&day17::assemble(source)?))
+        }
+        _ => Err(format!("day {} has no assembler", day).into()),
+    }
+}
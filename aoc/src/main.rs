@@ -0,0 +1,428 @@
+use std::{
+    env,
+    error::Error,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    answers::{Answers, ANSWERS_FILE},
+    bench::{bench_day, change, Baseline},
+    solution,
+};
+use args::{parse_args, Args, USAGE};
+use common::{diag, input, parallel};
+use output::{verify_table, PartResult, Status, Verified};
+
+mod args;
+mod debug;
+mod output;
+mod replay;
+mod step;
+
+fn main() {
+    let args = parse_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("Error: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    });
+
+    // Help requested
+    let Some(args) = args else {
+        println!("{}", USAGE);
+        return;
+    };
+
+    if let Some(seed) = args.generate {
+        let day = *args.days.start();
+        match aoc::generate(day, seed, &args.params) {
+            Ok(input) => print!("{}", input),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if args.disassemble || args.assemble {
+        match translate(&args) {
+            Ok(asm) => print!("{}", asm),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if args.step {
+        if let Err(e) = run_stepper(&args) {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    let ok = if args.verify {
+        verify(&args)
+    } else {
+        run(&args)
+    };
+    if !ok {
+        process::exit(1);
+    }
+}
+
+/// Run all requested days, returns false if any of them failed
+fn run(args: &Args) -> bool {
+    let mut ok = true;
+    parallel::set_threads(args.threads);
+    diag::set_verbose(args.verbose);
+    // Rendering images would skew benchmarks
+    let images = args.images.as_ref().filter(|_| args.bench.is_none());
+    diag::set_image_dir(images.map(PathBuf::from));
+    let record = args.record.as_ref().filter(|_| args.bench.is_none());
+    diag::set_recording(record.map(|record| record.settings));
+
+    // Baseline to compare benchmarks with, and the one being recorded
+    let baseline = match args
+        .bench
+        .as_ref()
+        .and_then(|bench| bench.baseline.as_ref())
+    {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("Error loading baseline: {}", e);
+                return false;
+            }
+        },
+        None => None,
+    };
+    let mut new_baseline = Baseline::new();
+
+    let mut answers = match args.days.clone().any(|day| args.checks_answers(day)) {
+        true => match load_answers(args) {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("Error loading answers: {}", e);
+                return false;
+            }
+        },
+        false => None,
+    };
+    let confirmed = answers.as_ref().map_or(0, Answers::len);
+
+    for day in args.days.clone() {
+        let Some(mut solution) = solution(day) else {
+            day_error(args, day, "not solved".to_owned());
+            ok = false;
+            continue;
+        };
+
+        if let Err(e) = solution.configure(&args.params) {
+            day_error(args, day, e.to_string());
+            ok = false;
+            continue;
+        }
+
+        // Read input
+        let input = match read_input(args, day) {
+            Ok(input) => input,
+            Err(e) => {
+                day_error(args, day, format!("error reading input: {}", e));
+                ok = false;
+                continue;
+            }
+        };
+
+        if let Some(bench) = &args.bench {
+            let timings = match bench_day(&*solution, &input, args.parts(), bench.runs) {
+                Ok(timings) => timings,
+                Err(e) => {
+                    day_error(args, day, format!("error: {}", e));
+                    ok = false;
+                    continue;
+                }
+            };
+
+            // Every run reports the same warnings
+            let mut warnings = diag::take_warnings();
+            warnings.dedup();
+            for warning in warnings {
+                eprintln!("Day {}: warning: {}", day, warning);
+            }
+
+            for (stage, stats) in timings {
+                let compared = baseline
+                    .as_ref()
+                    .and_then(|baseline| baseline.get(day, stage))
+                    .map(|old| compare(old, stats.median))
+                    .unwrap_or_default();
+
+                println!(
+                    "Day {} {}: {:.2?} ± {:.2?}{}",
+                    day, stage, stats.median, stats.std_dev, compared
+                );
+                new_baseline.insert(day, stage, stats.median);
+            }
+            continue;
+        }
+
+        // Parse input once, it is shared between both parts
+        let input = match solution.parse(&mut input.as_slice()) {
+            Ok(input) => input,
+            Err(e) => {
+                day_error(args, day, format!("error parsing input: {}", e));
+                ok = false;
+                continue;
+            }
+        };
+        let parse_warnings = diag::take_warnings();
+
+        for part in args.parts() {
+            let prefix = format!("day{}-part{}-", day, part);
+            diag::set_image_prefix(&prefix);
+
+            let start = Instant::now();
+            let answer = solution.part(part, &input).map_err(|e| e.to_string());
+            let time = start.elapsed();
+            let answer = match (answer, &mut answers) {
+                (Ok(answer), Some(answers)) if args.checks_answers(day) => {
+                    answers.check(day, part, answer, args.confirm)
+                }
+                (answer, _) => answer,
+            };
+
+            ok &= answer.is_ok();
+            let mut warnings = parse_warnings.clone();
+            warnings.extend(diag::take_warnings());
+
+            if let Some(record) = record {
+                let dir = Path::new(&record.dir);
+                for recording in diag::take_recordings() {
+                    if let Err(e) = replay::save_recording(dir, &prefix, recording) {
+                        warnings.push(format!("can't save recording {}", e));
+                    }
+                }
+            }
+
+            print_result(
+                args,
+                &PartResult {
+                    day,
+                    part,
+                    answer,
+                    time,
+                    warnings,
+                },
+            );
+        }
+    }
+
+    if let Some(path) = args
+        .bench
+        .as_ref()
+        .and_then(|bench| bench.save_baseline.as_ref())
+    {
+        if let Err(e) = new_baseline.save(path) {
+            eprintln!("Error saving baseline: {}", e);
+            ok = false;
+        }
+    }
+
+    if let Some(answers) = answers.filter(|answers| answers.len() > confirmed) {
+        let path = answers_path(args);
+        match answers.save(&path) {
+            Ok(()) => eprintln!(
+                "Confirmed {} new answers in {}",
+                answers.len() - confirmed,
+                path.display()
+            ),
+            Err(e) => {
+                eprintln!("Error saving answers: {}", e);
+                ok = false;
+            }
+        }
+    }
+
+    ok
+}
+
+/// Run every part from the cache and print how they compare with the
+/// confirmed answers, returns false if any of them failed
+fn verify(args: &Args) -> bool {
+    parallel::set_threads(args.threads);
+    let mut answers = match load_answers(args) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Error loading answers: {}", e);
+            return false;
+        }
+    };
+    let confirmed = answers.len();
+
+    let mut rows = Vec::new();
+    for day in args.days.clone() {
+        let mut error = |details: String| {
+            for part in args.parts() {
+                rows.push(Verified {
+                    day,
+                    part,
+                    status: Status::Error,
+                    details: details.clone(),
+                    time: Duration::ZERO,
+                });
+            }
+        };
+
+        let Some(solution) = solution(day) else {
+            error("not solved".to_owned());
+            continue;
+        };
+        let input = match read_input(args, day) {
+            Ok(input) => input,
+            Err(e) => {
+                error(format!("error reading input: {}", e));
+                continue;
+            }
+        };
+        let input = match solution.parse(&mut input.as_slice()) {
+            Ok(input) => input,
+            Err(e) => {
+                error(format!("error parsing input: {}", e));
+                continue;
+            }
+        };
+
+        for part in args.parts() {
+            let start = Instant::now();
+            let answer = solution.part(part, &input);
+            let time = start.elapsed();
+
+            let (status, details) = match (answer, answers.get(day, part)) {
+                (Err(e), _) => (Status::Error, e.to_string()),
+                (Ok(answer), Some(exp)) if answer == exp => (Status::Pass, answer),
+                (Ok(answer), Some(exp)) => (
+                    Status::Fail,
+                    format!("got `{}`, confirmed `{}`", answer, exp),
+                ),
+                (Ok(answer), None) if args.confirm => {
+                    answers.insert(day, part, &answer);
+                    (Status::New, format!("{} (confirmed now)", answer))
+                }
+                (Ok(answer), None) => (Status::New, answer),
+            };
+            rows.push(Verified {
+                day,
+                part,
+                status,
+                details,
+                time,
+            });
+        }
+        // Nothing asked for them
+        diag::take_warnings();
+    }
+
+    print!("{}", verify_table(&rows));
+
+    let mut ok = rows
+        .iter()
+        .all(|row| matches!(row.status, Status::Pass | Status::New));
+    if answers.len() > confirmed {
+        if let Err(e) = answers.save(&answers_path(args)) {
+            eprintln!("Error saving answers: {}", e);
+            ok = false;
+        }
+    }
+    ok
+}
+
+/// Disassemble the program of the only requested day, or assemble it
+fn translate(args: &Args) -> Result<String, Box<dyn Error>> {
+    let day = *args.days.start();
+    let input = read_input(args, day)?;
+
+    match args.disassemble {
+        true => aoc::disassemble(day, &input),
+        false => aoc::assemble(day, &input),
+    }
+}
+
+/// Step through the simulation or program of the only requested day
+fn run_stepper(args: &Args) -> Result<(), Box<dyn Error>> {
+    let day = *args.days.start();
+    let input = read_input(args, day)?;
+    // Programs are debugged instruction by instruction instead
+    if day == 17 {
+        return debug::run(aoc::virtual_machine(day, &input)?);
+    }
+    let simulation = aoc::simulation(day, args.part.unwrap_or(1), &input)?;
+
+    step::run(simulation)
+}
+
+fn print_result(args: &Args, res: &PartResult) {
+    if args.json {
+        println!("{}", res.to_json());
+    } else {
+        res.print_text();
+    }
+}
+
+/// Report an error that prevents running any part of a day
+fn day_error(args: &Args, day: u8, msg: String) {
+    let warnings = diag::take_warnings();
+
+    // Scripts expect a result for every part
+    if args.json {
+        for part in args.parts() {
+            print_result(
+                args,
+                &PartResult {
+                    day,
+                    part,
+                    answer: Err(msg.clone()),
+                    time: Duration::ZERO,
+                    warnings: warnings.clone(),
+                },
+            );
+        }
+        return;
+    }
+
+    for warning in warnings {
+        eprintln!("Day {}: warning: {}", day, warning);
+    }
+    eprintln!("Day {}: {}", day, msg);
+}
+
+/// Describe the change of a median compared to its baseline
+fn compare(old: Duration, new: Duration) -> String {
+    // Smaller changes are usually noise
+    const REGRESSION_PERCENT: f64 = 10.0;
+
+    let change = change(old, new);
+    if change > REGRESSION_PERCENT {
+        format!(" ({:+.1}% vs {:.2?}, regressed)", change, old)
+    } else {
+        format!(" ({:+.1}% vs {:.2?})", change, old)
+    }
+}
+
+/// Read the whole input of a day
+fn read_input(args: &Args, day: u8) -> Result<Vec<u8>, Box<dyn Error>> {
+    let cache_dir = input::cache_dir(args.inputs_dir.as_deref());
+    Ok(args.input.source_for(day).read(&cache_dir)?)
+}
+
+/// Answers file of the input cache
+fn answers_path(args: &Args) -> PathBuf {
+    input::cache_dir(args.inputs_dir.as_deref()).join(ANSWERS_FILE)
+}
+
+fn load_answers(args: &Args) -> Result<Answers, Box<dyn Error>> {
+    Answers::load(&answers_path(args))
+}
@@ -0,0 +1,198 @@
+use std::time::Duration;
+
+/// Outcome of running one part of a day
+#[derive(Debug)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    // Answer, or the error that prevented finding it
+    pub answer: Result<String, String>,
+    pub time: Duration,
+    pub warnings: Vec<String>,
+}
+
+impl PartResult {
+    /// Print as text, errors and warnings go to stderr
+    pub fn print_text(&self) {
+        let prefix = format!("Day {} part {}", self.day, self.part);
+
+        for warning in &self.warnings {
+            eprintln!("{}: warning: {}", prefix, warning);
+        }
+
+        match &self.answer {
+            Ok(answer) => println!("{}: {} ({:.2?})", prefix, answer, self.time),
+            Err(e) => eprintln!("{}: error: {}", prefix, e),
+        }
+    }
+
+    /// Single line JSON object, the answer is `null` on error
+    pub fn to_json(&self) -> String {
+        let (answer, error) = match &self.answer {
+            Ok(answer) => (json_string(answer), "null".to_owned()),
+            Err(e) => ("null".to_owned(), json_string(e)),
+        };
+        let warnings: Vec<String> = self.warnings.iter().map(|w| json_string(w)).collect();
+
+        format!(
+            r#"{{"day":{},"part":{},"answer":{},"error":{},"time_ns":{},"warnings":[{}]}}"#,
+            self.day,
+            self.part,
+            answer,
+            error,
+            self.time.as_nanos(),
+            warnings.join(",")
+        )
+    }
+}
+
+/// Outcome of checking a part with its confirmed answer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    // No answer was confirmed yet
+    New,
+    // The part didn't give an answer
+    Error,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail => "FAIL",
+            Self::New => "new",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Row of the verification table
+#[derive(Debug)]
+pub struct Verified {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    // Answer, or what went wrong
+    pub details: String,
+    pub time: Duration,
+}
+
+/// Table of verified parts followed by a count of each status
+pub fn verify_table(rows: &[Verified]) -> String {
+    let mut table = format!(
+        "{:>3}  {:>4}  {:<6}  {:>10}  {}\n",
+        "Day", "Part", "Result", "Time", "Details"
+    );
+    for row in rows {
+        table.push_str(&format!(
+            "{:>3}  {:>4}  {:<6}  {:>10}  {}\n",
+            row.day,
+            row.part,
+            row.status.name(),
+            format!("{:.2?}", row.time),
+            row.details
+        ));
+    }
+
+    let count = |status| rows.iter().filter(|row| row.status == status).count();
+    table.push_str(&format!(
+        "\n{} passed, {} failed, {} new, {} errors\n",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::New),
+        count(Status::Error)
+    ));
+    table
+}
+
+/// Quote and escape a JSON string
+pub fn json_string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if c.is_control() => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+
+    res.push('"');
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_string_test() {
+        let tests = [
+            ("6,1", r#""6,1""#),
+            ("say \"hi\"", r#""say \"hi\"""#),
+            ("a\\b\nc\u{1}", r#""a\\b\nc\u0001""#),
+        ];
+
+        for (input, exp) in tests {
+            assert_eq!(json_string(input), exp);
+        }
+    }
+
+    #[test]
+    fn to_json_test() {
+        let tests = [
+            (
+                Ok("22".to_owned()),
+                vec![],
+                r#"{"day":18,"part":1,"answer":"22","error":null,"time_ns":1500,"warnings":[]}"#,
+            ),
+            (
+                Err("Not enough bytes!".to_owned()),
+                vec!["odd".to_owned(), "input".to_owned()],
+                r#"{"day":18,"part":1,"answer":null,"error":"Not enough bytes!","time_ns":1500,"warnings":["odd","input"]}"#,
+            ),
+        ];
+
+        for (answer, warnings, exp) in tests {
+            let res = PartResult {
+                day: 18,
+                part: 1,
+                answer,
+                time: Duration::from_nanos(1500),
+                warnings,
+            };
+            assert_eq!(res.to_json(), exp);
+        }
+    }
+
+    #[test]
+    fn verify_table_test() {
+        let row = |part, status, details: &str| Verified {
+            day: 17,
+            part,
+            status,
+            details: details.to_owned(),
+            time: Duration::from_micros(1500),
+        };
+        let rows = [
+            row(1, Status::Pass, "4,6,3,5,6,3,5,2,1,0"),
+            row(2, Status::Fail, "got `1`, confirmed `117440`"),
+        ];
+
+        let exp = "\
+Day  Part  Result        Time  Details
+ 17     1  pass        1.50ms  4,6,3,5,6,3,5,2,1,0
+ 17     2  FAIL        1.50ms  got `1`, confirmed `117440`
+
+1 passed, 1 failed, 0 new, 0 errors
+";
+        assert_eq!(verify_table(&rows), exp);
+    }
+}
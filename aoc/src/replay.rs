@@ -0,0 +1,69 @@
+use std::{error::Error, fs, path::Path};
+
+use common::diag::Recording;
+use grid::Animation;
+
+use crate::output::json_string;
+
+// Hundredths of a second every frame is shown
+const FRAME_DELAY: u16 = 5;
+
+/// Save a recording as `<prefix><name>.gif` and `.cast` in `dir`
+pub fn save_recording(
+    dir: &Path,
+    prefix: &str,
+    recording: Recording,
+) -> Result<(), Box<dyn Error>> {
+    let path = dir.join(format!("{}{}", prefix, recording.name));
+
+    let cast = asciicast(&recording.frames, FRAME_DELAY as f64 / 100.0);
+    let gif = Animation::new(recording.frames).delay(FRAME_DELAY).to_gif();
+
+    for (ext, data) in [("gif", gif), ("cast", cast.into_bytes())] {
+        let path = path.with_extension(ext);
+        fs::write(&path, data).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+
+    Ok(())
+}
+
+/// Asciicast v2 recording of text frames, shown `secs` seconds each
+fn asciicast(frames: &[String], secs: f64) -> String {
+    let width = frames.iter().flat_map(|frame| frame.lines());
+    let width = width.map(|line| line.chars().count()).max().unwrap_or(0);
+    let height = frames.iter().map(|frame| frame.lines().count()).max();
+
+    let mut cast = format!(
+        r#"{{"version":2,"width":{},"height":{}}}"#,
+        width.max(1),
+        height.unwrap_or(0).max(1)
+    );
+    cast.push('\n');
+
+    for (idx, frame) in frames.iter().enumerate() {
+        // Move the cursor home and clear the screen before every frame
+        let text = format!("\x1b[H\x1b[2J{}", frame.replace('\n', "\r\n"));
+        cast.push_str(&format!(
+            "[{:.3},\"o\",{}]\n",
+            idx as f64 * secs,
+            json_string(&text)
+        ));
+    }
+
+    cast
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asciicast_test() {
+        let frames = ["#.\n".to_owned(), "#^\n..\n".to_owned()];
+        let exp = r#"{"version":2,"width":2,"height":2}
+[0.000,"o","\u001b[H\u001b[2J#.\r\n"]
+[0.050,"o","\u001b[H\u001b[2J#^\r\n..\r\n"]
+"#;
+        assert_eq!(asciicast(&frames, 0.05), exp);
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    error::Error,
+    io::{self, BufRead, IsTerminal, Write},
+};
+
+use grid::{Breakpoint, Simulation, Stepper};
+
+const HELP: &str = "Commands:
+  n [N]         Step forward N steps (default: 1), an empty line steps once
+  p [N]         Step back N steps (default: 1)
+  g <STEP>      Jump to step STEP
+  c             Continue until a breakpoint is hit or the simulation ends
+  b <COND>      Break on a condition:
+                  count <C>     the number of cells showing C changed
+                  revisit       the guard or robot moved to a cell it visited
+                  cell <X,Y>    the cell at X,Y changed
+                  label <TEXT>  the description of the step contains TEXT
+  b             List breakpoints
+  d <N>         Delete breakpoint N
+  h             Print this help
+  q             Quit";
+
+// Changed cells listed under the grid
+const MAX_LISTED_CELLS: usize = 10;
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Next(usize),
+    Prev(usize),
+    Goto(usize),
+    Continue,
+    Break(Breakpoint),
+    List,
+    Delete(usize),
+    Help,
+    Quit,
+}
+
+fn parse_command(line: &str) -> Result<Command, String> {
+    let (cmd, arg) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+    let arg = arg.trim();
+    let num = |default: Option<usize>| match (arg, default) {
+        ("", Some(default)) => Ok(default),
+        _ => arg
+            .parse()
+            .map_err(|_| format!("invalid number: `{}`", arg)),
+    };
+
+    match cmd {
+        "" | "n" => Ok(Command::Next(num(Some(1))?)),
+        "p" => Ok(Command::Prev(num(Some(1))?)),
+        "g" => Ok(Command::Goto(num(None)?)),
+        "c" => Ok(Command::Continue),
+        "b" if arg.is_empty() => Ok(Command::List),
+        "b" => Ok(Command::Break(arg.parse()?)),
+        "d" => Ok(Command::Delete(num(None)?)),
+        "h" => Ok(Command::Help),
+        "q" => Ok(Command::Quit),
+        _ => Err(format!("unknown command: `{}`, `h` for help", cmd)),
+    }
+}
+
+/// Step through a simulation interactively, reading commands from stdin
+pub fn run(simulation: Box<dyn Simulation>) -> Result<(), Box<dyn Error>> {
+    let mut stepper = Stepper::new(simulation);
+    let mut breakpoints: Vec<Breakpoint> = Vec::new();
+    // Highlight cells and redraw in place on terminals only
+    let ansi = io::stdout().is_terminal();
+    let mut message = "`h` for help".to_owned();
+
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        let mut stdout = io::stdout().lock();
+        if ansi {
+            write!(stdout, "\x1b[H\x1b[2J")?;
+        }
+        write!(stdout, "{}\n{}\n> ", screen(&stepper, ansi), message)?;
+        stdout.flush()?;
+
+        let Some(line) = lines.next() else {
+            return Ok(());
+        };
+
+        message = match parse_command(&line?) {
+            Ok(Command::Quit) => return Ok(()),
+            Ok(cmd) => execute(&mut stepper, &mut breakpoints, cmd),
+            Err(e) => e,
+        };
+    }
+}
+
+// Run a command, returns the message to show
+fn execute<S: Simulation>(
+    stepper: &mut Stepper<S>,
+    breakpoints: &mut Vec<Breakpoint>,
+    cmd: Command,
+) -> String {
+    let end = |stepper: &Stepper<S>| format!("Simulation ends at step {}", stepper.index());
+
+    match cmd {
+        Command::Next(n) => match (0..n).all(|_| stepper.forward()) {
+            true => String::new(),
+            false => end(stepper),
+        },
+        Command::Prev(n) => match (0..n).all(|_| stepper.back()) {
+            true => String::new(),
+            false => "At the start".to_owned(),
+        },
+        Command::Goto(idx) => match stepper.goto(idx) {
+            true => String::new(),
+            false => end(stepper),
+        },
+        Command::Continue => match stepper.run_until(breakpoints) {
+            Some(idx) => format!("Breakpoint {}: {}", idx, breakpoints[idx]),
+            None => end(stepper),
+        },
+        Command::Break(breakpoint) => {
+            breakpoints.push(breakpoint);
+            format!("Breakpoint {} added", breakpoints.len() - 1)
+        }
+        Command::List if breakpoints.is_empty() => "No breakpoints".to_owned(),
+        Command::List => breakpoints
+            .iter()
+            .enumerate()
+            .map(|(idx, bp)| format!("{}: {}", idx, bp))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Command::Delete(idx) if idx < breakpoints.len() => {
+            let bp = breakpoints.remove(idx);
+            format!("Breakpoint {} deleted: {}", idx, bp)
+        }
+        Command::Delete(idx) => format!("No breakpoint {}", idx),
+        Command::Help => HELP.to_owned(),
+        Command::Quit => String::new(),
+    }
+}
+
+// Grid of the current step with what changed, changed cells are shown in reverse
+// video with `ansi`
+fn screen<S: Simulation>(stepper: &Stepper<S>, ansi: bool) -> String {
+    let changed: Vec<_> = stepper.changed_cells().collect();
+    let mut screen = String::new();
+
+    for (pos, c) in stepper.grid().iter() {
+        if ansi && changed.contains(&pos) {
+            screen.push_str(&format!("\x1b[7m{}\x1b[0m", c));
+        } else {
+            screen.push(*c);
+        }
+        if pos.x as usize + 1 == stepper.grid().width() {
+            screen.push('\n');
+        }
+    }
+
+    let total = stepper
+        .total()
+        .map_or("?".to_owned(), |total| total.to_string());
+    screen.push_str(&format!(
+        "Step {} of {}: {}\n",
+        stepper.index(),
+        total,
+        stepper.label()
+    ));
+
+    let mut cells: Vec<String> = changed.iter().map(|pos| pos.to_string()).collect();
+    if cells.is_empty() {
+        cells.push("none".to_owned());
+    } else if cells.len() > MAX_LISTED_CELLS {
+        let more = cells.len() - MAX_LISTED_CELLS;
+        cells.truncate(MAX_LISTED_CELLS);
+        cells.push(format!("and {} more", more));
+    }
+    screen.push_str(&format!("Changed: {}", cells.join(" ")));
+
+    screen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_command_test() {
+        let tests = [
+            ("", Ok(Command::Next(1))),
+            ("n 5", Ok(Command::Next(5))),
+            ("p", Ok(Command::Prev(1))),
+            ("g 120", Ok(Command::Goto(120))),
+            ("c", Ok(Command::Continue)),
+            (
+                "b count O",
+                Ok(Command::Break(Breakpoint::CountChanged('O'))),
+            ),
+            ("b", Ok(Command::List)),
+            ("d 0", Ok(Command::Delete(0))),
+            ("g", Err("invalid number: ``".to_owned())),
+            ("n x", Err("invalid number: `x`".to_owned())),
+            ("b jump", Err("invalid breakpoint: `jump`".to_owned())),
+            ("x", Err("unknown command: `x`, `h` for help".to_owned())),
+        ];
+
+        for (input, exp) in tests {
+            assert_eq!(parse_command(input), exp);
+        }
+    }
+
+    #[test]
+    fn execute_test() {
+        let input = "#####\n#..O#\n#.@.#\n#####\n\n>^<\n";
+        let sim = aoc::simulation(15, 1, input.as_bytes()).unwrap();
+        let mut stepper = Stepper::new(sim);
+        let mut breakpoints = Vec::new();
+
+        let tests = [
+            (
+                Command::Break(Breakpoint::Label("blocked".to_owned())),
+                "Breakpoint 0 added",
+            ),
+            (Command::Continue, "Breakpoint 0: label blocked"),
+            (Command::Prev(5), "At the start"),
+            (Command::Goto(10), "Simulation ends at step 3"),
+            (Command::Delete(1), "No breakpoint 1"),
+        ];
+        for (cmd, exp) in tests {
+            assert_eq!(execute(&mut stepper, &mut breakpoints, cmd), exp);
+        }
+
+        execute(&mut stepper, &mut breakpoints, Command::Goto(1));
+        let exp = "#####\n#  O#\n#  @#\n#####\nStep 1 of 3: move 1 of 3: >\nChanged: 2,2 3,2";
+        assert_eq!(screen(&stepper, false), exp);
+    }
+}
@@ -0,0 +1,28 @@
+use aoc::{
+    fixtures::{fixtures_dir, load_fixtures},
+    solution,
+};
+
+#[test]
+fn examples() {
+    let fixtures = load_fixtures(&fixtures_dir()).unwrap();
+
+    // Every solved day must have at least one example
+    for day in (1..=25).filter(|&day| solution(day).is_some()) {
+        assert!(
+            fixtures.iter().any(|fixture| fixture.day == day),
+            "day {} has no fixture",
+            day
+        );
+    }
+
+    let failures: Vec<String> = fixtures
+        .iter()
+        .filter_map(|fixture| {
+            let err = fixture.check().err()?;
+            Some(format!("{}: {}", fixture, err))
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
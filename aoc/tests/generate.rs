@@ -0,0 +1,105 @@
+use aoc::{generate, solution};
+use common::Params;
+
+// Small generator sizes, and the puzzle parameters solving them needs
+const DAYS: [(u8, &str, &str); 20] = [
+    (1, "lines=50", ""),
+    (2, "reports=50", ""),
+    (3, "length=500", ""),
+    (4, "width=20 height=15", ""),
+    (5, "pages=15 updates=20", ""),
+    (6, "width=20 height=15 obstacles=10", ""),
+    (7, "equations=30 operands=6", ""),
+    (8, "width=20 height=20 frequencies=5", ""),
+    (9, "length=99", ""),
+    (10, "width=20 height=20", ""),
+    (11, "stones=4", ""),
+    (12, "width=20 height=20 kinds=4", ""),
+    (13, "machines=20", ""),
+    (14, "robots=30 width=11 height=7", "width=11 height=7"),
+    (15, "width=12 height=10 moves=200", ""),
+    (16, "width=21 height=15", ""),
+    (17, "digits=6", ""),
+    (18, "width=9 height=9", "width=9 height=9 bytes=20"),
+    (19, "patterns=20 designs=20", ""),
+    (20, "width=21 height=21", "threshold=10"),
+];
+
+fn params(pairs: &str) -> Params {
+    let mut params = Params::new();
+    for pair in pairs.split_whitespace() {
+        let (name, value) = pair.split_once('=').unwrap();
+        params.insert(name, value);
+    }
+    params
+}
+
+#[test]
+fn generated_inputs_are_solved() {
+    for (day, gen_params, solve_params) in DAYS {
+        let mut solution = solution(day).unwrap();
+        solution.configure(&params(solve_params)).unwrap();
+
+        for seed in 0..5 {
+            let input = generate(day, seed, &params(gen_params)).unwrap();
+            assert_eq!(generate(day, seed, &params(gen_params)).unwrap(), input);
+
+            let parsed = solution
+                .parse(&mut input.as_bytes())
+                .unwrap_or_else(|e| panic!("day {} seed {}: {}\n{}", day, seed, e, input));
+
+            for part in 1..=2 {
+                // Generated day 17 programs rarely output themselves
+                if (day, part) == (17, 2) {
+                    continue;
+                }
+                if let Err(e) = solution.part(part, &parsed) {
+                    panic!("day {} part {} seed {}: {}\n{}", day, part, seed, e, input);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn default_sizes() {
+    // Inputs are as big as the puzzle ones
+    let tests = [
+        (6, 130 * 131),
+        (9, 20000),
+        (18, 71 * 71 - 2),
+        (20, 141 * 142),
+    ];
+
+    for (day, exp) in tests {
+        let input = generate(day, 1, &Params::new()).unwrap();
+        let size = match day {
+            18 => input.lines().count(),
+            _ => input.len(),
+        };
+        assert_eq!(size, exp, "day {}", day);
+    }
+    let other = generate(9, 2, &Params::new()).unwrap();
+    assert_ne!(generate(9, 1, &Params::new()).unwrap(), other);
+}
+
+#[test]
+fn invalid_params() {
+    let tests = [
+        (
+            4,
+            "size=3",
+            "unknown parameter `size`, expected one of: width, height",
+        ),
+        (5, "pages=91", "there must be between 1 and 90 pages"),
+        (9, "length=0", "the disk map must have at least 1 digit"),
+        (11, "stones=0", "there must be at least 1 stone"),
+        (16, "width=4", "the maze must be at least 5 x 5"),
+        (21, "", "day 21 has no input generator"),
+    ];
+
+    for (day, pairs, exp) in tests {
+        let res = generate(day, 1, &params(pairs)).map_err(|e| e.to_string());
+        assert_eq!(res, Err(exp.to_owned()));
+    }
+}
@@ -0,0 +1,45 @@
+use aoc::{
+    fixtures::{fixtures_dir, load_fixtures, Fixture},
+    solution,
+};
+use common::parallel;
+
+// Days spreading their work over threads
+const PARALLEL_DAYS: [u8; 5] = [6, 7, 13, 19, 20];
+
+// Answers or errors of both parts
+fn answers(fixture: &Fixture) -> Vec<Result<String, String>> {
+    let mut solution = solution(fixture.day).unwrap();
+    solution.configure(&fixture.params).unwrap();
+    let input = solution.parse(&mut fixture.input.as_slice()).unwrap();
+
+    (1..=2)
+        .map(|part| solution.part(part, &input).map_err(|e| e.to_string()))
+        .collect()
+}
+
+#[test]
+fn same_answers() {
+    let fixtures = load_fixtures(&fixtures_dir()).unwrap();
+    let fixtures: Vec<&Fixture> = fixtures
+        .iter()
+        .filter(|fixture| PARALLEL_DAYS.contains(&fixture.day))
+        .collect();
+    assert!(!fixtures.is_empty());
+
+    for fixture in fixtures {
+        parallel::set_threads(1);
+        let sequential = answers(fixture);
+
+        for threads in [2, 4, 7] {
+            parallel::set_threads(threads);
+            assert_eq!(
+                answers(fixture),
+                sequential,
+                "{} on {} threads",
+                fixture,
+                threads
+            );
+        }
+    }
+}
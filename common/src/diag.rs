@@ -0,0 +1,207 @@
+//! Diagnostics of solvers, kept out of stdout so answers stay machine readable
+
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+// Directory images are saved to, and the prefix of their file names
+static IMAGES: Mutex<Option<(PathBuf, String)>> = Mutex::new(None);
+
+// Recording settings and the recordings made so far
+static RECORDINGS: Mutex<Option<(RecordSettings, Vec<Recording>)>> = Mutex::new(None);
+
+/// Picture of a solver's state, saved in every supported format
+pub trait Image {
+    fn svg(&self) -> String;
+    fn png(&self) -> Vec<u8>;
+}
+
+/// Enable or disable debug output
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+pub fn is_verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// Write debug output to stderr, `render` is only called in verbose mode
+pub fn debug(render: impl FnOnce() -> String) {
+    if is_verbose() {
+        let text = render();
+        eprintln!("{}", text.trim_end_matches('\n'));
+    }
+}
+
+/// Save images to `dir`, or don't render them at all
+pub fn set_image_dir(dir: Option<PathBuf>) {
+    *IMAGES.lock().unwrap_or_else(|e| e.into_inner()) = dir.map(|dir| (dir, String::new()));
+}
+
+/// Prefix of the file names of the next images, e.g. the day and part
+pub fn set_image_prefix(prefix: &str) {
+    if let Some((_, cur)) = &mut *IMAGES.lock().unwrap_or_else(|e| e.into_inner()) {
+        *cur = prefix.to_owned();
+    }
+}
+
+/// Save an image as `<prefix><name>.svg` and `.png`, `render` is only called
+/// when images are saved
+pub fn image<I: Image>(name: &str, render: impl FnOnce() -> I) {
+    let Some((dir, prefix)) = IMAGES.lock().unwrap_or_else(|e| e.into_inner()).clone() else {
+        return;
+    };
+
+    let image = render();
+    let path = dir.join(format!("{}{}", prefix, name));
+    for (ext, data) in [("svg", image.svg().into_bytes()), ("png", image.png())] {
+        let path = path.with_extension(ext);
+        if let Err(e) = fs::write(&path, data) {
+            warn(format!("can't save image {}: {}", path.display(), e));
+        }
+    }
+}
+
+/// Region of text frames to keep, in characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crop {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Crop {
+    fn apply(&self, frame: &str) -> String {
+        let mut res = String::new();
+        for line in frame.lines().skip(self.y).take(self.height) {
+            res.extend(line.chars().skip(self.x).take(self.width));
+            res.push('\n');
+        }
+        res
+    }
+}
+
+/// How frames of simulations are recorded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordSettings {
+    // Only every `stride`-th step is kept
+    pub stride: usize,
+    pub crop: Option<Crop>,
+}
+
+/// Text frames of a simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording {
+    pub name: String,
+    pub frames: Vec<String>,
+    // Steps seen, kept or not
+    steps: usize,
+}
+
+/// Record frames with these settings, or don't render them at all
+pub fn set_recording(settings: Option<RecordSettings>) {
+    *RECORDINGS.lock().unwrap_or_else(|e| e.into_inner()) =
+        settings.map(|settings| (settings, Vec::new()));
+}
+
+/// Record a step of the simulation `name`, `render` draws the grid as text and
+/// is only called for recorded steps
+pub fn frame(name: &str, render: impl FnOnce() -> String) {
+    // Count the step without holding the lock while rendering
+    let crop = {
+        let mut recordings = RECORDINGS.lock().unwrap_or_else(|e| e.into_inner());
+        let Some((settings, recordings)) = &mut *recordings else {
+            return;
+        };
+
+        let recording = match recordings.iter().position(|rec| rec.name == name) {
+            Some(idx) => &mut recordings[idx],
+            None => {
+                recordings.push(Recording {
+                    name: name.to_owned(),
+                    frames: Vec::new(),
+                    steps: 0,
+                });
+                recordings.last_mut().unwrap()
+            }
+        };
+
+        recording.steps += 1;
+        if (recording.steps - 1) % settings.stride != 0 {
+            return;
+        }
+        settings.crop
+    };
+
+    let mut frame = render();
+    if let Some(crop) = crop {
+        frame = crop.apply(&frame);
+    }
+
+    if let Some((_, recordings)) = &mut *RECORDINGS.lock().unwrap_or_else(|e| e.into_inner()) {
+        if let Some(recording) = recordings.iter_mut().find(|rec| rec.name == name) {
+            recording.frames.push(frame);
+        }
+    }
+}
+
+/// Get and clear the recordings made so far
+pub fn take_recordings() -> Vec<Recording> {
+    match &mut *RECORDINGS.lock().unwrap_or_else(|e| e.into_inner()) {
+        Some((_, recordings)) => std::mem::take(recordings),
+        None => Vec::new(),
+    }
+}
+
+/// Report something suspicious that doesn't prevent finding an answer
+pub fn warn(msg: impl Into<String>) {
+    WARNINGS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(msg.into());
+}
+
+/// Get and clear the warnings reported so far
+pub fn take_warnings() -> Vec<String> {
+    std::mem::take(&mut *WARNINGS.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warnings_test() {
+        warn("first");
+        warn(format!("second {}", 2));
+        assert_eq!(take_warnings(), ["first", "second 2"]);
+        assert!(take_warnings().is_empty());
+    }
+
+    #[test]
+    fn crop_test() {
+        let crop = Crop {
+            x: 1,
+            y: 1,
+            width: 2,
+            height: 5,
+        };
+        assert_eq!(crop.apply("abcd\nefgh\nijkl\n"), "fg\njk\n");
+        assert_eq!(crop.apply("a\nb"), "\n");
+    }
+
+    #[test]
+    fn debug_test() {
+        // Nothing is rendered unless verbose
+        debug(|| unreachable!());
+    }
+}
@@ -0,0 +1,177 @@
+//! Where puzzle inputs are read from: a file, stdin, or a local cache of inputs
+//! filled by hand
+
+use core::fmt;
+use std::{
+    env,
+    error::Error,
+    fmt::{Display, Formatter},
+    fs,
+    io::{self, ErrorKind, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable setting the cache directory
+pub const CACHE_DIR_VAR: &str = "AOC_INPUTS";
+
+/// Cache directory used unless another one is set
+pub const DEFAULT_CACHE_DIR: &str = "inputs";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+    /// Input of a day in the cache directory
+    Day(u8),
+}
+
+impl Source {
+    /// `-` is stdin, a day number is that day's cached input and anything else
+    /// is a path, `./5` reads a file named `5`
+    pub fn parse(val: &str) -> Self {
+        match val {
+            "-" => Self::Stdin,
+            _ => match val.parse() {
+                Ok(day) if (1..=25).contains(&day) => Self::Day(day),
+                _ => Self::Path(PathBuf::from(val)),
+            },
+        }
+    }
+
+    /// Read the whole input, cached inputs are looked up in `cache_dir`
+    pub fn read(&self, cache_dir: &Path) -> Result<Vec<u8>, InputError> {
+        let (path, day) = match self {
+            Self::Stdin => {
+                let mut buf = Vec::new();
+                return match io::stdin().lock().read_to_end(&mut buf) {
+                    Ok(_) => Ok(buf),
+                    Err(e) => Err(InputError::Io("stdin".to_owned(), e)),
+                };
+            }
+            Self::Path(path) => (path.clone(), None),
+            Self::Day(day) => (cached_path(cache_dir, *day), Some(*day)),
+        };
+
+        fs::read(&path).map_err(|e| match e.kind() {
+            ErrorKind::NotFound => InputError::Missing { path, day },
+            _ => InputError::Io(path.display().to_string(), e),
+        })
+    }
+}
+
+/// Cache directory given on the command line, else from `AOC_INPUTS`, else
+/// `inputs`
+pub fn cache_dir(dir: Option<&str>) -> PathBuf {
+    match dir {
+        Some(dir) => PathBuf::from(dir),
+        None => env::var_os(CACHE_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_CACHE_DIR)),
+    }
+}
+
+/// Path of a day's input in the cache, e.g. `inputs/day05.txt`
+pub fn cached_path(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir.join(format!("day{:02}.txt", day))
+}
+
+/// Read a whole input as text
+pub fn read_text(mut reader: impl Read) -> Result<String, io::Error> {
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf)?;
+    String::from_utf8(buf)
+        .map_err(|_| io::Error::new(ErrorKind::InvalidData, "input is not valid UTF-8"))
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The file doesn't exist, for a cached input the day it's for
+    Missing {
+        path: PathBuf,
+        day: Option<u8>,
+    },
+    Io(String, io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing {
+                path,
+                day: Some(day),
+            } => write!(
+                f,
+                "no cached input for day {}, save it as `{}`",
+                day,
+                path.display()
+            ),
+            Self::Missing { path, day: None } => {
+                write!(f, "input file `{}` doesn't exist", path.display())
+            }
+            Self::Io(source, e) => write!(f, "{}: {}", source, e),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let tests = [
+            ("-", Source::Stdin),
+            ("5", Source::Day(5)),
+            ("25", Source::Day(25)),
+            ("26", Source::Path(PathBuf::from("26"))),
+            ("./5", Source::Path(PathBuf::from("./5"))),
+            (
+                "inputs/day05.txt",
+                Source::Path(PathBuf::from("inputs/day05.txt")),
+            ),
+        ];
+
+        for (input, exp) in tests {
+            assert_eq!(Source::parse(input), exp);
+        }
+    }
+
+    #[test]
+    fn read_test() {
+        let dir = env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day07.txt"), "190: 10 19\n").unwrap();
+
+        let res = Source::Day(7).read(&dir).unwrap();
+        assert_eq!(res, b"190: 10 19\n");
+        let res = Source::Path(dir.join("day07.txt")).read(Path::new("unused"));
+        assert_eq!(res.unwrap(), b"190: 10 19\n");
+
+        let err = Source::Day(8).read(&dir).unwrap_err().to_string();
+        let exp = format!(
+            "no cached input for day 8, save it as `{}`",
+            dir.join("day08.txt").display()
+        );
+        assert_eq!(err, exp);
+        let err = Source::Path(dir.join("nope.txt"))
+            .read(&dir)
+            .unwrap_err()
+            .to_string();
+        let exp = format!(
+            "input file `{}` doesn't exist",
+            dir.join("nope.txt").display()
+        );
+        assert_eq!(err, exp);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_text_test() {
+        assert_eq!(read_text(&b"a\nb\n"[..]).unwrap(), "a\nb\n");
+        let err = read_text(&b"\xff"[..]).unwrap_err();
+        assert_eq!(err.to_string(), "input is not valid UTF-8");
+    }
+}
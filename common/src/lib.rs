@@ -0,0 +1,14 @@
+pub mod diag;
+pub mod input;
+pub mod parallel;
+mod params;
+mod parse_error;
+pub mod parser;
+pub mod prop;
+pub mod rng;
+pub mod search;
+mod solution;
+
+pub use params::{ParamError, Params};
+pub use parse_error::ParseError;
+pub use solution::{DynInput, DynSolution, Solution};
@@ -0,0 +1,144 @@
+//! Opt-in parallelism for solvers with independent work units, results are
+//! always the same as running on a single thread
+
+use std::{
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+// Chunks handed out per thread, so uneven work units still balance
+const CHUNKS_PER_THREAD: usize = 8;
+
+/// Number of threads solvers may use, 1 runs everything on the calling thread
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads.max(1), Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+/// Map every item with `f`, results are in the order of the items
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    map_with(items, || (), |_, item| f(item))
+}
+
+/// Map every item with `f`, which gets a state created by `init` once per
+/// thread, e.g. a memoisation cache
+pub fn map_with<T, S, R, I, F>(items: &[T], init: I, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    I: Fn() -> S + Sync,
+    F: Fn(&mut S, &T) -> R + Sync,
+{
+    let threads = threads().min(items.len());
+    if threads <= 1 {
+        let mut state = init();
+        return items.iter().map(|item| f(&mut state, item)).collect();
+    }
+
+    let chunk_size = items.len().div_ceil(threads * CHUNKS_PER_THREAD);
+    let chunks: Vec<&[T]> = items.chunks(chunk_size).collect();
+    let next = AtomicUsize::new(0);
+
+    // Every worker takes the next chunk until none are left
+    let mut done: Vec<(usize, Vec<R>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut state = init();
+                    let mut done = Vec::new();
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(chunk) = chunks.get(idx) else {
+                            break;
+                        };
+                        done.push((idx, chunk.iter().map(|item| f(&mut state, item)).collect()));
+                    }
+                    done
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    });
+
+    done.sort_unstable_by_key(|(idx, _)| *idx);
+    done.into_iter().flat_map(|(_, results)| results).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, MutexGuard};
+
+    use super::*;
+
+    // Tests changing the global thread count must not run concurrently
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock_threads(threads: usize) -> MutexGuard<'static, ()> {
+        let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        set_threads(threads);
+        guard
+    }
+
+    #[test]
+    fn map_test() {
+        let items: Vec<u64> = (0..1000).collect();
+        let exp: Vec<u64> = items.iter().map(|n| n * n).collect();
+
+        for threads in [1, 3, 16] {
+            let _guard = lock_threads(threads);
+            assert_eq!(map(&items, |n| n * n), exp);
+            assert_eq!(map(&items[..2], |n| n * n), exp[..2]);
+            assert!(map(&[], |n: &u64| n * n).is_empty());
+        }
+    }
+
+    #[test]
+    fn map_with_test() {
+        let items: Vec<usize> = (0..1000).collect();
+
+        for threads in [1, 4] {
+            let _guard = lock_threads(threads);
+
+            // Every state gets an id, and counts the items it handled
+            let inits = AtomicUsize::new(0);
+            let res = map_with(
+                &items,
+                || (inits.fetch_add(1, Ordering::Relaxed), 0),
+                |(id, seen), item| {
+                    *seen += 1;
+                    (*item, *id, *seen)
+                },
+            );
+
+            let states = inits.load(Ordering::Relaxed);
+            assert!((1..=threads).contains(&states));
+            assert_eq!(
+                res.iter().map(|(item, _, _)| *item).collect::<Vec<_>>(),
+                items
+            );
+
+            // A state handles its chunks in order, its count never restarts
+            let mut counts = vec![0; states];
+            for &(_, id, seen) in &res {
+                counts[id] += 1;
+                assert_eq!(seen, counts[id]);
+            }
+            assert_eq!(counts.iter().sum::<usize>(), items.len());
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use core::fmt;
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// Puzzle parameters given at runtime, e.g. the board size of a worked example
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a parameter, replacing its previous value
+    pub fn insert(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_owned(), value.to_owned());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Check that only the parameters in `known` were given
+    pub fn check_known(&self, known: &[&str]) -> Result<(), ParamError> {
+        match self
+            .values
+            .keys()
+            .find(|name| !known.contains(&name.as_str()))
+        {
+            Some(name) => Err(ParamError::Unknown {
+                name: name.clone(),
+                known: known.iter().map(|name| name.to_string()).collect(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Value of a parameter, `default` if it wasn't given
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> Result<T, ParamError> {
+        match self.values.get(name) {
+            Some(value) => value.parse().map_err(|_| self.invalid(name)),
+            None => Ok(default),
+        }
+    }
+
+    /// Value of a parameter without default, `None` if it wasn't given
+    pub fn get_opt<T: FromStr>(&self, name: &str) -> Result<Option<T>, ParamError> {
+        match self.values.get(name) {
+            Some(value) => value.parse().map(Some).map_err(|_| self.invalid(name)),
+            None => Ok(None),
+        }
+    }
+
+    /// Value of a comma separated list parameter, `None` if it wasn't given
+    pub fn get_list<T: FromStr>(&self, name: &str) -> Result<Option<Vec<T>>, ParamError> {
+        match self.values.get(name) {
+            Some(value) => value
+                .split(',')
+                .map(|item| item.trim().parse().map_err(|_| self.invalid(name)))
+                .collect::<Result<Vec<T>, ParamError>>()
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn invalid(&self, name: &str) -> ParamError {
+        ParamError::Invalid {
+            name: name.to_owned(),
+            value: self.values[name].clone(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParamError {
+    Unknown { name: String, known: Vec<String> },
+    Invalid { name: String, value: String },
+}
+
+impl Display for ParamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown { name, known } if known.is_empty() => {
+                write!(f, "unknown parameter `{}`, this day takes none", name)
+            }
+            Self::Unknown { name, known } => write!(
+                f,
+                "unknown parameter `{}`, expected one of: {}",
+                name,
+                known.join(", ")
+            ),
+            Self::Invalid { name, value } => {
+                write!(f, "invalid value `{}` for parameter `{}`", value, name)
+            }
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(pairs: &[(&str, &str)]) -> Params {
+        let mut params = Params::new();
+        for (name, value) in pairs {
+            params.insert(name, value);
+        }
+        params
+    }
+
+    #[test]
+    fn get_test() {
+        let tests = [
+            (&[][..], Ok(101)),
+            (&[("width", "11")][..], Ok(11)),
+            (&[("height", "7")][..], Ok(101)),
+            (
+                &[("width", "-1")][..],
+                Err(ParamError::Invalid {
+                    name: "width".to_owned(),
+                    value: "-1".to_owned(),
+                }),
+            ),
+        ];
+
+        for (pairs, exp) in tests {
+            let res = params(pairs).get::<usize>("width", 101);
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn get_opt_test() {
+        let tests = [
+            (&[][..], Ok(None)),
+            (&[("max_steps", "100")][..], Ok(Some(100))),
+            (
+                &[("max_steps", "lots")][..],
+                Err(ParamError::Invalid {
+                    name: "max_steps".to_owned(),
+                    value: "lots".to_owned(),
+                }),
+            ),
+        ];
+
+        for (pairs, exp) in tests {
+            let res = params(pairs).get_opt::<usize>("max_steps");
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn get_list_test() {
+        let tests = [
+            (&[][..], Ok(None)),
+            (&[("target", "0,3, 5")][..], Ok(Some(vec![0, 3, 5]))),
+            (
+                &[("target", "0,,5")][..],
+                Err(ParamError::Invalid {
+                    name: "target".to_owned(),
+                    value: "0,,5".to_owned(),
+                }),
+            ),
+        ];
+
+        for (pairs, exp) in tests {
+            let res = params(pairs).get_list::<u8>("target");
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn check_known_test() {
+        let tests = [
+            (&[][..], &[][..], Ok(())),
+            (&[("width", "11")][..], &["width", "height"][..], Ok(())),
+            (
+                &[("steps", "5")][..],
+                &["width", "height"][..],
+                Err(ParamError::Unknown {
+                    name: "steps".to_owned(),
+                    known: vec!["width".to_owned(), "height".to_owned()],
+                }),
+            ),
+        ];
+
+        for (pairs, known, exp) in tests {
+            let res = params(pairs).check_known(known);
+            assert_eq!(res, exp);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use core::fmt;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+// Maximum number of characters of the offending line shown in a diagnostic
+const SNIPPET_WIDTH: usize = 80;
+
+/// Error pointing at the part of the input that could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, starting from 1
+    pub line: usize,
+    /// Column in characters, starting from 1
+    pub column: usize,
+    /// Offending text, empty at the end of a line and `None` at the end of the input
+    pub found: Option<String>,
+    /// What the parser was looking for
+    pub expected: String,
+    /// Whole offending line, shown in the diagnostic
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// Error on `len` bytes starting at byte `offset` of the line with index `line_idx`
+    pub fn new(
+        line_idx: usize,
+        line: &str,
+        offset: usize,
+        len: usize,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self {
+            line: line_idx + 1,
+            column: line[..offset].chars().count() + 1,
+            found: Some(line[offset..offset + len].to_owned()),
+            expected: expected.into(),
+            source_line: line.to_owned(),
+        }
+    }
+
+    /// Error at the start of `rest`, the unparsed tail of the line with index `line_idx`.
+    /// The offending text is the word or the single symbol `rest` starts with
+    pub fn at(line_idx: usize, line: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let len = match rest.chars().next() {
+            Some(c) if c.is_alphanumeric() => rest
+                .find(|c: char| !c.is_alphanumeric())
+                .unwrap_or(rest.len()),
+            Some(c) => c.len_utf8(),
+            None => 0,
+        };
+
+        Self::new(line_idx, line, line.len() - rest.len(), len, expected)
+    }
+
+    /// Error for a grid row that is not `width` cells long
+    pub fn row_width(line_idx: usize, line: &str, width: usize) -> Self {
+        let offset = line
+            .char_indices()
+            .nth(width)
+            .map_or(line.len(), |(i, _)| i);
+
+        Self::new(
+            line_idx,
+            line,
+            offset,
+            line.len() - offset,
+            format!("row of {} cells", width),
+        )
+    }
+
+    /// Error for an input that ended after `lines` lines
+    pub fn end_of_input(lines: usize, expected: impl Into<String>) -> Self {
+        Self {
+            line: lines + 1,
+            column: 1,
+            found: None,
+            expected: expected.into(),
+            source_line: String::new(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+
+        let Some(found) = &self.found else {
+            return write!(f, "end of input");
+        };
+
+        if found.is_empty() {
+            write!(f, "end of line")?;
+        } else {
+            write!(f, "`{}`", found)?;
+        }
+
+        // Show a window of the line around the offending text
+        let chars: Vec<char> = self.source_line.chars().collect();
+        let start = (self.column - 1).saturating_sub(SNIPPET_WIDTH / 2);
+        let start = start.min(chars.len().saturating_sub(SNIPPET_WIDTH));
+        let end = chars.len().min(start + SNIPPET_WIDTH);
+        let prefix = if start > 0 { "..." } else { "" };
+        let suffix = if end < chars.len() { "..." } else { "" };
+        let snippet: String = chars[start..end].iter().collect();
+
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let padding = " ".repeat(prefix.len() + self.column - 1 - start);
+        let carets = "^".repeat(found.chars().count().max(1));
+
+        write!(f, "\n{} |", gutter)?;
+        write!(f, "\n{} | {}{}{}", number, prefix, snippet, suffix)?;
+        write!(f, "\n{} | {}{}", gutter, padding, carets)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_test() {
+        let tests = [
+            (("12 ab3 4", 3), (4, Some("ab3"))),
+            (("47|x53", 3), (4, Some("x53"))),
+            (("p=1,2", 1), (2, Some("="))),
+            (("1 2", 3), (4, Some(""))),
+            (("àè 5", 0), (1, Some("àè"))),
+            (("àè-5", 4), (3, Some("-"))),
+        ];
+
+        for ((line, offset), (column, found)) in tests {
+            let err = ParseError::at(0, line, &line[offset..], "number");
+            assert_eq!(err.line, 1);
+            assert_eq!(err.column, column);
+            assert_eq!(err.found.as_deref(), found);
+        }
+    }
+
+    #[test]
+    fn row_width_test() {
+        let tests = [
+            (("#..#", 3), (4, Some("#"))),
+            (("#..#..", 3), (4, Some("#.."))),
+            (("#.", 3), (3, Some(""))),
+        ];
+
+        for ((line, width), (column, found)) in tests {
+            let err = ParseError::row_width(0, line, width);
+            assert_eq!(err.column, column);
+            assert_eq!(err.found.as_deref(), found);
+            assert_eq!(err.expected, format!("row of {} cells", width));
+        }
+    }
+
+    #[test]
+    fn display_test() {
+        let long = format!("{}x{}", "1".repeat(100), "2".repeat(100));
+
+        let tests = [
+            (
+                ParseError::new(2, "3   4x", 5, 1, "number"),
+                "line 3, column 6: expected number, found `x`\n  |\n3 | 3   4x\n  |      ^",
+            ),
+            (
+                ParseError::at(11, "47|", "", "number"),
+                "line 12, column 4: expected number, found end of line\n   |\n12 | 47|\n   |    ^",
+            ),
+            (
+                ParseError::end_of_input(3, "program"),
+                "line 4, column 1: expected program, found end of input",
+            ),
+            (
+                ParseError::new(0, &long, 100, 1, "digit"),
+                &format!(
+                    "line 1, column 101: expected digit, found `x`\n  |\n1 | ...{}x{}...\n  | {}^",
+                    "1".repeat(40),
+                    "2".repeat(39),
+                    " ".repeat(43)
+                ),
+            ),
+        ];
+
+        for (err, exp) in tests {
+            assert_eq!(err.to_string(), exp);
+        }
+    }
+}
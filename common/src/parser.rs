@@ -0,0 +1,367 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::ParseError;
+
+/// Parse result, the parsed value and the input left to parse
+pub type PResult<'a, T> = Result<(T, &'a str), PError<'a>>;
+
+/// Parse error, `rest` is the input left when the parser failed
+#[derive(PartialEq, Debug, Clone)]
+pub struct PError<'a> {
+    pub rest: &'a str,
+    pub expected: String,
+}
+
+impl<'a> PError<'a> {
+    pub fn new(rest: &'a str, expected: impl Into<String>) -> Self {
+        Self {
+            rest,
+            expected: expected.into(),
+        }
+    }
+
+    /// Locate the error in `line`, the line with index `idx`
+    pub fn locate(self, idx: usize, line: &str) -> ParseError {
+        ParseError::at(idx, line, self.rest, self.expected)
+    }
+}
+
+/// Parse a whole line, nothing can follow what `parser` consumes
+pub fn parse_line<'a, T>(
+    idx: usize,
+    line: &'a str,
+    parser: impl Fn(&'a str) -> PResult<'a, T>,
+) -> Result<T, ParseError> {
+    let (val, rest) = parser(line).map_err(|err| err.locate(idx, line))?;
+
+    if !rest.is_empty() {
+        return Err(ParseError::at(idx, line, rest, "end of line"));
+    }
+
+    Ok(val)
+}
+
+/// Parse a string of text
+pub fn tag<'t>(tag: &'t str) -> impl Fn(&str) -> PResult<()> + 't {
+    move |input| match input.strip_prefix(tag) {
+        Some(rest) => Ok(((), rest)),
+        None => Err(PError::new(input, format!("`{}`", tag))),
+    }
+}
+
+/// Parse an unsigned number
+pub fn unsigned<T: FromStr>(input: &str) -> PResult<'_, T> {
+    number(input, digits(input, usize::MAX))
+}
+
+/// Parse an unsigned number with at most `max_digits` digits
+pub fn unsigned_n<T: FromStr>(max_digits: usize) -> impl Fn(&str) -> PResult<T> {
+    move |input| number(input, digits(input, max_digits))
+}
+
+/// Parse a number with an optional sign
+pub fn signed<T: FromStr>(input: &str) -> PResult<'_, T> {
+    let sign = if input.starts_with(['+', '-']) { 1 } else { 0 };
+    number(input, sign + digits(&input[sign..], usize::MAX))
+}
+
+// Count the leading digits of the input, up to `max`
+fn digits(input: &str, max: usize) -> usize {
+    input
+        .bytes()
+        .take(max)
+        .take_while(u8::is_ascii_digit)
+        .count()
+}
+
+// Parse the first `len` bytes of the input as a number
+fn number<T: FromStr>(input: &str, len: usize) -> PResult<'_, T> {
+    match input[..len].parse() {
+        Ok(num) => Ok((num, &input[len..])),
+        Err(_) => Err(PError::new(input, "number")),
+    }
+}
+
+/// Skip any amount of whitespace, including none
+pub fn whitespace(input: &str) -> PResult<'_, ()> {
+    Ok(((), input.trim_start()))
+}
+
+/// Succeed only at the end of the input
+pub fn eof(input: &str) -> PResult<'_, ()> {
+    if input.is_empty() {
+        Ok(((), input))
+    } else {
+        Err(PError::new(input, "end of input"))
+    }
+}
+
+/// Apply a parser if possible
+pub fn opt<'a, P, T>(parser: P) -> impl Fn(&'a str) -> PResult<'a, Option<T>>
+where
+    P: Fn(&'a str) -> PResult<'a, T>,
+{
+    move |input| match parser(input) {
+        Ok((val, input)) => Ok((Some(val), input)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// Apply a parser as many times as possible, including none
+pub fn many0<'a, P, T>(parser: P) -> impl Fn(&'a str) -> PResult<'a, Vec<T>>
+where
+    P: Fn(&'a str) -> PResult<'a, T>,
+{
+    move |mut input| {
+        let mut res = Vec::new();
+
+        while let Ok((val, rem)) = parser(input) {
+            input = rem;
+            res.push(val);
+        }
+
+        Ok((res, input))
+    }
+}
+
+/// Parse a possibly empty list of items, every separator must be followed by an item
+pub fn separated_list<'a, P, S, T, U>(item: P, sep: S) -> impl Fn(&'a str) -> PResult<'a, Vec<T>>
+where
+    P: Fn(&'a str) -> PResult<'a, T>,
+    S: Fn(&'a str) -> PResult<'a, U>,
+{
+    move |input| {
+        let mut res = Vec::new();
+
+        let Ok((val, mut input)) = item(input) else {
+            return Ok((res, input));
+        };
+        res.push(val);
+
+        while let Ok((_, rem)) = sep(input) {
+            let (val, rem) = item(rem)?;
+            input = rem;
+            res.push(val);
+        }
+
+        Ok((res, input))
+    }
+}
+
+/// Parse `parser` between `open` and `close`, keeping only its value
+pub fn delimited<'a, O, P, C, T, U, V>(
+    open: O,
+    parser: P,
+    close: C,
+) -> impl Fn(&'a str) -> PResult<'a, T>
+where
+    O: Fn(&'a str) -> PResult<'a, U>,
+    P: Fn(&'a str) -> PResult<'a, T>,
+    C: Fn(&'a str) -> PResult<'a, V>,
+{
+    move |input| {
+        let (_, input) = open(input)?;
+        let (val, input) = parser(input)?;
+        let (_, input) = close(input)?;
+
+        Ok((val, input))
+    }
+}
+
+/// Try `first`, then `second` if it fails
+pub fn alt<'a, P, Q, T>(first: P, second: Q) -> impl Fn(&'a str) -> PResult<'a, T>
+where
+    P: Fn(&'a str) -> PResult<'a, T>,
+    Q: Fn(&'a str) -> PResult<'a, T>,
+{
+    move |input| {
+        let err1 = match first(input) {
+            Ok(res) => return Ok(res),
+            Err(err) => err,
+        };
+        let err2 = match second(input) {
+            Ok(res) => return Ok(res),
+            Err(err) => err,
+        };
+
+        // Report the parser that got further, or both if they failed at the same point
+        match err1.rest.len().cmp(&err2.rest.len()) {
+            Ordering::Less => Err(err1),
+            Ordering::Greater => Err(err2),
+            Ordering::Equal => Err(PError::new(
+                err1.rest,
+                format!("{} or {}", err1.expected, err2.expected),
+            )),
+        }
+    }
+}
+
+/// Transform the value of a parser
+pub fn map<'a, P, F, T, U>(parser: P, f: F) -> impl Fn(&'a str) -> PResult<'a, U>
+where
+    P: Fn(&'a str) -> PResult<'a, T>,
+    F: Fn(T) -> U,
+{
+    move |input| {
+        let (val, input) = parser(input)?;
+        Ok((f(val), input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tag_test() {
+        let tests = [
+            (("hellothere", "hellothere"), Ok(((), ""))),
+            (("abcdefghi", "abcd"), Ok(((), "efghi"))),
+            (("bcdef", "abcd"), Err(PError::new("bcdef", "`abcd`"))),
+            (("abc", "abcd"), Err(PError::new("abc", "`abcd`"))),
+        ];
+
+        for ((input, t), exp) in tests {
+            let res = tag(t)(input);
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn unsigned_test() {
+        let tests = [
+            ("1234", Ok((1234, ""))),
+            ("0abcde", Ok((0, "abcde"))),
+            ("efghi", Err(PError::new("efghi", "number"))),
+            ("-12", Err(PError::new("-12", "number"))),
+            ("70000", Err(PError::new("70000", "number"))),
+        ];
+
+        for (input, exp) in tests {
+            let res = unsigned::<u16>(input);
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn unsigned_n_test() {
+        let tests = [
+            (("1234", 5), Ok((1234, ""))),
+            (("0abcde", 5), Ok((0, "abcde"))),
+            (("1234", 3), Ok((123, "4"))),
+            (("efghi", 5), Err(PError::new("efghi", "number"))),
+        ];
+
+        for ((input, n), exp) in tests {
+            let res = unsigned_n::<u16>(n)(input);
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn signed_test() {
+        let tests = [
+            ("123,", Ok((123, ","))),
+            ("-12 3", Ok((-12, " 3"))),
+            ("+7", Ok((7, ""))),
+            ("-", Err(PError::new("-", "number"))),
+            ("--1", Err(PError::new("--1", "number"))),
+            ("200", Err(PError::new("200", "number"))),
+        ];
+
+        for (input, exp) in tests {
+            let res = signed::<i8>(input);
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn whitespace_eof_test() {
+        assert_eq!(whitespace(" \t a "), Ok(((), "a ")));
+        assert_eq!(whitespace("a"), Ok(((), "a")));
+        assert_eq!(eof(""), Ok(((), "")));
+        assert_eq!(eof(" "), Err(PError::new(" ", "end of input")));
+    }
+
+    #[test]
+    fn many0_opt_test() {
+        let tests = [
+            ("1,2,3;", Ok((vec![1, 2, 3], ";"))),
+            ("1,2,", Ok((vec![1, 2], ""))),
+            ("x", Ok((vec![], "x"))),
+        ];
+
+        for (input, exp) in tests {
+            let res = many0(|input| {
+                let (n, input) = unsigned::<u8>(input)?;
+                let (_, input) = opt(tag(","))(input)?;
+                Ok((n, input))
+            })(input);
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn separated_list_test() {
+        let tests = [
+            ("1,2,3;", Ok((vec![1, 2, 3], ";"))),
+            ("7", Ok((vec![7], ""))),
+            ("x", Ok((vec![], "x"))),
+            ("1,2,x", Err(PError::new("x", "number"))),
+        ];
+
+        for (input, exp) in tests {
+            let res = separated_list(unsigned::<u8>, tag(","))(input);
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn delimited_test() {
+        let tests = [
+            ("(12)a", Ok((12, "a"))),
+            ("(12", Err(PError::new("", "`)`"))),
+            ("12)", Err(PError::new("12)", "`(`"))),
+        ];
+
+        for (input, exp) in tests {
+            let res = delimited(tag("("), unsigned::<u8>, tag(")"))(input);
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn alt_map_test() {
+        let tests = [
+            ("do()", Ok((true, ""))),
+            ("don't()", Ok((false, ""))),
+            ("don", Err(PError::new("don", "`do()` or `don't()`"))),
+        ];
+
+        for (input, exp) in tests {
+            let res = alt(map(tag("do()"), |_| true), map(tag("don't()"), |_| false))(input);
+            assert_eq!(res, exp);
+        }
+
+        // The parser that got further is reported
+        let res = alt(
+            delimited(tag("("), unsigned::<u8>, tag(")")),
+            map(tag("x"), |_| 0),
+        )("(1]");
+        assert_eq!(res, Err(PError::new("]", "`)`")));
+    }
+
+    #[test]
+    fn parse_line_test() {
+        let tests = [
+            ("1,2", Ok(vec![1, 2])),
+            ("1,2;", Err(ParseError::new(4, "1,2;", 3, 1, "end of line"))),
+            ("1,x", Err(ParseError::new(4, "1,x", 2, 1, "number"))),
+        ];
+
+        for (input, exp) in tests {
+            let res = parse_line(4, input, separated_list(unsigned::<u8>, tag(",")));
+            assert_eq!(res, exp);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Property checks on random inputs, a failing input is shrunk to a minimal
+//! counterexample before it's reported
+
+use std::{
+    any::Any,
+    env,
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::rng::Rng;
+
+// Seed used unless `PROP_SEED` is set, so test runs are reproducible
+const DEFAULT_SEED: u64 = 2024;
+
+// Bound on the shrinking steps, in case a property fails on almost everything
+const MAX_SHRINKS: usize = 10_000;
+
+/// Value that can be made smaller to simplify a counterexample
+pub trait Shrink: Clone + Debug {
+    /// Smaller values, simplest first
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_unsigned {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let n = *self;
+                if n == 0 {
+                    return Vec::new();
+                }
+                let mut res = vec![0, n / 2, n - 1];
+                res.dedup();
+                res.retain(|&c| c < n);
+                res
+            }
+        }
+    )*};
+}
+
+macro_rules! shrink_signed {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let n = *self;
+                if n == 0 {
+                    return Vec::new();
+                }
+                // Towards 0 from either side
+                let mut res = vec![0, n / 2, n - n.signum()];
+                res.dedup();
+                res.retain(|&c| c.unsigned_abs() < n.unsigned_abs());
+                res
+            }
+        }
+    )*};
+}
+
+shrink_unsigned!(u8, u16, u32, u64, usize);
+shrink_signed!(i8, i16, i32, i64, isize);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            true => vec![false],
+            false => Vec::new(),
+        }
+    }
+}
+
+impl Shrink for char {
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            'a' => Vec::new(),
+            _ => vec!['a'],
+        }
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut res = Vec::new();
+        if !self.is_empty() {
+            res.push(Vec::new());
+        }
+
+        // Drop halves, quarters... down to single items
+        let mut chunk = self.len() / 2;
+        while chunk > 0 {
+            for start in (0..self.len()).step_by(chunk) {
+                let mut smaller = self.clone();
+                smaller.drain(start..(start + chunk).min(self.len()));
+                res.push(smaller);
+            }
+            chunk /= 2;
+        }
+
+        // Then shrink the items in place
+        for (idx, item) in self.iter().enumerate() {
+            for item in item.shrink() {
+                let mut smaller = self.clone();
+                smaller[idx] = item;
+                res.push(smaller);
+            }
+        }
+
+        res
+    }
+}
+
+macro_rules! shrink_tuple {
+    ($($name:ident $idx:tt),*) => {
+        impl<$($name: Shrink),*> Shrink for ($($name,)*) {
+            fn shrink(&self) -> Vec<Self> {
+                let mut res = Vec::new();
+                $(
+                    for item in self.$idx.shrink() {
+                        let mut smaller = self.clone();
+                        smaller.$idx = item;
+                        res.push(smaller);
+                    }
+                )*
+                res
+            }
+        }
+    };
+}
+
+shrink_tuple!(A 0, B 1);
+shrink_tuple!(A 0, B 1, C 2);
+shrink_tuple!(A 0, B 1, C 2, D 3);
+
+/// Compare a solver's result with the reference one
+pub fn same<T: PartialEq + Debug>(res: T, exp: T) -> Result<(), String> {
+    match res == exp {
+        true => Ok(()),
+        false => Err(format!("got {:?}, expected {:?}", res, exp)),
+    }
+}
+
+/// Check `prop` on `cases` inputs made by `gen`, panics with the smallest
+/// failing input found. Shrinking may leave the generator's range, inputs the
+/// property doesn't apply to should pass.
+///
+/// The seed is fixed, `PROP_SEED` sets another one.
+pub fn check<T, G, P>(cases: usize, gen: G, prop: P)
+where
+    T: Shrink,
+    G: Fn(&mut Rng) -> T,
+    P: Fn(&T) -> Result<(), String>,
+{
+    let seed = env::var("PROP_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED);
+
+    if let Some((case, input, err)) = counterexample(seed, cases, gen, prop) {
+        panic!(
+            "property failed on case {} with seed {}\ninput: {:?}\n{}",
+            case, seed, input, err
+        );
+    }
+}
+
+// First failing case with its shrunk input and what went wrong
+fn counterexample<T, G, P>(seed: u64, cases: usize, gen: G, prop: P) -> Option<(usize, T, String)>
+where
+    T: Shrink,
+    G: Fn(&mut Rng) -> T,
+    P: Fn(&T) -> Result<(), String>,
+{
+    let mut rng = Rng::new(seed);
+
+    for case in 0..cases {
+        let mut input = gen(&mut rng);
+        let Err(mut err) = run(&prop, &input) else {
+            continue;
+        };
+
+        // Keep the first smaller input that still fails
+        let mut shrinks = 0;
+        'shrink: while shrinks < MAX_SHRINKS {
+            for smaller in input.shrink() {
+                if let Err(e) = run(&prop, &smaller) {
+                    input = smaller;
+                    err = e;
+                    shrinks += 1;
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+
+        return Some((case, input, err));
+    }
+
+    None
+}
+
+// Run the property, a panic fails it
+fn run<T, P>(prop: &P, input: &T) -> Result<(), String>
+where
+    P: Fn(&T) -> Result<(), String>,
+{
+    panic::catch_unwind(AssertUnwindSafe(|| prop(input)))
+        .unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(&*payload))))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg
+    } else {
+        "unknown payload"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrink_test() {
+        assert_eq!(10u32.shrink(), vec![0, 5, 9]);
+        assert_eq!(1u8.shrink(), vec![0]);
+        assert_eq!((-7i64).shrink(), vec![0, -3, -6]);
+        assert!(0usize.shrink().is_empty());
+
+        let res = vec![1u8, 2].shrink();
+        assert_eq!(
+            res,
+            vec![vec![], vec![2], vec![1], vec![0, 2], vec![1, 0], vec![1, 1]]
+        );
+
+        assert_eq!((2u8, true).shrink(), vec![(0, true), (1, true), (2, false)]);
+    }
+
+    #[test]
+    fn counterexample_test() {
+        // Sums of at least 10 shrink to items summing to 10
+        let gen = |rng: &mut Rng| {
+            (0..rng.below(8))
+                .map(|_| rng.below(20))
+                .collect::<Vec<u64>>()
+        };
+        let prop = |items: &Vec<u64>| match items.iter().sum::<u64>() {
+            sum if sum < 10 => Ok(()),
+            sum => Err(format!("sum is {}", sum)),
+        };
+        let (_, input, err) = counterexample(1, 100, gen, prop).unwrap();
+        assert_eq!((input.iter().sum::<u64>(), err.as_str()), (10, "sum is 10"));
+
+        // Panics fail the property too
+        let (_, input, err) = counterexample(1, 100, gen, |items| {
+            assert!(items.len() < 3, "too long");
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!((input, err.as_str()), (vec![0, 0, 0], "panicked: too long"));
+
+        assert!(counterexample(1, 100, gen, |_| Ok(())).is_none());
+    }
+}
@@ -0,0 +1,93 @@
+//! Small seeded random number generator (SplitMix64), the same seed always
+//! gives the same numbers
+
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Number in `0..n`, `n` must not be 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Number in `range`, which must not be empty
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range");
+        match (hi.abs_diff(lo)).checked_add(1) {
+            Some(len) => lo.wrapping_add(self.below(len) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    /// True `num` times out of `den`
+    pub fn ratio(&mut self, num: u64, den: u64) -> bool {
+        self.below(den) < num
+    }
+
+    /// Random item of `items`, which must not be empty
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_test() {
+        let mut rng = Rng::new(7);
+        let tests = [(0, 0), (-3, 3), (10, 12), (i64::MIN, i64::MAX)];
+
+        for (lo, hi) in tests {
+            for _ in 0..100 {
+                let n = rng.range(lo..=hi);
+                assert!((lo..=hi).contains(&n), "{} not in {}..={}", n, lo, hi);
+            }
+        }
+
+        // Same seed, same numbers, a different seed gives other ones
+        let (mut a, mut b, mut c) = (Rng::new(1), Rng::new(1), Rng::new(2));
+        let a: Vec<u64> = (0..1000).map(|_| a.next_u64()).collect();
+        let b: Vec<u64> = (0..1000).map(|_| b.next_u64()).collect();
+        let c: Vec<u64> = (0..1000).map(|_| c.next_u64()).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn shuffle_test() {
+        let mut rng = Rng::new(3);
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
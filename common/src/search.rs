@@ -0,0 +1,370 @@
+//! Graph searches over implicit graphs, given as a start node and a function
+//! returning the neighbours of a node
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Shortest distances from a start node, with every predecessor of a node on
+/// its shortest paths
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    start: N,
+    dist: HashMap<N, C>,
+    preds: HashMap<N, Vec<N>>,
+}
+
+impl<N, C> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    fn new(start: N, zero: C) -> Self {
+        Self {
+            start: start.clone(),
+            dist: HashMap::from([(start.clone(), zero)]),
+            preds: HashMap::from([(start, Vec::new())]),
+        }
+    }
+
+    // Relax the edge `from -> to`, returns true if `to` got closer
+    fn relax(&mut self, from: &N, to: N, dist: C) -> bool {
+        match self.dist.get(&to) {
+            // Zero-cost edges back to the start would make it its own
+            // predecessor
+            _ if to == self.start => false,
+            Some(&cur) if dist > cur => false,
+            Some(&cur) if dist == cur => {
+                self.preds.entry(to).or_default().push(from.clone());
+                false
+            }
+            _ => {
+                self.dist.insert(to.clone(), dist);
+                self.preds.insert(to, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    /// Shortest distance to a node, `None` if it can't be reached
+    pub fn dist(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    /// All reached nodes and their distances, in no particular order
+    pub fn reached(&self) -> impl Iterator<Item = (&N, C)> {
+        self.dist.iter().map(|(node, dist)| (node, *dist))
+    }
+
+    /// Nodes preceding `node` on its shortest paths
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.preds.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One of the shortest paths to a node, from the start to the node itself
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.dist.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(pred) = self.predecessors(path.last()?).first() {
+            path.push(pred.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every node on any of the shortest paths to `ends`
+    pub fn nodes_on_paths(&self, ends: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack: Vec<N> = ends
+            .into_iter()
+            .filter(|end| self.dist.contains_key(end))
+            .collect();
+
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+
+        nodes
+    }
+
+    /// Number of distinct shortest paths to every reached node, edge costs must
+    /// be positive
+    pub fn path_counts(&self) -> HashMap<N, u64> {
+        // Predecessors are always closer to the start
+        let mut nodes: Vec<(&N, C)> = self.reached().collect();
+        nodes.sort_by_key(|(_, dist)| *dist);
+
+        let mut counts = HashMap::with_capacity(nodes.len());
+        for (node, _) in nodes {
+            let preds = self.predecessors(node);
+            let count = if preds.is_empty() {
+                1
+            } else {
+                preds.iter().map(|pred| counts[pred]).sum()
+            };
+            counts.insert(node.clone(), count);
+        }
+
+        counts
+    }
+}
+
+/// Breadth first search from `start`, where every edge costs 1
+pub fn bfs<N, F, I>(start: N, mut neighbours: F) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, dist)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if paths.relax(&node, next.clone(), dist + 1) {
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's search from `start`, `neighbours` returns the neighbours of a
+/// node with the cost to move there, which can't be negative
+pub fn dijkstra<N, C, F, I>(start: N, mut neighbours: F) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new(start.clone(), C::default());
+    let mut heap = BinaryHeap::from([Reverse(Entry {
+        cost: C::default(),
+        node: start,
+    })]);
+
+    while let Some(Reverse(Entry { cost, node })) = heap.pop() {
+        // A shorter path to the node was already expanded
+        if paths.dist(&node).is_some_and(|dist| cost > dist) {
+            continue;
+        }
+
+        for (next, step) in neighbours(&node) {
+            let cost = cost + step;
+            if paths.relax(&node, next.clone(), cost) {
+                heap.push(Reverse(Entry { cost, node: next }));
+            }
+        }
+    }
+
+    paths
+}
+
+/// A* search from `start` to the first node satisfying `is_goal`, returns the
+/// path to it and its cost
+///
+/// `heuristic` estimates the remaining cost to a goal, it must never
+/// overestimate it for the path to be the shortest one.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut dist = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut heap = BinaryHeap::from([Reverse(Entry {
+        cost: heuristic(&start),
+        node: start,
+    })]);
+
+    while let Some(Reverse(Entry { node, .. })) = heap.pop() {
+        let cost = dist[&node];
+
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(parent) = parents.get(path.last()?) {
+                path.push(N::clone(parent));
+            }
+            path.reverse();
+
+            return Some((path, cost));
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if dist.get(&next).is_some_and(|&dist| next_cost >= dist) {
+                continue;
+            }
+
+            dist.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), node.clone());
+            heap.push(Reverse(Entry {
+                cost: next_cost + heuristic(&next),
+                node: next,
+            }));
+        }
+    }
+
+    None
+}
+
+// Heap entry, ordered by cost only so nodes don't need an order
+struct Entry<N, C> {
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.cmp(&other.cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Directed graph as adjacency lists of (node, cost)
+    fn graph() -> HashMap<char, Vec<(char, u32)>> {
+        HashMap::from([
+            ('a', vec![('b', 1), ('c', 4)]),
+            ('b', vec![('c', 2), ('d', 4)]),
+            ('c', vec![('d', 2)]),
+            ('d', vec![]),
+            ('e', vec![('a', 1)]),
+        ])
+    }
+
+    // Grid of `width` by `height` cells, as (x, y) nodes
+    fn grid_neighbours(width: i32, height: i32) -> impl Fn(&(i32, i32)) -> Vec<(i32, i32)> {
+        move |&(x, y)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..width).contains(&x) && (0..height).contains(&y))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn bfs_test() {
+        let paths = bfs((0, 0), grid_neighbours(3, 2));
+
+        let tests = [((0, 0), Some(0)), ((2, 1), Some(3)), ((3, 0), None)];
+        for (node, exp) in tests {
+            assert_eq!(paths.dist(&node), exp);
+        }
+
+        assert_eq!(paths.reached().count(), 6);
+        assert_eq!(paths.path_to(&(0, 1)), Some(vec![(0, 0), (0, 1)]));
+        assert_eq!(paths.path_to(&(2, 1)).map(|path| path.len()), Some(4));
+
+        // There are 3 monotone paths to the opposite corner of a 3x2 grid
+        assert_eq!(paths.path_counts()[&(2, 1)], 3);
+        assert_eq!(paths.nodes_on_paths([(2, 1)]).len(), 6);
+        assert_eq!(paths.nodes_on_paths([(1, 0)]).len(), 2);
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        let graph = graph();
+        let paths = dijkstra('a', |node| graph[node].clone());
+
+        let tests = [
+            ('a', Some(0)),
+            ('b', Some(1)),
+            ('c', Some(3)),
+            ('d', Some(5)),
+            ('e', None),
+        ];
+        for (node, exp) in tests {
+            assert_eq!(paths.dist(&node), exp);
+        }
+
+        // Both `a b d` and `a b c d` cost 5
+        assert_eq!(paths.predecessors(&'d'), ['b', 'c']);
+        assert_eq!(paths.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(paths.path_counts()[&'d'], 2);
+        assert_eq!(
+            paths.nodes_on_paths(['d']),
+            HashSet::from(['a', 'b', 'c', 'd'])
+        );
+        assert_eq!(paths.path_to(&'e'), None);
+    }
+
+    #[test]
+    fn zero_cost_cycle_test() {
+        let graph = HashMap::from([
+            ('a', vec![('b', 0)]),
+            ('b', vec![('a', 0), ('c', 0), ('d', 1)]),
+            ('c', vec![('b', 0)]),
+            ('d', vec![]),
+        ]);
+        let paths = dijkstra('a', |node| graph[node].clone());
+
+        assert_eq!(paths.dist(&'c'), Some(0));
+        assert!(paths.predecessors(&'a').is_empty());
+        assert_eq!(paths.predecessors(&'b'), ['a', 'c']);
+        assert_eq!(paths.path_to(&'a'), Some(vec!['a']));
+        assert_eq!(paths.path_to(&'d'), Some(vec!['a', 'b', 'd']));
+        assert_eq!(
+            paths.nodes_on_paths(['d']),
+            HashSet::from(['a', 'b', 'c', 'd'])
+        );
+    }
+
+    #[test]
+    fn astar_test() {
+        let graph = graph();
+        let res = astar('a', |node| graph[node].clone(), |_| 0, |&node| node == 'c');
+        assert_eq!(res, Some((vec!['a', 'b', 'c'], 3)));
+
+        let res = astar('b', |node| graph[node].clone(), |_| 0, |&node| node == 'a');
+        assert_eq!(res, None);
+
+        // Manhattan distance never overestimates on a grid
+        let neighbours = grid_neighbours(10, 10);
+        let res = astar(
+            (0, 0),
+            |node| neighbours(node).into_iter().map(|next| (next, 1)),
+            |&(x, y)| (9 - x) + (9 - y),
+            |&node| node == (9, 9),
+        );
+        let (path, cost) = res.unwrap();
+        assert_eq!(cost, 18);
+        assert_eq!(path.len(), 19);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(9, 9)));
+    }
+}
@@ -0,0 +1,118 @@
+use std::{any::Any, error::Error, fmt::Display, io::BufRead};
+
+use crate::Params;
+
+/// Solution to a day's puzzle, split in its parse and solve stages
+pub trait Solution {
+    /// Parsed puzzle input, shared by both parts
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    /// Set puzzle parameters, by default the ones of the real puzzle are used
+    fn configure(&mut self, params: &Params) -> Result<(), Box<dyn Error>> {
+        Ok(params.check_known(&[])?)
+    }
+
+    /// Parse puzzle input
+    fn parse(&self, input: impl BufRead) -> Result<Self::Input, Box<dyn Error>>;
+
+    /// Solve part 1
+    fn part1(&self, input: &Self::Input) -> Result<Self::Output1, Box<dyn Error>>;
+
+    /// Solve part 2
+    fn part2(&self, input: &Self::Input) -> Result<Self::Output2, Box<dyn Error>>;
+}
+
+/// Parsed input of a type erased solution
+pub type DynInput = Box<dyn Any>;
+
+/// Type erased [`Solution`], so solutions of different days can be stored
+/// together and run the same way
+pub trait DynSolution {
+    /// Set puzzle parameters
+    fn configure(&mut self, params: &Params) -> Result<(), Box<dyn Error>>;
+
+    /// Parse puzzle input
+    fn parse(&self, input: &mut dyn BufRead) -> Result<DynInput, Box<dyn Error>>;
+
+    /// Solve a part, the input must come from this solution's `parse`
+    fn part(&self, part: u8, input: &DynInput) -> Result<String, Box<dyn Error>>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn configure(&mut self, params: &Params) -> Result<(), Box<dyn Error>> {
+        Solution::configure(self, params)
+    }
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<DynInput, Box<dyn Error>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part(&self, part: u8, input: &DynInput) -> Result<String, Box<dyn Error>> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or("input was parsed by a different solution")?;
+
+        match part {
+            1 => Ok(self.part1(input)?.to_string()),
+            2 => Ok(self.part2(input)?.to_string()),
+            _ => Err(format!("invalid part: {}", part).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sums the numbers on each line, part 2 multiplies them
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(&self, input: impl BufRead) -> Result<Self::Input, Box<dyn Error>> {
+            input.lines().map(|line| Ok(line?.parse()?)).collect()
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<u32, Box<dyn Error>> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(&self, input: &Self::Input) -> Result<u32, Box<dyn Error>> {
+            Ok(input.iter().product())
+        }
+    }
+
+    #[test]
+    fn dyn_solution_test() {
+        let solution: &dyn DynSolution = &Sum;
+        let input = solution.parse(&mut "2\n3\n4".as_bytes()).unwrap();
+
+        assert_eq!(solution.part(1, &input).unwrap(), "9");
+        assert_eq!(solution.part(2, &input).unwrap(), "24");
+        assert!(solution.part(3, &input).is_err());
+        assert!(solution.parse(&mut "2\nx".as_bytes()).is_err());
+
+        let wrong_input: DynInput = Box::new("2");
+        assert!(solution.part(1, &wrong_input).is_err());
+    }
+
+    #[test]
+    fn configure_test() {
+        let solution: &mut dyn DynSolution = &mut Sum;
+        let mut params = Params::new();
+        assert!(solution.configure(&params).is_ok());
+
+        // Solutions take no parameters by default
+        params.insert("width", "11");
+        assert!(solution.configure(&params).is_err());
+    }
+}
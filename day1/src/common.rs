@@ -1,50 +1,54 @@
-use core::panic;
-use std::io::{stdin, BufRead, BufReader};
+use std::{error::Error, io::BufRead};
 
-/// Read lists from stdin
-pub fn read_lists() -> (Vec<i32>, Vec<i32>) {
-    // Open bufreader on stdin
-    let reader = BufReader::new(stdin());
+use ::common::ParseError;
 
+pub type Lists = (Vec<i32>, Vec<i32>);
+
+/// Read lists from input
+pub fn read_lists(reader: impl BufRead) -> Result<Lists, Box<dyn Error>> {
     let mut list1: Vec<i32> = Vec::new();
     let mut list2: Vec<i32> = Vec::new();
 
     // Parse all lines from input
-    reader.lines().for_each(|line| {
-        match line {
-            Ok(line) => match parse_line(&line) {
-                Ok((num1, num2)) => {
-                    list1.push(num1);
-                    list2.push(num2);
-                }
-                Err(_) => panic!("Parse error!"),
-            },
-            Err(e) => panic!("I/O error: {}", e),
-        };
-    });
-
-    (list1, list2)
-}
+    for (idx, line) in reader.lines().enumerate() {
+        let (num1, num2) = parse_line(idx, &line?)?;
+        list1.push(num1);
+        list2.push(num2);
+    }
 
-/// Process line of input
-fn parse_line(line: &str) -> Result<(i32, i32), ()> {
-    // Divide line by space
-    let mut split = line.split_whitespace();
+    Ok((list1, list2))
+}
 
-    // Get numbers
-    let n1 = split.next().ok_or(())?;
-    let n2 = split.next().ok_or(())?;
+/// Process line of input, `idx` is its index in the input
+fn parse_line(idx: usize, line: &str) -> Result<(i32, i32), ParseError> {
+    let (n1, rest) = parse_number(idx, line, line)?;
+    let (n2, rest) = parse_number(idx, line, rest)?;
 
-    // Parse numbers
-    let n1: i32 = n1.parse().or(Err(()))?;
-    let n2: i32 = n2.parse().or(Err(()))?;
+    // Nothing can follow the numbers
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        return Err(ParseError::at(idx, line, rest, "end of line"));
+    }
 
     Ok((n1, n2))
 }
 
+/// Parse the next whitespace separated number from `rest`, the unparsed tail of `line`
+fn parse_number<'a>(idx: usize, line: &str, rest: &'a str) -> Result<(i32, &'a str), ParseError> {
+    let rest = rest.trim_start();
+    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+    let num = rest[..end]
+        .parse()
+        .map_err(|_| ParseError::new(idx, line, line.len() - rest.len(), end, "number"))?;
+
+    Ok((num, &rest[end..]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::parse_line;
+    use ::common::ParseError;
 
     #[test]
     fn parse_line_ok() {
@@ -53,11 +57,26 @@ mod tests {
             ("0 0", (0, 0)),
             ("1234   1234", (1234, 1234)),
             ("999999 999999", (999999, 999999)),
+            ("2147483647 -2147483648", (i32::MAX, i32::MIN)),
         ];
 
         for (input, exp) in tests {
-            let res = parse_line(input).unwrap();
+            let res = parse_line(0, input).unwrap();
             assert_eq!(res, exp);
         }
     }
+
+    #[test]
+    fn parse_line_err() {
+        let tests = [
+            ("1234 12a4", (5, 4, "number")),
+            ("1234", (4, 0, "number")),
+            ("1234 1234 5", (10, 1, "end of line")),
+        ];
+
+        for (input, (offset, len, expected)) in tests {
+            let res = parse_line(2, input);
+            assert_eq!(res, Err(ParseError::new(2, input, offset, len, expected)));
+        }
+    }
 }
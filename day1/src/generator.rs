@@ -0,0 +1,23 @@
+use std::error::Error;
+
+use ::common::{rng::Rng, Params};
+
+/// Random input from `seed`, with `lines` pairs of location IDs (default: 1000)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["lines"])?;
+    let lines: usize = params.get("lines", 1000)?;
+    let mut rng = Rng::new(seed);
+
+    let left: Vec<i64> = (0..lines).map(|_| rng.range(10000..=99999)).collect();
+    let mut input = String::new();
+    for id in &left {
+        // Some IDs appear in both lists, for the similarity score
+        let other = match rng.ratio(1, 3) {
+            true => *rng.pick(&left),
+            false => rng.range(10000..=99999),
+        };
+        input.push_str(&format!("{}   {}\n", id, other));
+    }
+
+    Ok(input)
+}
@@ -1 +1,32 @@
+use std::{error::Error, io::BufRead};
+
+use crate::common::{read_lists, Lists};
+use ::common::Solution;
+
 pub mod common;
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
+
+#[derive(Debug, Default)]
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Lists;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: impl BufRead) -> Result<Lists, Box<dyn Error>> {
+        read_lists(input)
+    }
+
+    fn part1(&self, input: &Lists) -> Result<i64, Box<dyn Error>> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Lists) -> Result<i64, Box<dyn Error>> {
+        part2::solve(input)
+    }
+}
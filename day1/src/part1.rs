@@ -0,0 +1,45 @@
+use std::error::Error;
+
+use crate::common::Lists;
+
+pub fn solve((list1, list2): &Lists) -> Result<i64, Box<dyn Error>> {
+    // Lists are sorted in place
+    let (mut list1, mut list2) = (list1.clone(), list2.clone());
+
+    // Process lists
+    let res = compute_difference(&mut list1, &mut list2);
+
+    Ok(res)
+}
+
+fn compute_difference(list1: &mut [i32], list2: &mut [i32]) -> i64 {
+    // Sort lists
+    list1.sort();
+    list2.sort();
+
+    list1
+        .iter()
+        .zip(list2)
+        .fold(0, |acc, (el1, el2)| acc + i64::from(el1.abs_diff(*el2)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compute_difference;
+
+    #[test]
+    fn compute_difference_test() {
+        let tests = [
+            (([3, 4, 2, 1, 3, 3], [4, 3, 5, 3, 9, 3]), 11),
+            (
+                ([i32::MAX, 0, 0, 0, 0, 0], [i32::MIN, 0, 0, 0, 0, 0]),
+                4294967295,
+            ),
+        ];
+
+        for ((mut list1, mut list2), exp) in tests {
+            let res = compute_difference(&mut list1, &mut list2);
+            assert_eq!(res, exp);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::error::Error;
+
+use crate::common::Lists;
+
+pub fn solve((list1, list2): &Lists) -> Result<i64, Box<dyn Error>> {
+    // Process lists
+    let res = compute_similarity(list1, list2);
+
+    Ok(res)
+}
+
+fn compute_similarity(list1: &[i32], list2: &[i32]) -> i64 {
+    list1.iter().fold(0, |acc, el| {
+        acc + i64::from(*el) * count_occurrences(list2, *el)
+    })
+}
+
+fn count_occurrences(list: &[i32], val: i32) -> i64 {
+    list.iter().filter(|el| **el == val).count() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute_similarity, count_occurrences};
+
+    #[test]
+    fn compute_similarity_test() {
+        let tests = [
+            (([3, 4, 2, 1, 3, 3], [4, 3, 5, 3, 9, 3]), 31),
+            (
+                (
+                    [i32::MAX, i32::MIN, 0, 0, 0, 0],
+                    [i32::MAX, i32::MAX, i32::MIN, 1, 1, 1],
+                ),
+                2147483646,
+            ),
+        ];
+
+        for ((list1, list2), exp) in tests {
+            let res = compute_similarity(&list1, &list2);
+            assert_eq!(res, exp);
+        }
+    }
+
+    #[test]
+    fn count_occurrences_test() {
+        let tests = [(([1, 2, 2, 3, 2, 1, 2], 2), 4)];
+
+        for ((list, val), exp) in tests {
+            let res = count_occurrences(&list, val);
+            assert_eq!(res, exp);
+        }
+    }
+}
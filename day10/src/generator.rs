@@ -0,0 +1,56 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+use grid::{Matrix2D, Position};
+
+/// Random input from `seed`, a `width` x `height` map (default: 50 x 50) with
+/// `trails` hiking trails laid over random heights (default: 1 per 25 cells)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["width", "height", "trails"])?;
+    let width: usize = params.get("width", 50)?;
+    let height: usize = params.get("height", 50)?;
+    let trails: usize = params.get("trails", width * height / 25)?;
+    if width == 0 || height == 0 {
+        return Err("the map can't be empty".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    let mut map = Matrix2D::new(width, height, 0);
+    for pos in map.positions().collect::<Vec<_>>() {
+        map.set(pos, rng.range(0..=9));
+    }
+
+    // Every trail climbs from 0 to 9 one step at a time, without crossing
+    // other trails
+    let mut on_trail = Matrix2D::new(width, height, false);
+    for _ in 0..trails {
+        let x = rng.below(width as u64) as usize;
+        let y = rng.below(height as u64) as usize;
+        let mut pos = Position::new_usize(x, y);
+        if on_trail[pos] {
+            continue;
+        }
+
+        for h in 0..=9 {
+            map.set(pos, h);
+            on_trail.set(pos, true);
+            let next: Vec<Position> = on_trail
+                .neighbours4(pos)
+                .filter(|(_, on_trail)| !**on_trail)
+                .map(|(pos, _)| pos)
+                .collect();
+            if next.is_empty() {
+                break;
+            }
+            pos = *rng.pick(&next);
+        }
+    }
+
+    let mut input = String::new();
+    for row in map.rows() {
+        input.extend(row.iter().map(|h| char::from(b'0' + *h as u8)));
+        input.push('\n');
+    }
+
+    Ok(input)
+}
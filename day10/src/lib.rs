@@ -1,70 +1,77 @@
-use core::fmt;
-use std::{
-    error::Error,
-    fmt::{Display, Formatter},
-    io::BufRead,
+use std::{error::Error, io::BufRead};
+
+use common::{
+    search::{bfs, Paths},
+    ParseError, Solution,
 };
+use grid::{Matrix2D, Position};
 
-#[derive(Debug)]
-pub struct ParseError {}
+mod generator;
+mod part1;
+mod part2;
 
-impl ParseError {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
+pub use generator::generate;
 
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "parse error")
-    }
-}
+#[derive(Debug, Default)]
+pub struct Day10;
 
-impl Error for ParseError {}
+impl Solution for Day10 {
+    type Input = Matrix2D<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Position {
-    pub x: isize,
-    pub y: isize,
-}
+    fn parse(&self, input: impl BufRead) -> Result<Matrix2D<u8>, Box<dyn Error>> {
+        parse_input(input)
+    }
 
-impl Position {
-    pub fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
+    fn part1(&self, input: &Matrix2D<u8>) -> Result<usize, Box<dyn Error>> {
+        part1::solve(input)
     }
 
-    pub fn new_usize(x: usize, y: usize) -> Self {
-        Self {
-            x: x as isize,
-            y: y as isize,
-        }
+    fn part2(&self, input: &Matrix2D<u8>) -> Result<usize, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
-pub fn parse_input(input: impl BufRead) -> Result<Vec<Vec<u8>>, Box<dyn Error>> {
-    let mut width = None;
-    input
-        .lines()
-        .map(|line| -> Result<Vec<u8>, Box<dyn Error>> {
-            let line = line?;
-            let line: Vec<u8> = line
-                .chars()
-                .map(|c| -> Result<u8, Box<dyn Error>> {
-                    match c.to_digit(10) {
-                        Some(val) => Ok(val as u8),
-                        None => Err(Box::new(ParseError::new())),
-                    }
-                })
-                .collect::<Result<Vec<u8>, Box<dyn Error>>>()?;
+pub fn parse_input(input: impl BufRead) -> Result<Matrix2D<u8>, Box<dyn Error>> {
+    let mut rows: Vec<Vec<u8>> = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let line = line?;
+        let row = line
+            .char_indices()
+            .map(|(offset, c)| match c.to_digit(10) {
+                Some(val) => Ok(val as u8),
+                None => Err(ParseError::new(
+                    idx,
+                    &line,
+                    offset,
+                    c.len_utf8(),
+                    "height digit",
+                )),
+            })
+            .collect::<Result<Vec<u8>, ParseError>>()?;
 
-            if let Some(width) = width {
-                if line.len() != width {
-                    return Err(Box::new(ParseError::new()));
-                }
+        // All rows must be as wide as the first one
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(Box::new(ParseError::row_width(idx, &line, first.len())));
             }
-            width = Some(line.len());
+        }
+
+        rows.push(row);
+    }
+
+    Ok(Matrix2D::from_rows(rows).ok_or("malformed map")?)
+}
 
-            Ok(line)
-        })
-        .collect()
+/// Every hiking trail from a trailhead, trails go up by exactly one at each step
+pub fn trails_from(map: &Matrix2D<u8>, trailhead: Position) -> Paths<Position, usize> {
+    bfs(trailhead, |&pos| {
+        let height = map[pos];
+        map.neighbours4(pos)
+            .filter(move |(_, next)| **next == height + 1)
+            .map(|(next, _)| next)
+            .collect::<Vec<_>>()
+    })
 }
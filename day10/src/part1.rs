@@ -0,0 +1,25 @@
+use std::error::Error;
+
+use crate::trails_from;
+use grid::Matrix2D;
+
+pub fn solve(map: &Matrix2D<u8>) -> Result<usize, Box<dyn Error>> {
+    // Compute result
+    let result = compute_result(map);
+
+    Ok(result)
+}
+
+fn compute_result(map: &Matrix2D<u8>) -> usize {
+    let mut sum = 0;
+
+    for (pos, cell) in map.iter() {
+        if *cell == 0 {
+            // Count reachable peaks
+            let trails = trails_from(map, pos);
+            sum += trails.reached().filter(|(pos, _)| map[**pos] == 9).count();
+        }
+    }
+
+    sum
+}
@@ -0,0 +1,29 @@
+use std::error::Error;
+
+use crate::trails_from;
+use grid::Matrix2D;
+
+pub fn solve(map: &Matrix2D<u8>) -> Result<usize, Box<dyn Error>> {
+    // Compute result
+    let result = compute_result(map);
+
+    Ok(result)
+}
+
+fn compute_result(map: &Matrix2D<u8>) -> usize {
+    let mut sum = 0;
+
+    for (pos, cell) in map.iter() {
+        if *cell == 0 {
+            // Every trail to a peak is as long as the climb, so it's a shortest path
+            let counts = trails_from(map, pos).path_counts();
+            sum += counts
+                .iter()
+                .filter(|(pos, _)| map[**pos] == 9)
+                .map(|(_, count)| *count as usize)
+                .sum::<usize>();
+        }
+    }
+
+    sum
+}
@@ -0,0 +1,19 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+/// Random input from `seed`, `stones` engraved stones (default: 8)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["stones"])?;
+    let stones: usize = params.get("stones", 8)?;
+    if stones == 0 {
+        return Err("there must be at least 1 stone".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    let stones: Vec<String> = (0..stones)
+        .map(|_| rng.range(0..=9_999_999).to_string())
+        .collect();
+
+    Ok(stones.join(" ") + "\n")
+}
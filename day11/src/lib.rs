@@ -1,83 +1,120 @@
-use core::fmt;
-use std::{
-    collections::HashMap,
-    error::Error,
-    fmt::{Display, Formatter},
-    io::BufRead,
-    num::ParseIntError,
-};
+use std::{collections::HashMap, error::Error, io::BufRead};
+
+use common::{Params, ParseError, Solution};
+
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
 
 #[derive(Debug)]
-pub struct ParseError {}
+pub struct Day11 {
+    // Number of blinks of each part
+    pub blinks1: usize,
+    pub blinks2: usize,
+}
 
-impl ParseError {
-    pub fn new() -> Self {
-        Self {}
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            blinks1: 25,
+            blinks2: 75,
+        }
     }
 }
 
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "parse error")
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn configure(&mut self, params: &Params) -> Result<(), Box<dyn Error>> {
+        params.check_known(&["blinks1", "blinks2"])?;
+        self.blinks1 = params.get("blinks1", self.blinks1)?;
+        self.blinks2 = params.get("blinks2", self.blinks2)?;
+
+        Ok(())
+    }
+
+    fn parse(&self, input: impl BufRead) -> Result<Vec<u64>, Box<dyn Error>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Vec<u64>) -> Result<u64, Box<dyn Error>> {
+        part1::solve(input, self.blinks1)
     }
-}
 
-impl Error for ParseError {}
+    fn part2(&self, input: &Vec<u64>) -> Result<u64, Box<dyn Error>> {
+        part2::solve(input, self.blinks2)
+    }
+}
 
 pub fn parse_input(input: impl BufRead) -> Result<Vec<u64>, Box<dyn Error>> {
-    let line = input.lines().next();
-
-    if let Some(line) = line {
-        let line = line?;
-        let res = line
-            .split_whitespace()
-            .map(|num| num.parse())
-            .collect::<Result<Vec<u64>, ParseIntError>>()?;
-        return Ok(res);
+    let line = input
+        .lines()
+        .next()
+        .ok_or(ParseError::end_of_input(0, "stones"))??;
+
+    let mut res = Vec::new();
+    let mut rest = line.trim_start();
+
+    // Parse each stone
+    while !rest.is_empty() {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+        let num = rest[..end]
+            .parse()
+            .map_err(|_| ParseError::new(0, &line, line.len() - rest.len(), end, "number"))?;
+        res.push(num);
+
+        rest = rest[end..].trim_start();
     }
 
-    return Err(Box::new(ParseError::new()));
+    Ok(res)
 }
 
-pub fn process(input: &[u64], steps: usize) -> usize {
+/// Number of stones after `steps` blinks, `None` if a stone or the count gets
+/// too big for a u64
+pub fn process(input: &[u64], steps: usize) -> Option<u64> {
     let mut memo = HashMap::new();
-    input
-        .iter()
-        .map(|n| process_value(*n, steps, &mut memo))
-        .sum()
+    input.iter().try_fold(0u64, |acc, n| {
+        acc.checked_add(process_value(*n, steps, &mut memo)?)
+    })
 }
 
-fn process_value(n: u64, steps: usize, memo: &mut HashMap<(u64, usize), usize>) -> usize {
+fn process_value(n: u64, steps: usize, memo: &mut HashMap<(u64, usize), u64>) -> Option<u64> {
     // Base case
     if steps == 0 {
-        return 1;
+        return Some(1);
     }
 
     if let Some(res) = memo.get(&(n, steps)) {
-        return *res;
+        return Some(*res);
     }
 
     let res = if n == 0 {
-        process_value(1, steps - 1, memo)
+        process_value(1, steps - 1, memo)?
     } else if let Some((a, b)) = split_number(n) {
-        process_value(a, steps - 1, memo) + process_value(b, steps - 1, memo)
+        process_value(a, steps - 1, memo)?.checked_add(process_value(b, steps - 1, memo)?)?
     } else {
-        process_value(n * 2024, steps - 1, memo)
+        process_value(n.checked_mul(2024)?, steps - 1, memo)?
     };
 
     memo.insert((n, steps), res);
 
-    res
+    Some(res)
 }
 
 fn count_digits(n: u64) -> u32 {
-    (n as f64).log10().floor() as u32 + 1
+    // Integer logarithm, a float one rounds up just below powers of 10
+    n.checked_ilog10().map_or(1, |log| log + 1)
 }
 
 fn split_number(n: u64) -> Option<(u64, u64)> {
     let n_digits = count_digits(n);
 
-    if n_digits % 2 != 0 {
+    if !n_digits.is_multiple_of(2) {
         return None;
     }
 
@@ -92,10 +129,67 @@ fn split_number(n: u64) -> Option<(u64, u64)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::{prop, rng::Rng};
+
+    // Blink every stone one at a time, `None` if a stone gets too big for a u64
+    fn naive_process(stones: &[u64], blinks: usize) -> Option<u64> {
+        let mut stones: Vec<u128> = stones.iter().map(|n| *n as u128).collect();
+        for _ in 0..blinks {
+            let mut next = Vec::new();
+            for n in stones {
+                let digits = n.to_string();
+                if n == 0 {
+                    next.push(1);
+                } else if digits.len().is_multiple_of(2) {
+                    let (a, b) = digits.split_at(digits.len() / 2);
+                    next.push(a.parse().unwrap());
+                    next.push(b.parse().unwrap());
+                } else {
+                    next.push(n * 2024);
+                }
+            }
+            if next.iter().any(|n| *n > u64::MAX as u128) {
+                return None;
+            }
+            stones = next;
+        }
+        Some(stones.len() as u64)
+    }
+
+    // Small numbers, numbers of any length, and powers of 10 give or take one
+    fn stone(rng: &mut Rng) -> u64 {
+        let pow = 10u64.pow(rng.below(19) as u32);
+        match rng.below(3) {
+            0 => rng.below(100),
+            1 => pow + rng.below(pow * 9),
+            _ => pow - 1 + rng.below(2),
+        }
+    }
+
+    #[test]
+    fn process_test() {
+        let tests = [
+            (vec![125, 17], 6, Some(22)),
+            (vec![9223372036854775807], 1, None),
+            (vec![125, 9223372036854775807], 0, Some(2)),
+        ];
+
+        for (stones, blinks, exp) in tests {
+            assert_eq!(process(&stones, blinks), exp);
+        }
+    }
 
     #[test]
     fn count_digits_1() {
-        let tests = [(12, 2), (123, 3), (0, 1), (9, 1), (6666, 4), (66667, 5)];
+        let tests = [
+            (12, 2),
+            (123, 3),
+            (0, 1),
+            (9, 1),
+            (6666, 4),
+            (66667, 5),
+            (9999999999999979, 16),
+        ];
 
         for (input, exp) in tests {
             let res = count_digits(input);
@@ -117,4 +211,16 @@ mod tests {
             assert_eq!(res, exp);
         }
     }
+
+    #[test]
+    fn process_matches_naive() {
+        let gen = |rng: &mut Rng| {
+            let stones = (0..=rng.below(3)).map(|_| stone(rng)).collect::<Vec<_>>();
+            (stones, rng.below(7) as usize)
+        };
+
+        prop::check(200, gen, |(stones, blinks)| {
+            prop::same(process(stones, *blinks), naive_process(stones, *blinks))
+        });
+    }
 }
@@ -0,0 +1,9 @@
+use std::error::Error;
+
+use crate::process;
+
+pub fn solve(stones: &[u64], blinks: usize) -> Result<u64, Box<dyn Error>> {
+    let res = process(stones, blinks).ok_or("stone numbers or count overflow a u64")?;
+
+    Ok(res)
+}
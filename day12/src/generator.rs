@@ -0,0 +1,34 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+/// Random input from `seed`, a `width` x `height` garden (default: 140 x 140)
+/// with `kinds` kinds of plants (default: 26, at most 26)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["width", "height", "kinds"])?;
+    let width: usize = params.get("width", 140)?;
+    let height: usize = params.get("height", 140)?;
+    let kinds: u8 = params.get("kinds", 26)?;
+    if !(1..=26).contains(&kinds) {
+        return Err("there must be between 1 and 26 kinds of plants".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    // Plants mostly spread from their left and top neighbours, forming regions
+    let mut rows: Vec<Vec<char>> = Vec::new();
+    for y in 0..height {
+        let mut row: Vec<char> = Vec::new();
+        for x in 0..width {
+            let plant = match rng.below(6) {
+                0..=2 if x > 0 => row[x - 1],
+                3 | 4 if y > 0 => rows[y - 1][x],
+                _ => char::from(b'A' + rng.below(kinds as u64) as u8),
+            };
+            row.push(plant);
+        }
+        rows.push(row);
+    }
+
+    let rows: Vec<String> = rows.into_iter().map(String::from_iter).collect();
+    Ok(rows.join("\n") + "\n")
+}
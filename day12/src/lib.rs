@@ -1,74 +1,63 @@
-use core::fmt;
-use std::{
-    error::Error,
-    fmt::{Display, Formatter},
-    io::BufRead,
-};
+use std::{error::Error, io::BufRead};
 
-#[derive(Debug)]
-pub struct ParseError {}
+use common::{search::bfs, ParseError, Solution};
+use grid::{Matrix2D, Position};
 
-impl ParseError {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
+mod generator;
+mod part1;
+mod part2;
 
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "parse error")
-    }
-}
+pub use generator::generate;
 
-impl Error for ParseError {}
+#[derive(Debug, Default)]
+pub struct Day12;
 
-pub fn parse_input(input: impl BufRead) -> Result<Vec<Vec<char>>, Box<dyn Error>> {
-    let mut width = None;
-    input
-        .lines()
-        .map(|line| -> Result<Vec<char>, Box<dyn Error>> {
-            let line = line?;
-            let line: Vec<char> = line.chars().collect();
+impl Solution for Day12 {
+    type Input = Matrix2D<char>;
+    type Output1 = u64;
+    type Output2 = u64;
 
-            if let Some(width) = width {
-                if line.len() != width {
-                    return Err(Box::new(ParseError::new()));
-                }
-            }
-            width = Some(line.len());
+    fn parse(&self, input: impl BufRead) -> Result<Matrix2D<char>, Box<dyn Error>> {
+        parse_input(input)
+    }
 
-            Ok(line)
-        })
-        .collect()
-}
+    fn part1(&self, input: &Matrix2D<char>) -> Result<u64, Box<dyn Error>> {
+        part1::solve(input)
+    }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Position {
-    pub x: isize,
-    pub y: isize,
+    fn part2(&self, input: &Matrix2D<char>) -> Result<u64, Box<dyn Error>> {
+        part2::solve(input)
+    }
 }
 
-impl Position {
-    pub fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
+pub fn parse_input(input: impl BufRead) -> Result<Matrix2D<char>, Box<dyn Error>> {
+    let mut rows: Vec<Vec<char>> = Vec::new();
 
-    pub fn new_usize(x: usize, y: usize) -> Self {
-        Self {
-            x: x as isize,
-            y: y as isize,
+    for (idx, line) in input.lines().enumerate() {
+        let line = line?;
+        let row: Vec<char> = line.chars().collect();
+
+        // All rows must be as wide as the first one
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(Box::new(ParseError::row_width(idx, &line, first.len())));
+            }
         }
-    }
-}
 
-pub fn get_map_cell<'a, T>(map: &'a Vec<Vec<T>>, pos: Position) -> Option<&'a T> {
-    if pos.x < 0 || pos.x >= map.len() as isize || pos.y < 0 || pos.y >= map[0].len() as isize {
-        None
-    } else {
-        Some(&map[pos.y as usize][pos.x as usize])
+        rows.push(row);
     }
+
+    Ok(Matrix2D::from_rows(rows).ok_or("malformed map")?)
 }
 
-pub fn get_map_cell_unchecked<'a, T>(map: &'a Vec<Vec<T>>, pos: Position) -> &T {
-    &map[pos.y as usize][pos.x as usize]
+/// Positions of the region containing `pos`, connected plots of the same plant
+pub fn region(plots: &Matrix2D<char>, pos: Position) -> Vec<Position> {
+    let plant = plots[pos];
+    let paths = bfs(pos, |&pos| {
+        pos.neighbours4()
+            .into_iter()
+            .filter(|pos| plots.get(*pos) == Some(plant))
+    });
+
+    paths.reached().map(|(pos, _)| *pos).collect()
 }
@@ -0,0 +1,44 @@
+use std::error::Error;
+
+use crate::region;
+use grid::Matrix2D;
+
+pub fn solve(plots: &Matrix2D<char>) -> Result<u64, Box<dyn Error>> {
+    // Calculate result
+    let result = compute_result(plots);
+
+    Ok(result)
+}
+
+fn compute_result(plots: &Matrix2D<char>) -> u64 {
+    // Visited map
+    let mut visited = Matrix2D::new(plots.width(), plots.height(), false);
+
+    let mut sum = 0;
+
+    // Process cells
+    for pos in plots.positions() {
+        // Ignore visited cells
+        if visited[pos] {
+            continue;
+        }
+
+        let region = region(plots, pos);
+        let mut perimeter = 0;
+
+        for pos in &region {
+            visited[*pos] = true;
+
+            // Sides facing another plant or outside of the map add perimeter
+            perimeter += pos
+                .neighbours4()
+                .into_iter()
+                .filter(|next| plots.get(*next) != Some(plots[*pos]))
+                .count() as u64;
+        }
+
+        sum += perimeter * region.len() as u64;
+    }
+
+    sum
+}
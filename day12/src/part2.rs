@@ -0,0 +1,111 @@
+use std::{cmp::Ordering, error::Error};
+
+use crate::region;
+use grid::{Matrix2D, Position};
+
+pub fn solve(plots: &Matrix2D<char>) -> Result<u64, Box<dyn Error>> {
+    // Calculate result
+    let result = compute_result(plots);
+
+    Ok(result)
+}
+
+fn compute_result(plots: &Matrix2D<char>) -> u64 {
+    // Visited map
+    let mut visited = Matrix2D::new(plots.width(), plots.height(), false);
+
+    let mut sum = 0;
+
+    // Process cells
+    for pos in plots.positions() {
+        // Ignore visited cells
+        if visited[pos] {
+            continue;
+        }
+
+        let region = region(plots, pos);
+
+        let mut v_edges = Vec::new();
+        let mut h_edges = Vec::new();
+
+        for pos in &region {
+            visited[*pos] = true;
+            add_edges(plots, &mut v_edges, &mut h_edges, *pos);
+        }
+
+        let edges = count_unique_edges(&mut v_edges) + count_unique_edges(&mut h_edges);
+
+        sum += edges * region.len() as u64;
+    }
+
+    sum
+}
+
+// Add the sides of a plot facing another plant or outside of the map, sides
+// are grouped by line, with bottom and right sides offset apart from top and left ones
+fn add_edges(
+    map: &Matrix2D<char>,
+    v_edges: &mut Vec<(isize, isize)>,
+    h_edges: &mut Vec<(isize, isize)>,
+    pos: Position,
+) {
+    let cell = map[pos];
+    let is_edge = |pos: Position| map.get(pos) != Some(cell);
+
+    // Top
+    if is_edge(Position::new(pos.x, pos.y - 1)) {
+        h_edges.push((pos.y, pos.x));
+    }
+
+    // Bottom
+    if is_edge(Position::new(pos.x, pos.y + 1)) {
+        h_edges.push(((pos.y + 1) + 1000, pos.x));
+    }
+
+    // Left
+    if is_edge(Position::new(pos.x - 1, pos.y)) {
+        v_edges.push((pos.x, pos.y));
+    }
+
+    // Right
+    if is_edge(Position::new(pos.x + 1, pos.y)) {
+        v_edges.push(((pos.x + 1) + 1000, pos.y));
+    }
+}
+
+fn count_unique_edges(edges: &mut [(isize, isize)]) -> u64 {
+    edges.sort_by(|(a_group, a_cell), (b_group, b_cell)| {
+        if a_group == b_group {
+            if a_cell > b_cell {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        } else {
+            if a_group > b_group {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        }
+    });
+
+    let mut count = 0;
+    let mut last_pos: Option<(isize, isize)> = None;
+    for (group, cell) in edges {
+        if let Some((last_group, last_cell)) = last_pos {
+            if *group != last_group || *cell != last_cell + 1 {
+                // New edge!
+                count += 1;
+            }
+        }
+
+        last_pos = Some((*group, *cell));
+    }
+
+    if last_pos.is_some() {
+        count += 1;
+    }
+
+    count
+}
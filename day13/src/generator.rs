@@ -0,0 +1,38 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+/// Random input from `seed`, `machines` claw machines (default: 320)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["machines"])?;
+    let machines: usize = params.get("machines", 320)?;
+    let mut rng = Rng::new(seed);
+
+    let mut input = Vec::new();
+    for _ in 0..machines {
+        // Buttons are never collinear
+        let (a, b) = loop {
+            let a = (rng.range(10..=99), rng.range(10..=99));
+            let b = (rng.range(10..=99), rng.range(10..=99));
+            if a.0 * b.1 != a.1 * b.0 {
+                break (a, b);
+            }
+        };
+
+        // Half of the prizes can be won
+        let prize = match rng.ratio(1, 2) {
+            true => {
+                let (n, m) = (rng.range(0..=100), rng.range(0..=100));
+                (n * a.0 + m * b.0, n * a.1 + m * b.1)
+            }
+            false => (rng.range(1000..=20000), rng.range(1000..=20000)),
+        };
+
+        input.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+
+    Ok(input.join("\n"))
+}
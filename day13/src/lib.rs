@@ -1,28 +1,37 @@
-use core::fmt;
 use std::{
     error::Error,
-    fmt::{Display, Formatter},
     io::{self, BufRead},
 };
 
-// Pare result type
-type PResult<'a, T> = Result<(T, &'a str), PError>;
+use common::{
+    parser::{opt, parse_line, tag, unsigned, whitespace, PError, PResult},
+    ParseError, Solution,
+};
+
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
+
+#[derive(Debug, Default)]
+pub struct Day13;
 
-// Parse error
-#[derive(PartialEq, Debug, Clone)]
-struct PError {}
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+    type Output1 = u32;
+    type Output2 = i64;
 
-impl PError {
-    pub fn new() -> Self {
-        Self {}
+    fn parse(&self, input: impl BufRead) -> Result<Vec<Machine>, Box<dyn Error>> {
+        parse_input(input)
     }
-}
 
-impl std::error::Error for PError {}
+    fn part1(&self, input: &Vec<Machine>) -> Result<u32, Box<dyn Error>> {
+        part1::solve(input)
+    }
 
-impl Display for PError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Parse error!")
+    fn part2(&self, input: &Vec<Machine>) -> Result<i64, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
@@ -54,22 +63,45 @@ pub struct Machine {
     pub prize_pos: Position,
 }
 
+// Lines describing a machine
+const MACHINE_LINES: [&str; 3] = ["`Button A` line", "`Button B` line", "`Prize` line"];
+
+// Largest coordinate, far above the puzzle ones and low enough for part 2 not
+// to overflow
+const MAX_VALUE: i64 = 99_999;
+
 /// Parse input data
 pub fn parse_input(input: impl BufRead) -> Result<Vec<Machine>, Box<dyn Error>> {
     let lines = input.lines().collect::<Result<Vec<String>, io::Error>>()?;
 
     let machines = lines
         .iter()
-        .group_until(|line| line.is_empty())
+        .enumerate()
+        .group_until(|(_, line)| line.is_empty())
         .map(|group| {
-            // Wrong number of lines
-            if group.len() != 3 {
-                return Err(PError::new());
+            // Too many lines
+            if let Some(&(idx, line)) = group.get(MACHINE_LINES.len()) {
+                return Err(ParseError::at(idx, line, line, "empty line"));
             }
 
-            let (btn_a, _) = parse_button('A')(group[0])?;
-            let (btn_b, _) = parse_button('B')(group[1])?;
-            let (prize_pos, _) = parse_prize_location(group[2])?;
+            // Too few lines, the next one is either empty or missing
+            if let Some(expected) = MACHINE_LINES.get(group.len()) {
+                let idx = group[group.len() - 1].0 + 1;
+                return Err(match lines.get(idx) {
+                    Some(line) => ParseError::at(idx, line, line, *expected),
+                    None => ParseError::end_of_input(lines.len(), *expected),
+                });
+            }
+
+            let btn_a = parse_line(group[0].0, group[0].1, parse_button('A'))?;
+            let btn_b = parse_line(group[1].0, group[1].1, parse_button('B'))?;
+            // Both parts rely on a single way of reaching each position
+            if btn_a.x * btn_b.y == btn_a.y * btn_b.x {
+                let (idx, line) = group[1];
+                let expected = "button not collinear with button A";
+                return Err(ParseError::new(idx, line, 0, line.len(), expected));
+            }
+            let prize_pos = parse_line(group[2].0, group[2].1, parse_prize_location)?;
 
             Ok(Machine {
                 btn_a,
@@ -77,16 +109,17 @@ pub fn parse_input(input: impl BufRead) -> Result<Vec<Machine>, Box<dyn Error>>
                 prize_pos,
             })
         })
-        .collect::<Result<Vec<Machine>, PError>>()?;
+        .collect::<Result<Vec<Machine>, ParseError>>()?;
 
     Ok(machines)
 }
 
 fn parse_button<'a>(button_id: char) -> impl Fn(&str) -> PResult<Button> + 'a {
     move |input| {
-        let (_, input) = parse_tag(&format!("Button {}:", button_id))(input)?;
-        let (delta_x, input) = parse_value("X+")(input)?;
-        let (delta_y, input) = parse_value("Y+")(input)?;
+        let (_, input) = tag(&format!("Button {}:", button_id))(input)?;
+        // Buttons always move the claw
+        let (delta_x, input) = parse_value("X+", 1)(input)?;
+        let (delta_y, input) = parse_value("Y+", 1)(input)?;
 
         Ok((
             Button {
@@ -98,20 +131,25 @@ fn parse_button<'a>(button_id: char) -> impl Fn(&str) -> PResult<Button> + 'a {
     }
 }
 
-fn parse_prize_location(input: &str) -> PResult<Position> {
-    let (_, input) = parse_tag("Prize:")(input)?;
-    let (x, input) = parse_value("X=")(input)?;
-    let (y, input) = parse_value("Y=")(input)?;
+fn parse_prize_location(input: &str) -> PResult<'_, Position> {
+    let (_, input) = tag("Prize:")(input)?;
+    let (x, input) = parse_value("X=", 0)(input)?;
+    let (y, input) = parse_value("Y=", 0)(input)?;
 
     Ok((Position { x, y }, input))
 }
 
-fn parse_value<'a>(prefix: &'a str) -> impl Fn(&str) -> PResult<i64> + 'a {
+// Value from `min` to `MAX_VALUE` after `prefix`
+fn parse_value<'a>(prefix: &'a str, min: i64) -> impl Fn(&str) -> PResult<i64> + 'a {
     move |input| {
-        let (_, input) = parse_tag(" ")(input)?;
-        let (_, input) = parse_tag(prefix)(input)?;
-        let (val, input) = parse_i64(input)?;
-        let (_, input) = parse_opt(parse_tag(","))(input)?;
+        let (_, input) = whitespace(input)?;
+        let (_, input) = tag(prefix)(input)?;
+        let expected = || PError::new(input, format!("number from {} to {}", min, MAX_VALUE));
+        let (val, input) = unsigned(input).map_err(|_| expected())?;
+        if !(min..=MAX_VALUE).contains(&val) {
+            return Err(expected());
+        }
+        let (_, input) = opt(tag(","))(input)?;
 
         Ok((val, input))
     }
@@ -148,7 +186,7 @@ where
             break;
         }
 
-        if group.len() == 0 {
+        if group.is_empty() {
             return None;
         }
 
@@ -169,53 +207,35 @@ where
     }
 }
 
-// Parse a string of text
-fn parse_tag<'a>(tag: &'a str) -> impl Fn(&str) -> PResult<()> + 'a {
-    let tag = tag.to_owned();
-
-    move |input| {
-        // Check size
-        if input.len() < tag.len() {
-            return Err(PError::new());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_input_err() {
+        let tests = [
+            (
+                "Button A: X+0, Y+0\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400",
+                "line 1, column 13: expected number from 1 to 99999, found `0`",
+            ),
+            (
+                "Button A: X+94, Y+34\nButton B: X+47, Y+17\nPrize: X=8400, Y=5400",
+                "line 2, column 1: expected button not collinear with button A, found \
+                 `Button B: X+47, Y+17`",
+            ),
+            (
+                "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=100000",
+                "line 3, column 18: expected number from 0 to 99999, found `100000`",
+            ),
+            (
+                "Button A: X+94, Y+34\nButton B: X+22, Y+67\n",
+                "line 3, column 1: expected `Prize` line, found end of input",
+            ),
+        ];
+
+        for (input, exp) in tests {
+            let err = parse_input(input.as_bytes()).unwrap_err().to_string();
+            assert_eq!(err.lines().next(), Some(exp), "{:?}", input);
         }
-
-        if input[0..tag.len()] == tag {
-            return Ok(((), &input[tag.len()..]));
-        } else {
-            Err(PError::new())
-        }
-    }
-}
-
-// Parse a number with maximum number of digits
-fn parse_i64(input: &str) -> PResult<i64> {
-    // Find end of digits
-    let mut end: usize = 0;
-    for c in input.chars() {
-        if !c.is_ascii_digit() {
-            break;
-        }
-        end += 1;
-    }
-
-    // Construct number slice
-    let num = &input[0..end];
-
-    // Parse number
-    let num: i64 = match num.parse() {
-        Ok(res) => res,
-        Err(_) => return Err(PError::new()),
-    };
-
-    Ok((num, &input[end..]))
-}
-
-fn parse_opt<'a, P, T>(parser: P) -> impl Fn(&'a str) -> PResult<'a, Option<T>>
-where
-    P: Fn(&str) -> PResult<T>,
-{
-    move |input| match parser(input) {
-        Ok((val, input)) => Ok((Some(val), input)),
-        Err(_) => Ok((None, input)),
     }
 }
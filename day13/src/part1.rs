@@ -0,0 +1,71 @@
+use std::{collections::HashMap, error::Error};
+
+use crate::{Machine, Position};
+use common::parallel;
+
+// Times each button can be pressed at most
+const MAX_PRESSES: u32 = 100;
+
+pub fn solve(machines: &[Machine]) -> Result<u32, Box<dyn Error>> {
+    let res = compute_result(machines);
+
+    Ok(res)
+}
+
+fn compute_result(machines: &[Machine]) -> u32 {
+    parallel::map(machines, |machine| {
+        let mut memo = HashMap::new();
+        calculate_price(machine, &mut memo, Position { x: 0, y: 0 }, (0, 0)).unwrap_or(0)
+    })
+    .into_iter()
+    .sum()
+}
+
+fn calculate_price(
+    machine: &Machine,
+    memo: &mut HashMap<Position, Option<u32>>,
+    cur_pos: Position,
+    (presses_a, presses_b): (u32, u32),
+) -> Option<u32> {
+    // Overshot, or pressed a button too many times
+    if cur_pos.x > machine.prize_pos.x
+        || cur_pos.y > machine.prize_pos.y
+        || presses_a > MAX_PRESSES
+        || presses_b > MAX_PRESSES
+    {
+        return None;
+    }
+
+    // Found prize
+    if cur_pos == machine.prize_pos {
+        // It costs nothing
+        return Some(0);
+    }
+
+    // Check memo, buttons aren't collinear so the presses are the same for
+    // every path to a position
+    if let Some(memo) = memo.get(&cur_pos) {
+        // println!("Memo hit!");
+        return *memo;
+    }
+
+    let pos_a = machine.btn_a.apply_movement(cur_pos);
+    let cost_a = calculate_price(machine, memo, pos_a, (presses_a + 1, presses_b));
+    let pos_b = machine.btn_b.apply_movement(cur_pos);
+    let cost_b = calculate_price(machine, memo, pos_b, (presses_a, presses_b + 1));
+
+    let val = if let Some(cost_a) = cost_a {
+        let cost_a = cost_a + 3;
+        Some(if let Some(cost_b) = cost_b {
+            cost_a.min(cost_b + 1)
+        } else {
+            cost_a
+        })
+    } else {
+        cost_b.map(|cost_b| cost_b + 1)
+    };
+
+    memo.insert(cur_pos, val);
+
+    val
+}
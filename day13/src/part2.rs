@@ -0,0 +1,106 @@
+use std::error::Error;
+
+use crate::{Machine, Position};
+use common::parallel;
+
+pub fn solve(machines: &[Machine]) -> Result<i64, Box<dyn Error>> {
+    let res = compute_result(machines);
+
+    Ok(res)
+}
+
+fn compute_result(machines: &[Machine]) -> i64 {
+    parallel::map(machines, |machine| {
+        let machine = Machine {
+            prize_pos: Position {
+                x: machine.prize_pos.x + 10000000000000,
+                y: machine.prize_pos.y + 10000000000000,
+                // x: machine.prize_pos.x,
+                // y: machine.prize_pos.y,
+            },
+            btn_a: machine.btn_a,
+            btn_b: machine.btn_b,
+        };
+        calculate_price(&machine).unwrap_or(0)
+    })
+    .into_iter()
+    .sum()
+}
+
+fn calculate_price(machine: &Machine) -> Option<i64> {
+    let term_a = machine.btn_b.x * machine.prize_pos.y - machine.btn_b.y * machine.prize_pos.x;
+    let term_b = machine.btn_b.x * machine.btn_a.y - machine.btn_b.y * machine.btn_a.x;
+
+    if term_a % term_b == 0 {
+        let solution_a = term_a / term_b;
+        // A moves forward, it can't be pressed more times than the distance
+        if !(0..=machine.prize_pos.x).contains(&solution_a) {
+            return None;
+        }
+        let term_c = machine.prize_pos.y - solution_a * machine.btn_a.y;
+        if term_c % machine.btn_b.y == 0 {
+            // Both solutions are integers
+            let solution_b = term_c / machine.btn_b.y;
+
+            // Buttons can't be pressed a negative number of times
+            if solution_a >= 0 && solution_b >= 0 {
+                return Some(solution_a * 3 + solution_b);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Button;
+    use common::{prop, rng::Rng};
+
+    // Cheapest presses found by trying every number of A presses
+    fn brute_force_price(machine: &Machine) -> Option<i64> {
+        let (a, b, prize) = (machine.btn_a, machine.btn_b, machine.prize_pos);
+        (0..=prize.x / a.x)
+            .filter_map(|presses_a| {
+                let rest = Position {
+                    x: prize.x - presses_a * a.x,
+                    y: prize.y - presses_a * a.y,
+                };
+                let presses_b = rest.x / b.x;
+                (rest.x % b.x == 0 && rest.y == presses_b * b.y)
+                    .then_some(presses_a * 3 + presses_b)
+            })
+            .min()
+    }
+
+    #[test]
+    fn calculate_price_matches_brute_force() {
+        // Buttons and prize as (x, y), half of the prizes are reachable
+        let gen = |rng: &mut Rng| {
+            let mut coords = || (rng.range(1..=30), rng.range(1..=30));
+            let (a, b) = (coords(), coords());
+            let prize = match rng.ratio(1, 2) {
+                true => {
+                    let (n, m) = (rng.range(0..=50), rng.range(0..=50));
+                    (n * a.0 + m * b.0, n * a.1 + m * b.1)
+                }
+                false => (rng.range(0..=1000), rng.range(0..=1000)),
+            };
+            (a, b, prize)
+        };
+
+        prop::check(500, gen, |&((ax, ay), (bx, by), (x, y))| {
+            // Puzzle buttons move forward on both axes and aren't collinear
+            if ax <= 0 || ay <= 0 || bx <= 0 || by <= 0 || ax * by == ay * bx || x < 0 || y < 0 {
+                return Ok(());
+            }
+            let machine = Machine {
+                btn_a: Button { x: ax, y: ay },
+                btn_b: Button { x: bx, y: by },
+                prize_pos: Position { x, y },
+            };
+            prop::same(calculate_price(&machine), brute_force_price(&machine))
+        });
+    }
+}
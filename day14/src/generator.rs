@@ -0,0 +1,31 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+/// Random input from `seed`, `robots` robots (default: 500) on a `width` x
+/// `height` floor (default: 101 x 103)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["robots", "width", "height"])?;
+    let robots: usize = params.get("robots", 500)?;
+    let width: i64 = params.get("width", 101)?;
+    let height: i64 = params.get("height", 103)?;
+    if width <= 0 || height <= 0 || robots as i64 > width * height {
+        return Err("the robots don't fit on the floor".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    // Robots are on distinct tiles at some second, so part 2 ends
+    let second = rng.range(1..=width * height);
+    let mut tiles: Vec<i64> = (0..width * height).collect();
+    rng.shuffle(&mut tiles);
+
+    let mut input = String::new();
+    for tile in &tiles[..robots] {
+        let (vel_x, vel_y) = (rng.range(-100..=100), rng.range(-100..=100));
+        let x = (tile % width - vel_x * second).rem_euclid(width);
+        let y = (tile / width - vel_y * second).rem_euclid(height);
+        input.push_str(&format!("p={},{} v={},{}\n", x, y, vel_x, vel_y));
+    }
+
+    Ok(input)
+}
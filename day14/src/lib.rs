@@ -1,32 +1,64 @@
-use core::fmt;
-use std::{
-    error::Error,
-    fmt::{Display, Formatter},
-    io::BufRead,
+use std::{error::Error, io::BufRead};
+
+use common::{
+    parser::{parse_line, signed, tag, PError, PResult},
+    Params, Solution,
 };
 
-// Pare result type
-type PResult<'a, T> = Result<(T, &'a str), PError>;
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
 
-// Parse error
-#[derive(PartialEq, Debug, Clone)]
-struct PError {}
+#[derive(Debug)]
+pub struct Day14 {
+    // Board size
+    pub width: i64,
+    pub height: i64,
+}
 
-impl PError {
-    pub fn new() -> Self {
-        Self {}
+impl Default for Day14 {
+    fn default() -> Self {
+        Self {
+            width: 101,
+            height: 103,
+        }
     }
 }
 
-impl std::error::Error for PError {}
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+    type Output1 = u64;
+    type Output2 = usize;
+
+    fn configure(&mut self, params: &Params) -> Result<(), Box<dyn Error>> {
+        params.check_known(&["width", "height"])?;
+        self.width = params.get("width", self.width)?;
+        self.height = params.get("height", self.height)?;
 
-impl Display for PError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Parse error!")
+        // Positions wrap around the board
+        if self.width <= 0 || self.height <= 0 {
+            return Err("board size must be positive".into());
+        }
+
+        Ok(())
+    }
+
+    fn parse(&self, input: impl BufRead) -> Result<Vec<Robot>, Box<dyn Error>> {
+        parse_input(input, self.width, self.height)
+    }
+
+    fn part1(&self, input: &Vec<Robot>) -> Result<u64, Box<dyn Error>> {
+        part1::solve(input, self.width, self.height)
+    }
+
+    fn part2(&self, input: &Vec<Robot>) -> Result<usize, Box<dyn Error>> {
+        part2::solve(input, self.width, self.height)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Robot {
     pub x: i64,
     pub y: i64,
@@ -34,68 +66,38 @@ pub struct Robot {
     pub vel_y: i64,
 }
 
-/// Parse input data
-pub fn parse_input(input: impl BufRead) -> Result<Vec<Robot>, Box<dyn Error>> {
+/// Parse input data, robots must start on a `width` by `height` board
+pub fn parse_input(
+    input: impl BufRead,
+    width: i64,
+    height: i64,
+) -> Result<Vec<Robot>, Box<dyn Error>> {
     input
         .lines()
-        .map(|line| {
-            let line = line?;
-            let (robot, _) = parse_robot(&line)?;
-            Ok(robot)
-        })
+        .enumerate()
+        .map(|(idx, line)| Ok(parse_line(idx, &line?, parse_robot(width, height))?))
         .collect()
 }
 
-fn parse_robot(input: &str) -> PResult<Robot> {
-    let (_, input) = parse_tag("p=")(input)?;
-    let (x, input) = parse_i64(input)?;
-    let (_, input) = parse_tag(",")(input)?;
-    let (y, input) = parse_i64(input)?;
-    let (_, input) = parse_tag(" v=")(input)?;
-    let (vel_x, input) = parse_i64(input)?;
-    let (_, input) = parse_tag(",")(input)?;
-    let (vel_y, input) = parse_i64(input)?;
-
-    Ok((Robot { x, y, vel_x, vel_y }, input))
-}
-
-// Parse a string of text
-fn parse_tag<'a>(tag: &'a str) -> impl Fn(&str) -> PResult<()> + 'a {
-    let tag = tag.to_owned();
-
+fn parse_robot(width: i64, height: i64) -> impl Fn(&str) -> PResult<Robot> {
     move |input| {
-        // Check size
-        if input.len() < tag.len() {
-            return Err(PError::new());
-        }
-
-        if input[0..tag.len()] == tag {
-            return Ok(((), &input[tag.len()..]));
-        } else {
-            Err(PError::new())
-        }
+        let (_, input) = tag("p=")(input)?;
+        let (x, input) = coordinate(width)(input)?;
+        let (_, input) = tag(",")(input)?;
+        let (y, input) = coordinate(height)(input)?;
+        let (_, input) = tag(" v=")(input)?;
+        let (vel_x, input) = signed(input)?;
+        let (_, input) = tag(",")(input)?;
+        let (vel_y, input) = signed(input)?;
+
+        Ok((Robot { x, y, vel_x, vel_y }, input))
     }
 }
 
-// Parse a number with maximum number of digits
-fn parse_i64(input: &str) -> PResult<i64> {
-    // Find end of digits
-    let mut end: usize = 0;
-    for c in input.chars() {
-        if !c.is_ascii_digit() && c != '-' {
-            break;
-        }
-        end += 1;
+// Coordinate on a board of `size` tiles
+fn coordinate(size: i64) -> impl Fn(&str) -> PResult<i64> {
+    move |input| match signed(input) {
+        Ok((val, rest)) if (0..size).contains(&val) => Ok((val, rest)),
+        _ => Err(PError::new(input, format!("number from 0 to {}", size - 1))),
     }
-
-    // Construct number slice
-    let num = &input[0..end];
-
-    // Parse number
-    let num: i64 = match num.parse() {
-        Ok(res) => res,
-        Err(_) => return Err(PError::new()),
-    };
-
-    Ok((num, &input[end..]))
 }
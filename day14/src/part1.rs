@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use crate::Robot;
+
+const STEPS: u64 = 100;
+
+pub fn solve(robots: &[Robot], width: i64, height: i64) -> Result<u64, Box<dyn Error>> {
+    // Compute result
+    let res = compute_result(robots, width, height);
+
+    Ok(res)
+}
+
+fn compute_result(robots: &[Robot], width: i64, height: i64) -> u64 {
+    let mut q1 = 0;
+    let mut q2 = 0;
+    let mut q3 = 0;
+    let mut q4 = 0;
+
+    for robot in robots {
+        // dbg!(robot);
+        let (x, y) = compute_robot_position(robot, width, height);
+        // dbg!((x, y));
+
+        if x > (width - 1) / 2 {
+            if y < (height - 1) / 2 {
+                q1 += 1;
+            } else if y > (height - 1) / 2 {
+                q2 += 1;
+            }
+        } else if x < (width - 1) / 2 {
+            if y < (height - 1) / 2 {
+                q4 += 1;
+            } else if y > (height - 1) / 2 {
+                q3 += 1;
+            }
+        }
+    }
+
+    // dbg!((q1, q2, q3, q4));
+
+    q1 * q2 * q3 * q4
+}
+
+fn compute_robot_position(robot: &Robot, width: i64, height: i64) -> (i64, i64) {
+    let x = mod_mult(robot.x, robot.vel_x, STEPS, width);
+    let y = mod_mult(robot.y, robot.vel_y, STEPS, height);
+    (x, y)
+}
+
+fn mod_mult(start: i64, b: i64, e: u64, p: i64) -> i64 {
+    let mut res = start;
+    // Speeds can be anything, only their remainder matters
+    let b = b.rem_euclid(p);
+
+    for _ in 0..e {
+        res = (res + b) % p;
+    }
+
+    if res < 0 {
+        res += p;
+    }
+
+    res
+}
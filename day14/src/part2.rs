@@ -0,0 +1,79 @@
+use std::error::Error;
+
+use crate::Robot;
+use common::diag;
+
+pub fn solve(robots: &[Robot], width: i64, height: i64) -> Result<usize, Box<dyn Error>> {
+    // Robots are moved in place, speeds can be anything but only their
+    // remainder matters
+    let mut robots: Vec<Robot> = robots
+        .iter()
+        .map(|robot| Robot {
+            vel_x: robot.vel_x.rem_euclid(width),
+            vel_y: robot.vel_y.rem_euclid(height),
+            ..*robot
+        })
+        .collect();
+
+    // Compute result
+    let res = do_computation(&mut robots, width, height)
+        .ok_or("robots never all stand on different tiles")?;
+
+    Ok(res)
+}
+
+fn do_computation(robots: &mut [Robot], width: i64, height: i64) -> Option<usize> {
+    // for second in 1..=STEPS {
+    let mut second = 1;
+    loop {
+        // Robots are back where they started after `width * height` seconds
+        if second as i64 > width * height {
+            return None;
+        }
+
+        let mut board = vec![vec![0u32; width as usize]; height as usize];
+        // Compute new board positions
+        for robot in robots.iter_mut() {
+            // Next robot position
+            robot.x = (robot.x + robot.vel_x) % width;
+            robot.y = (robot.y + robot.vel_y) % height;
+
+            if robot.x < 0 {
+                robot.x += width;
+            }
+
+            if robot.y < 0 {
+                robot.y += height;
+            }
+
+            board[robot.y as usize][robot.x as usize] += 1;
+        }
+        diag::frame("robots", || render_board(&board));
+
+        if board.iter().map(|row| row.iter().max()).max() == Some(Some(&1)) {
+            diag::debug(|| format!("Second {}:\n{}", second, render_board(&board)));
+            break;
+        }
+
+        second += 1;
+    }
+    // }
+
+    Some(second)
+}
+
+// Render the robot count of every tile, empty tiles are blank
+fn render_board(board: &[Vec<u32>]) -> String {
+    let mut text = String::new();
+    for row in board {
+        for cell in row {
+            if *cell == 0 {
+                text.push(' ');
+            } else {
+                text.push_str(&cell.to_string());
+            }
+        }
+        text.push('\n');
+    }
+    text
+}
@@ -0,0 +1,48 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+// Moves per line
+const LINE_MOVES: usize = 1000;
+
+/// Random input from `seed`, a `width` x `height` warehouse (default: 50 x 50)
+/// with `boxes` percent of boxes (default: 30), `walls` percent of walls
+/// (default: 5) and `moves` moves (default: 20000)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["width", "height", "boxes", "walls", "moves"])?;
+    let width: usize = params.get("width", 50)?;
+    let height: usize = params.get("height", 50)?;
+    let boxes: u64 = params.get("boxes", 30)?;
+    let walls: u64 = params.get("walls", 5)?;
+    let moves: usize = params.get("moves", 20000)?;
+    if width < 3 || height < 3 {
+        return Err("the warehouse must be at least 3 x 3".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    // Walls all around
+    let mut rows: Vec<Vec<char>> = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| match rng.below(100) {
+                    _ if x == 0 || y == 0 || x == width - 1 || y == height - 1 => '#',
+                    n if n < walls => '#',
+                    n if n < walls + boxes => 'O',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+    let x = rng.range(1..=width as i64 - 2) as usize;
+    let y = rng.range(1..=height as i64 - 2) as usize;
+    rows[y][x] = '@';
+
+    let mut input: Vec<String> = rows.into_iter().map(String::from_iter).collect();
+    input.push(String::new());
+    let moves: Vec<char> = (0..moves)
+        .map(|_| *rng.pick(&['^', 'v', '<', '>']))
+        .collect();
+    input.extend(moves.chunks(LINE_MOVES).map(String::from_iter));
+
+    Ok(input.join("\n") + "\n")
+}
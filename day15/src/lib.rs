@@ -1,194 +1,159 @@
-use core::fmt;
 use std::{
     error::Error,
-    fmt::{Display, Formatter},
     io::{self, BufRead},
     vec,
 };
 
-#[derive(Debug)]
-pub struct ParseError {}
+use common::{ParseError, Solution};
+use grid::{Direction, DisplayChar, Matrix2D, Position};
 
-impl ParseError {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
-
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "parse error")
-    }
-}
+mod generator;
+mod part1;
+mod part2;
 
-impl Error for ParseError {}
+pub use generator::generate;
+pub use part1::RobotMoves;
+pub use part2::WideRobotMoves;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Position {
-    pub x: isize,
-    pub y: isize,
-}
+#[derive(Debug, Default)]
+pub struct Day15;
 
-impl Position {
-    pub fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
+impl Solution for Day15 {
+    type Input = Warehouse;
+    type Output1 = isize;
+    type Output2 = isize;
 
-    pub fn new_usize(x: usize, y: usize) -> Self {
-        Self {
-            x: x as isize,
-            y: y as isize,
-        }
+    fn parse(&self, input: impl BufRead) -> Result<Warehouse, Box<dyn Error>> {
+        parse_input(input)
     }
 
-    pub fn moved(&self, m: Move) -> Self {
-        let (dx, dy) = m.get_deltas();
-        Position::new(self.x + dx, self.y + dy)
+    fn part1(&self, input: &Warehouse) -> Result<isize, Box<dyn Error>> {
+        part1::solve(input)
     }
 
-    pub fn apply_move(&mut self, m: Move) {
-        let (dx, dy) = m.get_deltas();
-        self.x += dx;
-        self.y += dy;
+    fn part2(&self, input: &Warehouse) -> Result<isize, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-pub enum Move {
-    Up,
-    Down,
-    Left,
-    Right,
+pub enum Cell {
+    Box,
+    Wall,
+    Empty,
 }
 
-impl Move {
-    pub fn from_char(c: char) -> Result<Self, ParseError> {
-        match c {
-            '^' => Ok(Self::Up),
-            'v' => Ok(Self::Down),
-            '<' => Ok(Self::Left),
-            '>' => Ok(Self::Right),
-            _ => Err(ParseError::new()),
-        }
-    }
-
-    pub fn get_deltas(&self) -> (isize, isize) {
+impl DisplayChar for Cell {
+    fn display_char(&self) -> char {
         match self {
-            Move::Up => (0, -1),
-            Move::Down => (0, 1),
-            Move::Left => (-1, 0),
-            Move::Right => (1, 0),
-        }
-    }
-
-    pub fn reverse(&self) -> Self {
-        match self {
-            Move::Up => Self::Down,
-            Move::Down => Self::Up,
-            Move::Left => Self::Right,
-            Move::Right => Self::Left,
+            Self::Box => 'O',
+            Self::Wall => '#',
+            Self::Empty => ' ',
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum Cell {
-    Box,
-    Wall,
-    Empty,
-}
+pub type Board = Matrix2D<Cell>;
 
-impl Display for Cell {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Box => write!(f, "O"),
-            Self::Wall => write!(f, "#"),
-            Self::Empty => write!(f, " "),
+// Board, robot moves and robot position
+pub type Warehouse = (Board, Vec<Direction>, Position);
+
+/// Parse the list of robot moves following the board
+pub fn parse_moves<'a>(
+    lines: impl Iterator<Item = (usize, &'a String)>,
+) -> Result<Vec<Direction>, ParseError> {
+    let mut moves = vec![];
+    for (idx, line) in lines {
+        for (offset, c) in line.char_indices() {
+            let dir = Direction::from_arrow(c)
+                .ok_or_else(|| ParseError::new(idx, line, offset, c.len_utf8(), "robot move"))?;
+            moves.push(dir);
         }
     }
+
+    Ok(moves)
 }
 
-pub fn parse_input(
-    input: impl BufRead,
-) -> Result<(Vec<Vec<Cell>>, Vec<Move>, Position), Box<dyn Error>> {
-    let lines = input.lines().collect::<Result<Vec<String>, io::Error>>()?;
-    let mut lines = lines.iter();
+pub fn parse_input(input: impl BufRead) -> Result<Warehouse, Box<dyn Error>> {
+    let all_lines = input.lines().collect::<Result<Vec<String>, io::Error>>()?;
+    let mut lines = all_lines.iter().enumerate();
 
     // Robot position
     let mut robot_pos: Option<Position> = None;
 
     // Parse board
-    let board = lines
-        .by_ref()
-        .take_while(|line| !line.is_empty())
-        .enumerate()
-        .map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(x, c)| {
-                    Ok(match c {
-                        '#' => Cell::Wall,
-                        'O' => Cell::Box,
-                        '@' => {
-                            // Set robot position
-                            if let Some(_) = robot_pos {
-                                return Err(ParseError::new());
-                            }
-                            robot_pos = Some(Position::new_usize(x, y));
-
-                            Cell::Empty
-                        }
-                        _ => Cell::Empty,
-                    })
-                })
-                .collect()
-        })
-        .collect::<Result<Vec<Vec<Cell>>, ParseError>>()?;
+    let mut rows: Vec<Vec<Cell>> = Vec::new();
+    for (y, line) in lines.by_ref().take_while(|(_, line)| !line.is_empty()) {
+        let row = line
+            .char_indices()
+            .enumerate()
+            .map(|(x, (offset, c))| match c {
+                '#' => Ok(Cell::Wall),
+                'O' => Ok(Cell::Box),
+                '.' => Ok(Cell::Empty),
+                '@' => {
+                    // Set robot position
+                    if robot_pos.is_some() {
+                        return Err(ParseError::new(y, line, offset, 1, "a single robot"));
+                    }
+                    robot_pos = Some(Position::new_usize(x, y));
+
+                    Ok(Cell::Empty)
+                }
+                _ => Err(ParseError::new(
+                    y,
+                    line,
+                    offset,
+                    c.len_utf8(),
+                    "`#`, `O`, `.` or `@`",
+                )),
+            })
+            .collect::<Result<Vec<Cell>, ParseError>>()?;
+
+        // All rows must be as wide as the first one
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(Box::new(ParseError::row_width(y, line, first.len())));
+            }
+        }
 
-    // Parse moves
-    let mut moves = vec![];
-    for line in lines {
-        moves.append(
-            &mut line
-                .chars()
-                .map(|c| Move::from_char(c))
-                .collect::<Result<Vec<Move>, ParseError>>()?,
-        );
+        rows.push(row);
     }
-
-    let robot_pos = match robot_pos {
-        Some(pos) => pos,
-        None => {
-            return Err(Box::new(ParseError::new()));
+    let board = Matrix2D::from_rows(rows).ok_or("malformed board")?;
+
+    // Walls around the warehouse keep the robot and boxes in
+    for (pos, cell) in board.iter() {
+        let (x, y) = (pos.x as usize, pos.y as usize);
+        let border = x == 0 || y == 0 || x + 1 == board.width() || y + 1 == board.height();
+        if border && !matches!(cell, Cell::Wall) {
+            let line = &all_lines[y];
+            let (offset, c) = line
+                .char_indices()
+                .nth(x)
+                .expect("rows have a cell per char");
+            return Err(Box::new(ParseError::new(
+                y,
+                line,
+                offset,
+                c.len_utf8(),
+                "wall `#`",
+            )));
         }
-    };
+    }
 
-    Ok((board, moves, robot_pos))
-}
+    // The robot must be on the board
+    let robot_pos = robot_pos.ok_or(ParseError::end_of_input(board.height(), "a robot"))?;
 
-pub fn get_map_cell<T: Copy>(map: &Vec<Vec<T>>, pos: Position) -> Option<T> {
-    if pos.x < 0 || pos.x >= map.len() as isize || pos.y < 0 || pos.y >= map[0].len() as isize {
-        None
-    } else {
-        Some(map[pos.y as usize][pos.x as usize])
-    }
-}
+    // Parse moves
+    let moves = parse_moves(lines)?;
 
-pub fn set_map_cell<T: Copy>(map: &mut Vec<Vec<T>>, pos: Position, val: T) {
-    if !(pos.x < 0 || pos.x >= map.len() as isize || pos.y < 0 || pos.y >= map[0].len() as isize) {
-        map[pos.y as usize][pos.x as usize] = val;
-    }
+    Ok((board, moves, robot_pos))
 }
 
-pub fn print_board(board: &Vec<Vec<Cell>>, robot_pos: Position) {
-    for (y, row) in board.iter().enumerate() {
-        for (x, cell) in row.iter().enumerate() {
-            if Position::new_usize(x, y) == robot_pos {
-                print!("@");
-            } else {
-                print!("{}", cell);
-            }
-        }
-        println!();
-    }
+/// Render the warehouse with the robot in it, for debugging
+pub fn render_board(board: &Board, robot_pos: Position) -> String {
+    let mut text = board.map(|cell| cell.display_char());
+    text.set(robot_pos, '@');
+
+    text.render()
 }
@@ -0,0 +1,128 @@
+use std::error::Error;
+
+use crate::{render_board, Cell, Warehouse};
+use common::diag;
+use grid::{Direction, DisplayChar, Matrix2D, Position, Simulation};
+
+pub fn solve((board, moves, robot_pos): &Warehouse) -> Result<isize, Box<dyn Error>> {
+    let mut board = board.clone();
+    let mut robot_pos = *robot_pos;
+
+    // Apply all moves
+    diag::frame("robot", || render_board(&board, robot_pos));
+    for m in moves {
+        apply_move(&mut board, &mut robot_pos, *m);
+        diag::frame("robot", || render_board(&board, robot_pos));
+    }
+
+    Ok(compute_gps_sum(&board))
+}
+
+/// Robot applying its moves one at a time
+pub struct RobotMoves {
+    board: Matrix2D<Cell>,
+    moves: Vec<Direction>,
+    robot_pos: Position,
+    done: usize,
+}
+
+impl RobotMoves {
+    pub fn new((board, moves, robot_pos): Warehouse) -> Self {
+        Self {
+            board,
+            moves,
+            robot_pos,
+            done: 0,
+        }
+    }
+}
+
+impl Simulation for RobotMoves {
+    fn grid(&self) -> Matrix2D<char> {
+        let mut grid = self.board.map(|cell| cell.display_char());
+        grid.set(self.robot_pos, '@');
+        grid
+    }
+
+    fn actor(&self) -> Option<Position> {
+        Some(self.robot_pos)
+    }
+
+    fn step(&mut self) -> Option<String> {
+        let m = *self.moves.get(self.done)?;
+        let prev = self.robot_pos;
+        apply_move(&mut self.board, &mut self.robot_pos, m);
+        self.done += 1;
+
+        Some(move_label(
+            self.done,
+            self.moves.len(),
+            m,
+            prev == self.robot_pos,
+        ))
+    }
+}
+
+/// Describe the `idx`th robot move, counting from 1
+pub fn move_label(idx: usize, total: usize, m: Direction, blocked: bool) -> String {
+    let blocked = if blocked { " (blocked)" } else { "" };
+    format!("move {} of {}: {}{}", idx, total, m.display_char(), blocked)
+}
+
+fn apply_move(board: &mut Matrix2D<Cell>, robot_pos: &mut Position, m: Direction) {
+    // Count boxes that have to be moved
+    let n_boxes = match count_boxes_to_move(board, *robot_pos, m) {
+        Some(n) => n,
+        None => return,
+    };
+
+    // Move boxes
+    move_boxes(board, *robot_pos, m, n_boxes);
+
+    // Move robot
+    robot_pos.apply_move(m);
+}
+
+fn count_boxes_to_move(board: &Matrix2D<Cell>, robot_pos: Position, m: Direction) -> Option<usize> {
+    let mut cursor = robot_pos;
+    for i in 0.. {
+        // Move cursor
+        cursor.apply_move(m);
+
+        if let Some(cell) = board.get(cursor) {
+            match cell {
+                Cell::Wall => break,
+                Cell::Empty => return Some(i),
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+fn move_boxes(board: &mut Matrix2D<Cell>, robot_pos: Position, m: Direction, n_boxes: usize) {
+    let mut cursor = robot_pos;
+
+    // Move cursor to end
+    for _ in 0..n_boxes {
+        cursor.apply_move(m);
+    }
+
+    // Go backward moving boxes
+    for _ in 0..n_boxes {
+        let val = board.get(cursor);
+        if let Some(val) = val {
+            board.set(cursor.moved(m), val);
+            board.set(cursor, Cell::Empty);
+        }
+        cursor.apply_move(m.reverse());
+    }
+}
+
+fn compute_gps_sum(board: &Matrix2D<Cell>) -> isize {
+    board
+        .iter()
+        .filter(|(_, cell)| matches!(cell, Cell::Box))
+        .map(|(pos, _)| 100 * pos.y + pos.x)
+        .sum()
+}
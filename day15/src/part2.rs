@@ -0,0 +1,273 @@
+use std::error::Error;
+
+use crate::{part1::move_label, Board, Cell, Warehouse};
+use common::diag;
+use grid::{Direction, Matrix2D, Position, Simulation};
+
+type WideBoard = Matrix2D<char>;
+
+pub fn solve((board, moves, robot_pos): &Warehouse) -> Result<isize, Box<dyn Error>> {
+    // Widen board
+    let mut board = widen_board(board);
+    let mut robot_pos = Position::new(robot_pos.x * 2, robot_pos.y);
+
+    // Apply all moves
+    diag::frame("robot", || render_wide_board(&board, robot_pos));
+    for m in moves {
+        apply_move(&mut board, &mut robot_pos, *m);
+        diag::frame("robot", || render_wide_board(&board, robot_pos));
+    }
+
+    // Calculate GPS sum
+    Ok(calculate_gps_sum(&board))
+}
+
+/// Robot applying its moves one at a time in the wide warehouse
+pub struct WideRobotMoves {
+    board: WideBoard,
+    moves: Vec<Direction>,
+    robot_pos: Position,
+    done: usize,
+}
+
+impl WideRobotMoves {
+    pub fn new((board, moves, robot_pos): Warehouse) -> Self {
+        Self {
+            board: widen_board(&board),
+            moves,
+            robot_pos: Position::new(robot_pos.x * 2, robot_pos.y),
+            done: 0,
+        }
+    }
+}
+
+impl Simulation for WideRobotMoves {
+    fn grid(&self) -> Matrix2D<char> {
+        let mut grid = self.board.clone();
+        grid.set(self.robot_pos, '@');
+        grid
+    }
+
+    fn actor(&self) -> Option<Position> {
+        Some(self.robot_pos)
+    }
+
+    fn step(&mut self) -> Option<String> {
+        let m = *self.moves.get(self.done)?;
+        let prev = self.robot_pos;
+        apply_move(&mut self.board, &mut self.robot_pos, m);
+        self.done += 1;
+
+        Some(move_label(
+            self.done,
+            self.moves.len(),
+            m,
+            prev == self.robot_pos,
+        ))
+    }
+}
+
+fn apply_move(board: &mut WideBoard, robot_pos: &mut Position, m: Direction) {
+    match m {
+        Direction::Up => {
+            apply_move_up(board, robot_pos);
+        }
+        Direction::Down => {
+            apply_move_down(board, robot_pos);
+        }
+        Direction::Left => {
+            apply_move_left(board, robot_pos);
+        }
+        Direction::Right => {
+            apply_move_right(board, robot_pos);
+        }
+    }
+}
+
+fn apply_move_left(board: &mut WideBoard, robot_pos: &mut Position) {
+    // Count boxes going left
+    let mut x1 = None;
+    for x in (0..robot_pos.x).rev() {
+        let cell = board[Position::new(x, robot_pos.y)];
+        if cell == '.' {
+            x1 = Some(x);
+            break;
+        } else if cell == '#' {
+            break;
+        }
+    }
+
+    if let Some(x1) = x1 {
+        // We can do the move
+
+        // Move boxes
+        for x in x1..robot_pos.x {
+            board[Position::new(x, robot_pos.y)] = board[Position::new(x + 1, robot_pos.y)];
+        }
+
+        // Move robot
+        board[*robot_pos] = '.';
+        robot_pos.apply_move(Direction::Left);
+    }
+}
+
+fn apply_move_right(board: &mut WideBoard, robot_pos: &mut Position) {
+    // Count boxes going left
+    let mut x1 = None;
+    for x in ((robot_pos.x as usize) + 1)..board.width() {
+        let cell = board[Position::new_usize(x, robot_pos.y as usize)];
+        if cell == '.' {
+            x1 = Some(x);
+            break;
+        } else if cell == '#' {
+            break;
+        }
+    }
+
+    if let Some(x1) = x1 {
+        // We can do the move
+
+        // Move boxes
+        for x in ((robot_pos.x as usize + 1)..=x1).rev() {
+            board[Position::new_usize(x, robot_pos.y as usize)] =
+                board[Position::new_usize(x - 1, robot_pos.y as usize)];
+        }
+
+        // Move robot
+        board[*robot_pos] = '.';
+        robot_pos.apply_move(Direction::Right);
+    }
+}
+
+fn apply_move_up(board: &mut WideBoard, robot_pos: &mut Position) {
+    if move_up(
+        board,
+        robot_pos.y as usize - 1,
+        robot_pos.x as usize,
+        robot_pos.x as usize,
+    ) {
+        robot_pos.apply_move(Direction::Up);
+    }
+}
+
+fn apply_move_down(board: &mut WideBoard, robot_pos: &mut Position) {
+    if move_down(
+        board,
+        robot_pos.y as usize + 1,
+        robot_pos.x as usize,
+        robot_pos.x as usize,
+    ) {
+        robot_pos.apply_move(Direction::Down);
+    }
+}
+
+fn move_up(board: &mut WideBoard, y: usize, range_xa: usize, range_xb: usize) -> bool {
+    let mut xa = board.width() - 1;
+    let mut xb = 0;
+    // Compute new push range, while checking if the whole range is free
+    let mut all_free = true;
+    for x in range_xa..=range_xb {
+        let cell = board[Position::new_usize(x, y)];
+        if cell == '[' {
+            xa = xa.min(x);
+            xb = xb.max(x + 1);
+            all_free = false;
+        } else if cell == ']' {
+            xa = xa.min(x - 1);
+            xb = xb.max(x);
+            all_free = false;
+        } else if cell == '#' {
+            // Wall, we can't move!
+            return false;
+        }
+    }
+
+    if all_free {
+        return true;
+    }
+
+    // Move row above
+    if !move_up(board, y - 1, xa, xb) {
+        return false;
+    }
+
+    // Do move
+    for x in xa..=xb {
+        board[Position::new_usize(x, y - 1)] = board[Position::new_usize(x, y)];
+        board[Position::new_usize(x, y)] = '.';
+    }
+
+    true
+}
+
+fn move_down(board: &mut WideBoard, y: usize, range_xa: usize, range_xb: usize) -> bool {
+    let mut xa = board.width() - 1;
+    let mut xb = 0;
+    // Compute new push range, while checking if the whole range is free
+    let mut all_free = true;
+    for x in range_xa..=range_xb {
+        let cell = board[Position::new_usize(x, y)];
+        if cell == '[' {
+            xa = xa.min(x);
+            xb = xb.max(x + 1);
+            all_free = false;
+        } else if cell == ']' {
+            xa = xa.min(x - 1);
+            xb = xb.max(x);
+            all_free = false;
+        } else if cell == '#' {
+            // Wall, we can't move!
+            return false;
+        }
+    }
+
+    if all_free {
+        return true;
+    }
+
+    // Move row above
+    if !move_down(board, y + 1, xa, xb) {
+        return false;
+    }
+
+    // Do move
+    for x in xa..=xb {
+        board[Position::new_usize(x, y + 1)] = board[Position::new_usize(x, y)];
+        board[Position::new_usize(x, y)] = '.';
+    }
+
+    true
+}
+
+/// Widen board, every cell becomes two cells wide
+fn widen_board(board: &Board) -> WideBoard {
+    let mut wide = Matrix2D::new(board.width() * 2, board.height(), '.');
+
+    for (pos, cell) in board.iter() {
+        let [left, right] = match cell {
+            Cell::Wall => ['#', '#'],
+            Cell::Box => ['[', ']'],
+            Cell::Empty => ['.', '.'],
+        };
+        wide.set(Position::new(pos.x * 2, pos.y), left);
+        wide.set(Position::new(pos.x * 2 + 1, pos.y), right);
+    }
+
+    wide
+}
+
+// Render the wide warehouse with the robot in it
+fn render_wide_board(board: &WideBoard, robot_pos: Position) -> String {
+    let mut board = board.clone();
+    board.set(robot_pos, '@');
+
+    board.render()
+}
+
+fn calculate_gps_sum(board: &WideBoard) -> isize {
+    board
+        .iter()
+        .filter(|(_, cell)| **cell == '[')
+        .map(|(pos, _)| pos.y * 100 + pos.x)
+        .sum()
+}
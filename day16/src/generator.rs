@@ -0,0 +1,44 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+use grid::{maze, Position};
+
+/// Random input from `seed`, a `width` x `height` maze (default: 141 x 141)
+/// where `loops` percent of the walls between corridors are knocked down
+/// (default: 10)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["width", "height", "loops"])?;
+    let width: usize = params.get("width", 141)?;
+    let height: usize = params.get("height", 141)?;
+    let loops: u64 = params.get("loops", 10)?;
+    if width < 5 || height < 5 {
+        return Err("the maze must be at least 5 x 5".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    let mut walls = maze(width, height, &mut rng);
+
+    // Walls with corridors on both sides, knocking them down makes loops
+    let inner = walls.positions().filter(|pos| {
+        let open = |dx, dy| walls.get(Position::new(pos.x + dx, pos.y + dy)) == Some(false);
+        pos.x > 0
+            && pos.y > 0
+            && (pos.x as usize) < width - 1
+            && (pos.y as usize) < height - 1
+            && ((open(-1, 0) && open(1, 0)) || (open(0, -1) && open(0, 1)))
+    });
+    for pos in inner.collect::<Vec<_>>() {
+        if rng.ratio(loops, 100) {
+            walls.set(pos, false);
+        }
+    }
+
+    // Start in the bottom left corner, end in the top right one
+    let start = Position::new_usize(1, (height - 3) | 1);
+    let end = Position::new_usize((width - 3) | 1, 1);
+    let mut map = walls.map(|wall| if *wall { '#' } else { '.' });
+    map.set(start, 'S');
+    map.set(end, 'E');
+
+    Ok(map.render())
+}
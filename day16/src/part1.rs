@@ -0,0 +1,11 @@
+use std::error::Error;
+
+use crate::{best_ends, lowest_scores, Maze};
+
+pub fn solve((matrix, start_pos, end_pos): &Maze) -> Result<usize, Box<dyn Error>> {
+    // Solve
+    let paths = lowest_scores(matrix, *start_pos);
+    let (_, res) = best_ends(&paths, *end_pos).ok_or("Destination unreachable")?;
+
+    Ok(res)
+}
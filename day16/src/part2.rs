@@ -0,0 +1,40 @@
+use std::{collections::HashSet, error::Error};
+
+use crate::{best_ends, lowest_scores, Cell, Maze};
+use common::diag;
+use grid::{Colour, DisplayChar, GridImage, Matrix2D, Position};
+
+pub fn solve((matrix, start_pos, end_pos): &Maze) -> Result<usize, Box<dyn Error>> {
+    // Solve
+    let res = count_seats(matrix, *start_pos, *end_pos).ok_or("Destination unreachable")?;
+
+    Ok(res)
+}
+
+fn count_seats(matrix: &Matrix2D<Cell>, start: Position, end: Position) -> Option<usize> {
+    let paths = lowest_scores(matrix, start);
+    let (ends, _) = best_ends(&paths, end)?;
+
+    // Seats are positions on any best path, whatever the facing
+    let seats: HashSet<Position> = paths
+        .nodes_on_paths(ends)
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect();
+
+    diag::debug(|| render_seats(matrix, &seats));
+    diag::image("seats", || {
+        GridImage::from_chars(matrix).cells(seats.iter().copied(), Colour::ORANGE)
+    });
+    Some(seats.len())
+}
+
+// Render the maze with the seats on best paths marked
+fn render_seats(matrix: &Matrix2D<Cell>, seats: &HashSet<Position>) -> String {
+    let mut board = matrix.map(|cell| cell.display_char());
+    for pos in seats {
+        board.set(*pos, 'O');
+    }
+
+    board.render()
+}
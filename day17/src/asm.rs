@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use common::ParseError;
+
+use crate::{
+    disasm::{Operand, MNEMONICS},
+    OpCode, Program, Registers,
+};
+
+/// Assemble a program written as one instruction per line, e.g. `bst A` or
+/// `jnz loop`, the ignored operand of `bxc` is optional. Lines can start with
+/// `label:`, with the address printed by the disassembler, or be `.reg A 2024`
+/// to set a register (0 by default), `#` starts a comment.
+pub fn assemble(source: &str) -> Result<Program, ParseError> {
+    let mut regs = Registers { a: 0, b: 0, c: 0 };
+    let mut program = Vec::new();
+    let mut labels = HashMap::new();
+    // Jumps to labels, resolved once every label is known: address of the
+    // operand, line index and offset of the label
+    let mut jumps = Vec::new();
+
+    let lines: Vec<&str> = source.lines().collect();
+    for (idx, line) in lines.iter().enumerate() {
+        let code = line.split('#').next().unwrap_or_default();
+        let mut words = &words(code)[..];
+        let err = |(offset, word): (usize, &str), expected: &str| {
+            ParseError::new(idx, line, offset, word.len(), expected)
+        };
+        // Missing words are at the end of the code
+        let end = (code.trim_end().len(), "");
+
+        // Address printed by the disassembler
+        if let Some((&(offset, word), rest)) = words.split_first() {
+            if word.starts_with(|c: char| c.is_ascii_digit()) {
+                if word.parse() != Ok(program.len()) {
+                    let expected = format!("address {}", program.len());
+                    return Err(err((offset, word), &expected));
+                }
+                words = rest;
+            }
+        }
+
+        // Labels of the next instruction
+        while let Some((&(offset, word), rest)) = words.split_first() {
+            let Some(label) = word.strip_suffix(':') else {
+                break;
+            };
+            if !is_label(label) {
+                return Err(err((offset, word), "label made of letters, digits and `_`"));
+            }
+            if labels.insert(label, program.len()).is_some() {
+                return Err(err((offset, word), "label not defined before"));
+            }
+            words = rest;
+        }
+
+        let Some((&name, operands)) = words.split_first() else {
+            continue;
+        };
+
+        if name.1 == ".reg" {
+            let reg = *operands.first().unwrap_or(&end);
+            let reg = match reg.1.to_ascii_uppercase().as_str() {
+                "A" => &mut regs.a,
+                "B" => &mut regs.b,
+                "C" => &mut regs.c,
+                _ => return Err(err(reg, "register A, B or C")),
+            };
+            let val = *operands.get(1).unwrap_or(&end);
+            *reg = val.1.parse().map_err(|_| err(val, "register value"))?;
+            if let Some(&extra) = operands.get(2) {
+                return Err(err(extra, "end of line"));
+            }
+            continue;
+        }
+
+        let opcode = MNEMONICS
+            .iter()
+            .position(|mnemonic| mnemonic.eq_ignore_ascii_case(name.1))
+            .ok_or_else(|| err(name, "instruction"))? as OpCode;
+
+        let kind = Operand::of(opcode).expect("mnemonics have valid opcodes");
+        if let Some(&extra) = operands.get(1) {
+            return Err(err(extra, "end of line"));
+        }
+
+        let operand = match (kind, operands.first()) {
+            // The operand of `bxc` is ignored, it can be left out
+            (Operand::Ignored, None) => None,
+            (kind, word) => Some((kind, *word.unwrap_or(&end))),
+        };
+        let operand = match operand {
+            None => 0,
+            Some((_, word)) if word.1.is_empty() => return Err(err(word, "operand")),
+            Some((Operand::Combo, word)) => match word.1.to_ascii_uppercase().as_str() {
+                "A" => 4,
+                "B" => 5,
+                "C" => 6,
+                val => match val.parse() {
+                    Ok(val @ 0..=6) => val,
+                    _ => return Err(err(word, "combo operand 0 to 3, A, B or C")),
+                },
+            },
+            // Jump targets can be labels
+            Some((_, word)) if opcode == 3 && is_label(word.1) => {
+                jumps.push((program.len() + 1, idx, word));
+                0
+            }
+            Some((_, word)) => match word.1.parse() {
+                Ok(val @ 0..=7) => val,
+                _ => return Err(err(word, "literal operand 0 to 7")),
+            },
+        };
+
+        program.push(opcode);
+        program.push(operand);
+    }
+
+    for (addr, idx, (offset, label)) in jumps {
+        let err = |expected| ParseError::new(idx, lines[idx], offset, label.len(), expected);
+        match labels.get(label) {
+            Some(&target @ 0..=7) => program[addr] = target as OpCode,
+            Some(_) => return Err(err("label at an address from 0 to 7")),
+            None => return Err(err("defined label")),
+        }
+    }
+
+    Ok((regs, program))
+}
+
+/// Program in the format of the puzzle input
+pub fn format_input((regs, program): &Program) -> String {
+    let program: Vec<String> = program.iter().map(|op| op.to_string()).collect();
+
+    format!(
+        "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+        regs.a,
+        regs.b,
+        regs.c,
+        program.join(",")
+    )
+}
+
+// Words of a line with their byte offsets
+fn words(line: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut rest = line;
+
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        words.push((line.len() - rest.len(), &rest[..len]));
+        rest = &rest[len..];
+    }
+
+    words
+}
+
+fn is_label(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disassemble;
+
+    #[test]
+    fn assemble_test() {
+        let source = "\
+# The puzzle program
+.reg A 729
+loop: bst a
+    bxl 5
+    cdv B       # C = A >> B
+    bxl 6
+    adv 3
+    bxc 2
+    out B
+    jnz loop
+";
+        let (regs, program) = assemble(source).unwrap();
+        assert_eq!(regs, Registers { a: 729, b: 0, c: 0 });
+        assert_eq!(program, [2, 4, 1, 5, 7, 5, 1, 6, 0, 3, 4, 2, 5, 5, 3, 0]);
+
+        // Disassembled programs assemble back
+        let (_, res) = assemble(&disassemble(&program)).unwrap();
+        assert_eq!(res, program);
+    }
+
+    #[test]
+    fn assemble_error_test() {
+        let tests = [
+            (
+                "adv 7",
+                "line 1, column 5: expected combo operand 0 to 3, A, B or C, found `7`",
+            ),
+            (
+                "out\n",
+                "line 1, column 4: expected operand, found end of line",
+            ),
+            (
+                "bxl 8",
+                "line 1, column 5: expected literal operand 0 to 7, found `8`",
+            ),
+            (
+                "bxc 1 2",
+                "line 1, column 7: expected end of line, found `2`",
+            ),
+            (
+                "adv 1\nmul 2",
+                "line 2, column 1: expected instruction, found `mul`",
+            ),
+            (
+                "jnz end\nadv 1",
+                "line 1, column 5: expected defined label, found `end`",
+            ),
+            (
+                "adv 1\nadv 1\nadv 1\nadv 1\nfar: jnz far",
+                "line 5, column 10: expected label at an address from 0 to 7, found `far`",
+            ),
+            (
+                "a: adv 1\na: adv 2",
+                "line 2, column 1: expected label not defined before, found `a:`",
+            ),
+            (
+                "0 adv 1\n4 adv 2",
+                "line 2, column 1: expected address 2, found `4`",
+            ),
+            (
+                ".reg D 1",
+                "line 1, column 6: expected register A, B or C, found `D`",
+            ),
+        ];
+
+        for (source, exp) in tests {
+            let err = assemble(source).unwrap_err().to_string();
+            assert_eq!(err.lines().next(), Some(exp), "{}", source);
+        }
+    }
+}
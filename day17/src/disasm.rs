@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use crate::OpCode;
+
+/// Mnemonics of the instructions, indexed by opcode
+pub const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+/// Operand kinds of the instructions, indexed by opcode
+const OPERANDS: [Operand; 8] = [
+    Operand::Combo,
+    Operand::Literal,
+    Operand::Combo,
+    Operand::Literal,
+    Operand::Ignored,
+    Operand::Combo,
+    Operand::Combo,
+    Operand::Combo,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand {
+    Literal,
+    /// 0 to 3 are literals, 4 to 6 registers A to C, 7 is reserved
+    Combo,
+    Ignored,
+}
+
+impl Operand {
+    /// Kind of operand an opcode takes, `None` for invalid opcodes
+    pub fn of(opcode: OpCode) -> Option<Self> {
+        OPERANDS.get(opcode as usize).copied()
+    }
+}
+
+/// Name of a combo operand, `None` for the reserved 7
+pub fn combo_name(operand: u8) -> Option<String> {
+    match operand {
+        0..=3 => Some(operand.to_string()),
+        4 => Some("A".to_owned()),
+        5 => Some("B".to_owned()),
+        6 => Some("C".to_owned()),
+        _ => None,
+    }
+}
+
+/// Disassemble a program, one instruction per line with its address,
+/// mnemonic and operand, followed by the pseudo-code of what it does. Jump
+/// targets are labelled `L0`, `L1`... in address order.
+pub fn disassemble(program: &[OpCode]) -> String {
+    // Only instructions can be labelled, other targets stay addresses
+    let mut labels = BTreeMap::new();
+    for pair in program.chunks_exact(2) {
+        let target = pair[1] as usize;
+        if pair[0] == 3 && target.is_multiple_of(2) && target + 1 < program.len() {
+            labels.insert(target, String::new());
+        }
+    }
+    for (idx, label) in labels.values_mut().enumerate() {
+        *label = format!("L{}", idx);
+    }
+
+    let mut res = String::new();
+    for (idx, pair) in program.chunks(2).enumerate() {
+        let addr = idx * 2;
+        if let Some(label) = labels.get(&addr) {
+            res.push_str(&format!("{}:\n", label));
+        }
+
+        let (code, pseudo) = match *pair {
+            [opcode, operand] => instruction(opcode, operand, &labels),
+            _ => (
+                format!("# {}", pair[0]),
+                format!("opcode {} without operand, halts", pair[0]),
+            ),
+        };
+        res.push_str(&format!("{:>4}  {:<10} # {}\n", addr, code, pseudo));
+    }
+
+    res
+}
+
+// Assembly and pseudo-code of an instruction
+pub(crate) fn instruction(
+    opcode: u8,
+    operand: u8,
+    labels: &BTreeMap<usize, String>,
+) -> (String, String) {
+    let Some(kind) = Operand::of(opcode) else {
+        return (
+            format!("# {},{}", opcode, operand),
+            format!("invalid opcode {}", opcode),
+        );
+    };
+    let mnemonic = MNEMONICS[opcode as usize];
+
+    // Jumps go to labels when they can
+    if opcode == 3 {
+        let target = match labels.get(&(operand as usize)) {
+            Some(label) => label.clone(),
+            None => operand.to_string(),
+        };
+        return (
+            format!("jnz {}", target),
+            format!("if A != 0 goto {}", target),
+        );
+    }
+
+    let operand = match kind {
+        Operand::Combo => match combo_name(operand) {
+            Some(name) => name,
+            None => {
+                return (
+                    format!("# {},{}", opcode, operand),
+                    format!("{} with reserved combo operand 7", mnemonic),
+                )
+            }
+        },
+        Operand::Literal => operand.to_string(),
+        // Kept so that the program assembles back the same
+        Operand::Ignored if operand != 0 => {
+            return (format!("{} {}", mnemonic, operand), "B = B ^ C".to_owned())
+        }
+        Operand::Ignored => return (mnemonic.to_owned(), "B = B ^ C".to_owned()),
+    };
+
+    let pseudo = match opcode {
+        0 => format!("A = A >> {}", operand),
+        1 => format!("B = B ^ {}", operand),
+        2 => format!("B = {} % 8", operand),
+        5 => format!("out {} % 8", operand),
+        6 => format!("B = A >> {}", operand),
+        _ => format!("C = A >> {}", operand),
+    };
+
+    (format!("{} {}", mnemonic, operand), pseudo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassemble_test() {
+        let tests = [
+            (
+                // The puzzle program
+                vec![2, 4, 1, 5, 7, 5, 1, 6, 0, 3, 4, 2, 5, 5, 3, 0],
+                "\
+L0:
+   0  bst A      # B = A % 8
+   2  bxl 5      # B = B ^ 5
+   4  cdv B      # C = A >> B
+   6  bxl 6      # B = B ^ 6
+   8  adv 3      # A = A >> 3
+  10  bxc 2      # B = B ^ C
+  12  out B      # out B % 8
+  14  jnz L0     # if A != 0 goto L0
+",
+            ),
+            (
+                // Misaligned and out of range jumps, invalid opcodes and operands
+                vec![3, 4, 3, 1, 3, 12, 6, 7, 8, 0, 5],
+                "   0  jnz L0     # if A != 0 goto L0
+   2  jnz 1      # if A != 0 goto 1
+L0:
+   4  jnz 12     # if A != 0 goto 12
+   6  # 6,7      # bdv with reserved combo operand 7
+   8  # 8,0      # invalid opcode 8
+  10  # 5        # opcode 5 without operand, halts
+",
+            ),
+        ];
+
+        for (program, exp) in tests {
+            assert_eq!(disassemble(&program), exp);
+        }
+    }
+}
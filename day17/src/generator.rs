@@ -0,0 +1,43 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+use crate::{format_input, OpCode, Registers};
+
+/// Random input from `seed`, a program shaped like the puzzle one outputting
+/// `digits` numbers (default: 16, at most 20)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["digits"])?;
+    let digits: u32 = params.get("digits", 16)?;
+    if !(1..=20).contains(&digits) {
+        return Err("the program must output between 1 and 20 numbers".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    // Register A has one octal digit per output
+    let low = if digits == 1 { 0 } else { 8i64.pow(digits - 1) };
+    let a = rng.range(low..=8i64.pow(digits) - 1);
+
+    // Mix the low bits of A with higher ones, output them and shift A
+    let program = [
+        2,
+        4,
+        1,
+        rng.below(8),
+        7,
+        5,
+        1,
+        rng.below(8),
+        0,
+        3,
+        4,
+        rng.below(8),
+        5,
+        5,
+        3,
+        0,
+    ];
+    let program = program.iter().map(|op| *op as OpCode).collect();
+
+    Ok(format_input(&(Registers { a, b: 0, c: 0 }, program)))
+}
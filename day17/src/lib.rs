@@ -1,33 +1,85 @@
-use core::fmt;
 use std::{
     error::Error,
-    fmt::{Display, Formatter},
     io::{self, BufRead},
-    str::FromStr,
+    path::PathBuf,
 };
 
-// Pare result type
-type PResult<'a, T> = Result<(T, &'a str), PError>;
+use common::{
+    parser::{parse_line, separated_list, signed, tag, unsigned, PResult},
+    Params, ParseError, Solution,
+};
+
+mod asm;
+mod disasm;
+mod generator;
+mod part1;
+mod part2;
+mod trace;
+mod vm;
 
-// Parse error
-#[derive(PartialEq, Debug, Clone)]
-struct PError {}
+pub use asm::{assemble, format_input};
+pub use disasm::disassemble;
+pub use generator::generate;
+pub use trace::{save_trace, TraceEntry, TraceFormat};
+pub use vm::{ExecutionError, VirtualMachine};
+
+#[derive(Debug)]
+pub struct Day17 {
+    // Output the program must produce in part 2, the program itself by default
+    pub target: Option<Vec<OpCode>>,
+    // Instructions a run can execute before giving up
+    pub max_steps: usize,
+    // File the instructions executed in part 1 are written to
+    pub trace: Option<PathBuf>,
+    pub trace_format: TraceFormat,
+}
 
-impl PError {
-    pub fn new() -> Self {
-        Self {}
+impl Default for Day17 {
+    fn default() -> Self {
+        Self {
+            target: None,
+            max_steps: 1_000_000,
+            trace: None,
+            trace_format: TraceFormat::Text,
+        }
     }
 }
 
-impl std::error::Error for PError {}
+impl Solution for Day17 {
+    type Input = Program;
+    type Output1 = String;
+    type Output2 = i64;
+
+    fn configure(&mut self, params: &Params) -> Result<(), Box<dyn Error>> {
+        params.check_known(&["target", "max_steps", "trace", "trace_format"])?;
+        self.target = params.get_list("target")?;
+        self.max_steps = params.get("max_steps", self.max_steps)?;
+        self.trace = params.get_opt("trace")?;
+        self.trace_format = params.get("trace_format", self.trace_format)?;
+
+        Ok(())
+    }
+
+    fn parse(&self, input: impl BufRead) -> Result<Program, Box<dyn Error>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Program) -> Result<String, Box<dyn Error>> {
+        let trace = self.trace.as_deref().map(|path| (path, self.trace_format));
+        part1::solve(input, self.max_steps, trace)
+    }
 
-impl Display for PError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Parse error!")
+    fn part2(&self, input: &Program) -> Result<i64, Box<dyn Error>> {
+        let (_, program) = input;
+        part2::solve(
+            input,
+            self.target.as_ref().unwrap_or(program),
+            self.max_steps,
+        )
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Registers {
     pub a: i64,
     pub b: i64,
@@ -36,149 +88,55 @@ pub struct Registers {
 
 pub type OpCode = u8;
 
+// Initial registers and program
+pub type Program = (Registers, Vec<OpCode>);
+
 /// Parse input data
-pub fn parse_input(input: impl BufRead) -> Result<(Registers, Vec<OpCode>), Box<dyn Error>> {
+pub fn parse_input(input: impl BufRead) -> Result<Program, Box<dyn Error>> {
     let lines = input.lines().collect::<Result<Vec<String>, io::Error>>()?;
-    let mut lines = lines.iter();
 
-    let regs = {
-        let reg_lines: Vec<&String> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
-
-        if reg_lines.len() != 3 {
-            return Err(Box::new(PError::new()));
-        }
-
-        // Parse registers
-        let (a, _) = parse_register_value('A')(reg_lines[0])?;
-        let (b, _) = parse_register_value('B')(reg_lines[1])?;
-        let (c, _) = parse_register_value('C')(reg_lines[2])?;
-
-        Registers { a, b, c }
+    // Get a line that must be present
+    let line = |idx: usize, expected: &str| {
+        lines
+            .get(idx)
+            .map(String::as_str)
+            .ok_or_else(|| ParseError::end_of_input(lines.len(), expected))
     };
 
-    let prog = {
-        let prog_line = lines.next().or_err(PError::new())?;
-        let (prog, _) = parse_program(prog_line)?;
-        prog
-    };
+    // Parse registers
+    let a = parse_line(0, line(0, "register A")?, parse_register_value('A'))?;
+    let b = parse_line(1, line(1, "register B")?, parse_register_value('B'))?;
+    let c = parse_line(2, line(2, "register C")?, parse_register_value('C'))?;
+    let regs = Registers { a, b, c };
+
+    // Registers and program are separated by an empty line
+    let separator = line(3, "empty line")?;
+    if !separator.is_empty() {
+        return Err(Box::new(ParseError::at(
+            3,
+            separator,
+            separator,
+            "empty line",
+        )));
+    }
+
+    let prog = parse_line(4, line(4, "program")?, parse_program)?;
 
     Ok((regs, prog))
 }
 
 fn parse_register_value<'a>(reg_id: char) -> impl Fn(&str) -> PResult<i64> + 'a {
     move |input| {
-        let (_, input) = parse_tag(&format!("Register {}: ", reg_id))(input)?;
-        let (val, input) = parse_number(input)?;
+        let (_, input) = tag(&format!("Register {}: ", reg_id))(input)?;
+        let (val, input) = signed(input)?;
 
         Ok((val, input))
     }
 }
 
-fn parse_program(input: &str) -> PResult<Vec<OpCode>> {
-    let (_, input) = parse_tag(&format!("Program: "))(input)?;
-    let (program, input) = parse_many0(|input| {
-        let (op, input) = parse_number(input)?;
-        let (_, input) = parse_opt(parse_tag(","))(input)?;
-        Ok((op, input))
-    })(input)?;
+fn parse_program(input: &str) -> PResult<'_, Vec<OpCode>> {
+    let (_, input) = tag("Program: ")(input)?;
+    let (program, input) = separated_list(unsigned, tag(","))(input)?;
 
     Ok((program, input))
 }
-
-// Parse a string of text
-fn parse_tag<'a>(tag: &'a str) -> impl Fn(&str) -> PResult<()> + 'a {
-    let tag = tag.to_owned();
-
-    move |input| {
-        // Check size
-        if input.len() < tag.len() {
-            return Err(PError::new());
-        }
-
-        if input[0..tag.len()] == tag {
-            return Ok(((), &input[tag.len()..]));
-        } else {
-            Err(PError::new())
-        }
-    }
-}
-
-// Parse a number with maximum number of digits
-fn parse_number<T: FromStr>(input: &str) -> PResult<T> {
-    // Find end of digits
-    let mut end: usize = 0;
-    for c in input.chars() {
-        if !c.is_ascii_digit() && c != '-' {
-            break;
-        }
-        end += 1;
-    }
-
-    // Construct number slice
-    let num = &input[0..end];
-
-    // Parse number
-    let num: T = match num.parse() {
-        Ok(res) => res,
-        Err(_) => return Err(PError::new()),
-    };
-
-    Ok((num, &input[end..]))
-}
-
-fn parse_many0<'a, P, T>(parser: P) -> impl Fn(&'a str) -> PResult<'a, Vec<T>>
-where
-    P: Fn(&str) -> PResult<T>,
-{
-    move |mut input| {
-        let mut res = Vec::new();
-
-        while let Ok((val, rem)) = parser(input) {
-            input = rem;
-            res.push(val);
-        }
-
-        Ok((res, input))
-    }
-}
-
-fn parse_opt<'a, P, T>(parser: P) -> impl Fn(&'a str) -> PResult<'a, Option<T>>
-where
-    P: Fn(&str) -> PResult<T>,
-{
-    move |input| match parser(input) {
-        Ok((val, input)) => Ok((Some(val), input)),
-        Err(_) => Ok((None, input)),
-    }
-}
-
-trait OptToResult<T, E> {
-    fn or_err(self, err: E) -> Result<T, E>;
-}
-
-impl<T, E> OptToResult<T, E> for Option<T> {
-    fn or_err(self, err: E) -> Result<T, E> {
-        if let Some(val) = self {
-            Ok(val)
-        } else {
-            Err(err)
-        }
-    }
-}
-
-#[derive(Debug)]
-pub struct ExecutionError {}
-
-impl ExecutionError {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
-
-impl Display for ExecutionError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "execution error")
-    }
-}
-
-impl Error for ExecutionError {}
@@ -0,0 +1,24 @@
+use std::{error::Error, path::Path};
+
+use crate::{save_trace, Program, TraceFormat, VirtualMachine};
+
+pub fn solve(
+    program: &Program,
+    max_steps: usize,
+    trace: Option<(&Path, TraceFormat)>,
+) -> Result<String, Box<dyn Error>> {
+    // Execute program in the Virtual Machine
+    let mut vm = VirtualMachine::from(program.clone()).with_max_steps(max_steps);
+    if trace.is_some() {
+        vm = vm.with_trace();
+    }
+    let res = vm.run().map(|_| ());
+
+    // Traces are most useful when the program fails
+    if let Some((path, format)) = trace {
+        save_trace(path, format, vm.trace())?;
+    }
+    res?;
+
+    Ok(vm.output_string())
+}
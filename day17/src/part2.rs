@@ -0,0 +1,115 @@
+use std::error::Error;
+
+use crate::{OpCode, Program, Registers, VirtualMachine};
+
+pub fn solve(
+    (regs, program): &Program,
+    target: &[OpCode],
+    max_steps: usize,
+) -> Result<i64, Box<dyn Error>> {
+    let a = find_a_val(*regs, program, target, max_steps)
+        .ok_or("no value of register A outputs the target")?;
+
+    Ok(a)
+}
+
+/// Lowest value of register A making the program output `target`, for
+/// programs dropping the lowest 3 bits of A after each output like the puzzle
+/// ones. Runs longer than `max_steps` count as not matching.
+fn find_a_val(regs: Registers, program: &[OpCode], target: &[u8], max_steps: usize) -> Option<i64> {
+    // Every output drops 3 bits of A, which always outputs something
+    if target.is_empty() {
+        return None;
+    }
+    find_a_digits(regs, program, target, max_steps, target.len(), 0)
+}
+
+// Add the 3 bits of A outputting `target[idx - 1]` to the higher bits in `a`,
+// backtracking when none of them fit
+fn find_a_digits(
+    regs: Registers,
+    program: &[OpCode],
+    target: &[u8],
+    max_steps: usize,
+    idx: usize,
+    a: i64,
+) -> Option<i64> {
+    if idx == 0 {
+        return Some(a);
+    }
+
+    (0..8)
+        .map(|bits| a * 8 + bits)
+        .filter(|new_a| {
+            let regs = Registers { a: *new_a, ..regs };
+            check_full_output(regs, program, &target[idx - 1..], max_steps)
+        })
+        .find_map(|new_a| find_a_digits(regs, program, target, max_steps, idx - 1, new_a))
+}
+
+// Run the program until its output stops matching `target`
+fn check_full_output(regs: Registers, program: &[OpCode], target: &[u8], max_steps: usize) -> bool {
+    let mut vm = VirtualMachine::new(regs, program.to_vec()).with_max_steps(max_steps);
+    loop {
+        match vm.step() {
+            Ok(true) => {}
+            Ok(false) => return vm.output() == target,
+            Err(_) => return false,
+        }
+        if !target.starts_with(vm.output()) {
+            return false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1;
+    use common::{prop, rng::Rng};
+
+    // Lowest A whose output is `target` when running the puzzle program, A
+    // never has more octal digits than outputs
+    fn brute_force_a_val(program: &[u8], target: &[u8]) -> Option<i64> {
+        let out: Vec<String> = target.iter().map(|n| n.to_string()).collect();
+        let out = out.join(",");
+
+        (0..8i64.pow(target.len() as u32)).find(|a| {
+            let regs = Registers { a: *a, b: 0, c: 0 };
+            part1::solve(&(regs, program.to_vec()), 1000, None)
+                .ok()
+                .as_ref()
+                == Some(&out)
+        })
+    }
+
+    #[test]
+    fn find_a_val_matches_brute_force() {
+        let program = vec![2, 4, 1, 5, 7, 5, 1, 6, 0, 3, 4, 2, 5, 5, 3, 0];
+        let regs = Registers { a: 0, b: 0, c: 0 };
+
+        // Outputs of random values of A, or random outputs which may be impossible
+        let gen = |rng: &mut Rng| match rng.ratio(1, 2) {
+            true => {
+                let regs = Registers {
+                    a: rng.range(0..=4095),
+                    b: 0,
+                    c: 0,
+                };
+                let out = part1::solve(&(regs, program.clone()), 1000, None).unwrap();
+                out.split(',')
+                    .map(|n| n.parse().unwrap())
+                    .collect::<Vec<u8>>()
+            }
+            false => (0..rng.range(1..=4)).map(|_| rng.below(8) as u8).collect(),
+        };
+
+        prop::check(100, gen, |target| {
+            if target.len() > 4 || target.iter().any(|n| *n >= 8) {
+                return Ok(());
+            }
+            let res = find_a_val(regs, &program, target, 1000);
+            prop::same(res, brute_force_a_val(&program, target))
+        });
+    }
+}
@@ -0,0 +1,133 @@
+use std::{collections::BTreeMap, error::Error, fs, path::Path, str::FromStr};
+
+use crate::{disasm::instruction, OpCode, Registers};
+
+/// Instruction executed by the VM, with the registers around it
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEntry {
+    /// Number of instructions executed before, starting from 0
+    pub step: usize,
+    pub ip: usize,
+    pub opcode: OpCode,
+    pub operand: u8,
+    pub before: Registers,
+    pub after: Registers,
+    /// Value output by the instruction
+    pub out: Option<u8>,
+}
+
+impl TraceEntry {
+    /// Single line with the disassembled instruction
+    pub fn to_text(&self) -> String {
+        let (code, _) = instruction(self.opcode, self.operand, &BTreeMap::new());
+        let regs = |regs: Registers| format!("A={} B={} C={}", regs.a, regs.b, regs.c);
+        let out = match self.out {
+            Some(val) => format!("  out {}", val),
+            None => String::new(),
+        };
+
+        format!(
+            "{:>6}  {:>4}  {:<8}  {} -> {}{}",
+            self.step,
+            self.ip,
+            code,
+            regs(self.before),
+            regs(self.after),
+            out
+        )
+    }
+
+    /// Single line JSON object, `out` is `null` for instructions not outputting
+    pub fn to_json(&self) -> String {
+        let regs = |regs: Registers| format!(r#"{{"a":{},"b":{},"c":{}}}"#, regs.a, regs.b, regs.c);
+        let out = match self.out {
+            Some(val) => val.to_string(),
+            None => "null".to_owned(),
+        };
+
+        format!(
+            r#"{{"step":{},"ip":{},"opcode":{},"operand":{},"before":{},"after":{},"out":{}}}"#,
+            self.step,
+            self.ip,
+            self.opcode,
+            self.operand,
+            regs(self.before),
+            regs(self.after),
+            out
+        )
+    }
+}
+
+/// How traces are written, a line per instruction
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TraceFormat {
+    #[default]
+    Text,
+    /// JSON lines
+    Json,
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("invalid trace format: `{}`", s)),
+        }
+    }
+}
+
+/// Write a trace to `path`
+pub fn save_trace(
+    path: &Path,
+    format: TraceFormat,
+    trace: &[TraceEntry],
+) -> Result<(), Box<dyn Error>> {
+    let mut text = String::new();
+    for entry in trace {
+        match format {
+            TraceFormat::Text => text.push_str(&entry.to_text()),
+            TraceFormat::Json => text.push_str(&entry.to_json()),
+        }
+        text.push('\n');
+    }
+
+    fs::write(path, text).map_err(|e| format!("can't save trace {}: {}", path.display(), e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_test() {
+        let entry = TraceEntry {
+            step: 12,
+            ip: 10,
+            opcode: 5,
+            operand: 5,
+            before: Registers {
+                a: 729,
+                b: 3,
+                c: -1,
+            },
+            after: Registers {
+                a: 729,
+                b: 3,
+                c: -1,
+            },
+            out: Some(3),
+        };
+
+        assert_eq!(
+            entry.to_text(),
+            "    12    10  out B     A=729 B=3 C=-1 -> A=729 B=3 C=-1  out 3"
+        );
+        assert_eq!(
+            entry.to_json(),
+            r#"{"step":12,"ip":10,"opcode":5,"operand":5,"before":{"a":729,"b":3,"c":-1},"after":{"a":729,"b":3,"c":-1},"out":3}"#
+        );
+    }
+}
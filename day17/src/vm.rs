@@ -0,0 +1,370 @@
+use core::fmt;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use crate::{trace::TraceEntry, OpCode, Program, Registers};
+
+/// Virtual Machine running 3-bit programs, it halts when the instruction
+/// pointer leaves the program
+#[derive(Debug, Clone)]
+pub struct VirtualMachine {
+    regs: Registers,
+    ip: usize,
+    program: Vec<OpCode>,
+    out: Vec<u8>,
+    // Instructions executed so far, and how many can be
+    steps: usize,
+    max_steps: Option<usize>,
+    // Executed instructions, if tracing
+    trace: Option<Vec<TraceEntry>>,
+}
+
+impl VirtualMachine {
+    /// Instantiate a new Virtual Machine at the start of `program`
+    pub fn new(regs: Registers, program: Vec<OpCode>) -> Self {
+        Self {
+            regs,
+            ip: 0,
+            program,
+            out: vec![],
+            steps: 0,
+            max_steps: None,
+            trace: None,
+        }
+    }
+
+    /// Fail with [`ExecutionError::StepLimit`] instead of executing more than
+    /// `max_steps` instructions
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Record every executed instruction, see [`VirtualMachine::trace`]
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.regs
+    }
+
+    /// Change the registers, e.g. while debugging a program
+    pub fn set_registers(&mut self, regs: Registers) {
+        self.regs = regs;
+    }
+
+    /// Address of the next instruction
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    /// Number of instructions executed so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Instructions executed so far, empty unless tracing
+    pub fn trace(&self) -> &[TraceEntry] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    pub fn program(&self) -> &[OpCode] {
+        &self.program
+    }
+
+    /// Values output so far
+    pub fn output(&self) -> &[u8] {
+        &self.out
+    }
+
+    /// Output as the comma-separated answer of part 1
+    pub fn output_string(&self) -> String {
+        let strings: Vec<String> = self.out.iter().map(|val| val.to_string()).collect();
+        strings.join(",")
+    }
+
+    /// Check if there's no instruction left, an opcode without operand ends
+    /// the program too
+    pub fn is_halted(&self) -> bool {
+        self.ip + 1 >= self.program.len()
+    }
+
+    /// Execute the next instruction, returns false if the VM had already halted
+    pub fn step(&mut self) -> Result<bool, ExecutionError> {
+        if self.is_halted() {
+            return Ok(false);
+        }
+        if let Some(max) = self.max_steps.filter(|max| self.steps >= *max) {
+            return Err(ExecutionError::StepLimit { max });
+        }
+
+        // Get current instruction and operand
+        let inst = self.program[self.ip];
+        let operand = self.program[self.ip + 1];
+        let (ip, before, len) = (self.ip, self.regs, self.out.len());
+
+        self.execute_instruction(inst, operand)?;
+
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEntry {
+                step: self.steps,
+                ip,
+                opcode: inst,
+                operand,
+                before,
+                after: self.regs,
+                out: self.out.get(len).copied(),
+            });
+        }
+        self.steps += 1;
+        Ok(true)
+    }
+
+    /// Execute the program until it halts, returns its output
+    pub fn run(&mut self) -> Result<&[u8], ExecutionError> {
+        while self.step()? {}
+
+        Ok(&self.out)
+    }
+
+    // Resolve combo operand
+    fn resolve_combo_operand(&self, operand: u8) -> Result<i64, ExecutionError> {
+        match operand {
+            0..=3 => Ok(operand as i64),
+            4 => Ok(self.regs.a),
+            5 => Ok(self.regs.b),
+            6 => Ok(self.regs.c),
+            _ => Err(ExecutionError::InvalidComboOperand {
+                ip: self.ip,
+                operand,
+            }),
+        }
+    }
+
+    // Execute instruction
+    fn execute_instruction(&mut self, inst: u8, operand: u8) -> Result<(), ExecutionError> {
+        // Select instruction to execute
+        match inst {
+            0 => self.regs.a = self.divide(operand)?,
+            1 => self.regs.b ^= operand as i64,
+            2 => self.regs.b = self.resolve_combo_operand(operand)?.rem_euclid(8),
+            3 => {
+                if self.regs.a != 0 {
+                    self.ip = operand as usize;
+                    return Ok(());
+                }
+            }
+            4 => self.regs.b ^= self.regs.c,
+            5 => {
+                let val = self.resolve_combo_operand(operand)?.rem_euclid(8);
+                self.out.push(val as u8);
+            }
+            6 => self.regs.b = self.divide(operand)?,
+            7 => self.regs.c = self.divide(operand)?,
+            _ => {
+                return Err(ExecutionError::InvalidOpcode {
+                    ip: self.ip,
+                    opcode: inst,
+                })
+            }
+        }
+
+        // Increment instruction pointer
+        self.ip += 2;
+        Ok(())
+    }
+
+    // Register A divided by 2 to the power of a combo operand, truncated,
+    // negative powers leave it as is
+    fn divide(&self, operand: u8) -> Result<i64, ExecutionError> {
+        let pow = self.resolve_combo_operand(operand)?;
+        if pow <= 0 {
+            return Ok(self.regs.a);
+        }
+
+        Ok((self.regs.a as i128 / (1i128 << pow.min(64))) as i64)
+    }
+}
+
+impl From<Program> for VirtualMachine {
+    fn from((regs, program): Program) -> Self {
+        Self::new(regs, program)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExecutionError {
+    InvalidOpcode {
+        ip: usize,
+        opcode: u8,
+    },
+    /// Combo operand 7 is reserved
+    InvalidComboOperand {
+        ip: usize,
+        operand: u8,
+    },
+    /// The program was still running after the maximum number of steps
+    StepLimit {
+        max: usize,
+    },
+}
+
+impl Display for ExecutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidOpcode { ip, opcode } => {
+                write!(f, "invalid opcode {} at address {}", opcode, ip)
+            }
+            Self::InvalidComboOperand { ip, operand } => {
+                write!(f, "invalid combo operand {} at address {}", operand, ip)
+            }
+            Self::StepLimit { max } => write!(f, "program didn't halt within {} steps", max),
+        }
+    }
+}
+
+impl Error for ExecutionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regs(a: i64, b: i64, c: i64) -> Registers {
+        Registers { a, b, c }
+    }
+
+    #[test]
+    fn run_test() {
+        // Small examples of the puzzle: registers, program, then registers and
+        // output once halted
+        let tests = [
+            (regs(0, 0, 9), vec![2, 6], regs(0, 1, 9), vec![]),
+            (
+                regs(10, 0, 0),
+                vec![5, 0, 5, 1, 5, 4],
+                regs(10, 0, 0),
+                vec![0, 1, 2],
+            ),
+            (
+                regs(2024, 0, 0),
+                vec![0, 1, 5, 4, 3, 0],
+                regs(0, 0, 0),
+                vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0],
+            ),
+            (regs(0, 29, 0), vec![1, 7], regs(0, 26, 0), vec![]),
+            (
+                regs(0, 2024, 43690),
+                vec![4, 0],
+                regs(0, 44354, 43690),
+                vec![],
+            ),
+            // Negative values and huge powers
+            (regs(-13, 0, 0), vec![2, 4, 5, 4], regs(-13, 3, 0), vec![3]),
+            (
+                regs(i64::MAX, 0, 0),
+                vec![7, 4, 6, 1],
+                regs(i64::MAX, i64::MAX / 2, 0),
+                vec![],
+            ),
+            // An opcode without operand halts
+            (regs(1, 0, 0), vec![5, 4, 5], regs(1, 0, 0), vec![1]),
+            (regs(1, 0, 0), vec![], regs(1, 0, 0), vec![]),
+        ];
+
+        for (start, program, end, out) in tests {
+            let mut vm = VirtualMachine::new(start, program);
+            assert_eq!(vm.run(), Ok(&out[..]));
+            assert!(vm.is_halted());
+            assert_eq!(vm.registers(), end);
+        }
+    }
+
+    #[test]
+    fn step_test() {
+        let mut vm = VirtualMachine::from((regs(2, 0, 0), vec![0, 1, 5, 4, 3, 0]));
+
+        let mut states = vec![];
+        while vm.step().unwrap() {
+            states.push((vm.ip(), vm.registers().a, vm.output_string()));
+        }
+
+        let exp = [
+            (2, 1, ""),
+            (4, 1, "1"),
+            (0, 1, "1"),
+            (2, 0, "1"),
+            (4, 0, "1,0"),
+            (6, 0, "1,0"),
+        ];
+        let exp: Vec<_> = exp
+            .iter()
+            .map(|(ip, a, out)| (*ip, *a, out.to_string()))
+            .collect();
+        assert_eq!(states, exp);
+        assert_eq!(vm.step(), Ok(false));
+    }
+
+    #[test]
+    fn execution_error_test() {
+        let tests = [
+            (vec![0, 1, 5, 7], "invalid combo operand 7 at address 2"),
+            (vec![8, 0], "invalid opcode 8 at address 0"),
+        ];
+
+        for (program, exp) in tests {
+            let mut vm = VirtualMachine::new(regs(1, 0, 0), program);
+            assert_eq!(vm.run().unwrap_err().to_string(), exp);
+        }
+    }
+
+    #[test]
+    fn step_limit_test() {
+        // Loops forever
+        let mut vm = VirtualMachine::new(regs(1, 0, 0), vec![5, 4, 3, 0]).with_max_steps(10);
+        assert_eq!(vm.run(), Err(ExecutionError::StepLimit { max: 10 }));
+        assert_eq!(vm.steps(), 10);
+        assert_eq!(vm.output(), [1; 5]);
+
+        // Halting on the last allowed step is fine
+        let mut vm = VirtualMachine::new(regs(2, 0, 0), vec![0, 1, 5, 4, 3, 0]).with_max_steps(6);
+        assert_eq!(vm.run(), Ok(&[1, 0][..]));
+    }
+
+    #[test]
+    fn trace_test() {
+        let mut vm = VirtualMachine::new(regs(2, 0, 0), vec![0, 1, 5, 4, 3, 0]).with_trace();
+        vm.run().unwrap();
+
+        let trace: Vec<_> = vm
+            .trace()
+            .iter()
+            .map(|entry| {
+                (
+                    entry.step,
+                    entry.ip,
+                    entry.before.a,
+                    entry.after.a,
+                    entry.out,
+                )
+            })
+            .collect();
+        let exp = [
+            (0, 0, 2, 1, None),
+            (1, 2, 1, 1, Some(1)),
+            (2, 4, 1, 1, None),
+            (3, 0, 1, 0, None),
+            (4, 2, 0, 0, Some(0)),
+            (5, 4, 0, 0, None),
+        ];
+        assert_eq!(trace, exp);
+
+        // Nothing recorded unless asked
+        let mut vm = VirtualMachine::new(regs(2, 0, 0), vec![0, 1, 5, 4, 3, 0]);
+        vm.run().unwrap();
+        assert!(vm.trace().is_empty());
+    }
+}
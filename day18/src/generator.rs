@@ -0,0 +1,74 @@
+use std::error::Error;
+
+use common::{rng::Rng, search::bfs, Params};
+use grid::{Matrix2D, Position};
+
+/// Random input from `seed`, `count` bytes (default: every cell but the start
+/// and the exit) falling on a `width` x `height` memory space (default: 71 x
+/// 71), the exit is still reachable after the first `bytes` (default: 1024)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["width", "height", "count", "bytes"])?;
+    let width: usize = params.get("width", 71)?;
+    let height: usize = params.get("height", 71)?;
+    if width * height < 2 {
+        return Err("the memory space must have a start and an exit".into());
+    }
+    let count: usize = params.get("count", width * height - 2)?;
+    let bytes: usize = params.get("bytes", 1024)?;
+    let mut rng = Rng::new(seed);
+
+    // Bytes never fall on the start and the exit
+    let mut cells: Vec<Position> = (1..width * height - 1)
+        .map(|cell| Position::new_usize(cell % width, cell / width))
+        .collect();
+    rng.shuffle(&mut cells);
+    if count > cells.len() {
+        return Err(format!("at most {} bytes can fall", cells.len()).into());
+    }
+
+    // Bytes cutting off the exit too early fall later
+    let mut fallen = Matrix2D::new(width, height, false);
+    let exit = Position::new_usize(width - 1, height - 1);
+    let mut path = shortest_path(&fallen, exit).unwrap_or_default();
+    let mut first = Vec::new();
+    let mut later = Vec::new();
+    for cell in cells {
+        if first.len() == bytes.min(count) {
+            later.push(cell);
+            continue;
+        }
+
+        fallen.set(cell, true);
+        if path.contains(&cell) {
+            match shortest_path(&fallen, exit) {
+                Some(new_path) => path = new_path,
+                None => {
+                    fallen.set(cell, false);
+                    later.push(cell);
+                    continue;
+                }
+            }
+        }
+        first.push(cell);
+    }
+
+    let mut input = String::new();
+    for cell in first.iter().chain(&later).take(count) {
+        input.push_str(&format!("{},{}\n", cell.x, cell.y));
+    }
+
+    Ok(input)
+}
+
+// Path from the start to the exit avoiding fallen bytes
+fn shortest_path(fallen: &Matrix2D<bool>, exit: Position) -> Option<Vec<Position>> {
+    let paths = bfs(Position::new(0, 0), |pos: &Position| {
+        fallen
+            .neighbours4(*pos)
+            .filter(|(_, fallen)| !**fallen)
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>()
+    });
+
+    paths.path_to(&exit)
+}
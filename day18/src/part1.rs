@@ -0,0 +1,22 @@
+use std::error::Error;
+
+use crate::{calculate_distance, construct_map};
+use grid::Position;
+
+pub fn solve(
+    bytes: &[Position],
+    width: usize,
+    height: usize,
+    max_bytes: usize,
+) -> Result<usize, Box<dyn Error>> {
+    // Simulate bytes falling
+    let fallen = bytes.get(..max_bytes).ok_or("Not enough bytes!")?;
+    let map = construct_map(fallen, width, height);
+
+    // Calculate distance
+    let start = Position::new(0, 0);
+    let end = Position::new_usize(width - 1, height - 1);
+    let res = calculate_distance(&map, start, end).ok_or("Couldn't reach end!")?;
+
+    Ok(res)
+}
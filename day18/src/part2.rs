@@ -0,0 +1,48 @@
+use std::error::Error;
+
+use crate::{calculate_distance, construct_map};
+use common::diag;
+use grid::{Matrix2D, Position};
+
+pub fn solve(
+    bytes: &[Position],
+    width: usize,
+    height: usize,
+    max_bytes: usize,
+) -> Result<Position, Box<dyn Error>> {
+    // Simulate bytes falling
+    let fallen = bytes.get(..max_bytes).ok_or("Not enough bytes!")?;
+    let map = construct_map(fallen, width, height);
+
+    // Calculate distance
+    let start = Position::new(0, 0);
+    let end = Position::new_usize(width - 1, height - 1);
+
+    let remaining_bytes = &bytes[max_bytes..];
+
+    // Find first blocking byte
+    let res =
+        find_first_blocking(map, remaining_bytes, start, end).ok_or("No blocking byte found!")?;
+
+    Ok(res)
+}
+
+fn find_first_blocking(
+    mut map: Matrix2D<bool>,
+    bytes: &[Position],
+    start: Position,
+    end: Position,
+) -> Option<Position> {
+    for byte in bytes {
+        // Set byte
+        map.set(*byte, true);
+        diag::frame("bytes", || map.render());
+
+        // Check if the path is blocked
+        if calculate_distance(&map, start, end).is_none() {
+            return Some(*byte);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,55 @@
+use std::{collections::HashSet, error::Error};
+
+use common::{rng::Rng, Params};
+
+const COLOURS: &[u8] = b"wubrg";
+
+/// Random input from `seed`, `patterns` towel patterns (default: 447) and
+/// `designs` designs (default: 400)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["patterns", "designs"])?;
+    let patterns: usize = params.get("patterns", 447)?;
+    let designs: usize = params.get("designs", 400)?;
+    if patterns == 0 || patterns > 10000 {
+        return Err("there must be between 1 and 10000 patterns".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    // Distinct patterns of up to 8 stripes, none of them ends with the last
+    // colour, so designs ending with it can't be made
+    let last = *rng.pick(COLOURS) as char;
+    let mut seen = HashSet::new();
+    let mut towels = Vec::new();
+    while towels.len() < patterns {
+        let len = rng.range(1..=8);
+        let towel = stripes(&mut rng, len as usize);
+        if !towel.ends_with(last) && seen.insert(towel.clone()) {
+            towels.push(towel);
+        }
+    }
+
+    let mut input = towels.join(", ") + "\n\n";
+    for _ in 0..designs {
+        // Half of the designs are made of towels, the others can't be
+        let len = rng.range(20..=60) as usize;
+        let design = match rng.ratio(1, 2) {
+            true => {
+                let mut design = String::new();
+                while design.len() < len {
+                    let towel: &String = rng.pick(&towels);
+                    design.push_str(towel);
+                }
+                design
+            }
+            false => stripes(&mut rng, len - 1) + &last.to_string(),
+        };
+        input.push_str(&design);
+        input.push('\n');
+    }
+
+    Ok(input)
+}
+
+fn stripes(rng: &mut Rng, len: usize) -> String {
+    (0..len).map(|_| *rng.pick(COLOURS) as char).collect()
+}
@@ -5,37 +5,36 @@ use std::{
     io::{self, BufRead},
 };
 
-// Parse error
-#[derive(PartialEq, Debug, Clone)]
-pub struct PError {}
+use common::{ParseError, Solution};
 
-impl PError {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
+mod generator;
+mod part1;
+mod part2;
 
-impl Display for PError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Parse error!")
+pub use generator::generate;
+
+#[derive(Debug, Default)]
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Towels;
+    type Output1 = usize;
+    type Output2 = u32;
+
+    fn parse(&self, input: impl BufRead) -> Result<Towels, Box<dyn Error>> {
+        parse_input(input)
     }
-}
 
-trait OptToResult<T, E> {
-    fn or_err(self, err: E) -> Result<T, E>;
-}
+    fn part1(&self, input: &Towels) -> Result<usize, Box<dyn Error>> {
+        part1::solve(input)
+    }
 
-impl<T, E> OptToResult<T, E> for Option<T> {
-    fn or_err(self, err: E) -> Result<T, E> {
-        if let Some(val) = self {
-            Ok(val)
-        } else {
-            Err(err)
-        }
+    fn part2(&self, input: &Towels) -> Result<u32, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct ExecutionError {}
 
 impl ExecutionError {
@@ -52,14 +51,34 @@ impl Display for ExecutionError {
 
 impl Error for ExecutionError {}
 
-impl std::error::Error for PError {}
+// Available patterns and target designs
+pub type Towels = (Vec<String>, Vec<String>);
 
-pub fn parse_input(input: impl BufRead) -> Result<(Vec<String>, Vec<String>), Box<dyn Error>> {
+pub fn parse_input(input: impl BufRead) -> Result<Towels, Box<dyn Error>> {
     let mut lines = input.lines();
 
     // Parse available patterns
-    let patterns = lines.next().or_err(PError::new())??;
-    let patterns: Vec<String> = patterns.split(",").map(|p| p.trim().to_owned()).collect();
+    let line = lines
+        .next()
+        .ok_or(ParseError::end_of_input(0, "towel patterns"))??;
+    let mut patterns = Vec::new();
+    let mut offset = 0;
+    for pattern in line.split(',') {
+        // Patterns can't be empty
+        let trimmed = pattern.trim();
+        if trimmed.is_empty() {
+            let offset = offset + pattern.len() - pattern.trim_start().len();
+            return Err(Box::new(ParseError::at(
+                0,
+                &line,
+                &line[offset..],
+                "towel pattern",
+            )));
+        }
+
+        patterns.push(trimmed.to_owned());
+        offset += pattern.len() + 1;
+    }
 
     // Parse targets
     let targets: Vec<String> = lines.collect::<Result<Vec<String>, io::Error>>()?;
@@ -1,26 +1,16 @@
-use std::{
-    collections::HashMap,
-    io::{stdin, BufReader},
-    process,
-};
+use std::{collections::HashMap, error::Error};
 
-use day19::parse_input;
-
-fn main() {
-    // Read input
-    let (patterns, targets) = parse_input(BufReader::new(stdin())).unwrap_or_else(|e| {
-        eprintln!("Error reading input: {}", e);
-        process::exit(1);
-    });
+use crate::Towels;
+use common::parallel;
 
+pub fn solve((patterns, targets): &Towels) -> Result<usize, Box<dyn Error>> {
     // Count reachable targets
-    let res = targets
-        .iter()
-        .filter(|target| is_target_reachable(target, &patterns, &mut HashMap::new()))
-        .count();
+    let reachable = parallel::map(targets, |target| {
+        is_target_reachable(target, patterns, &mut HashMap::new())
+    });
+    let res = reachable.into_iter().filter(|reachable| *reachable).count();
 
-    // Print result
-    println!("Result: {}", res);
+    Ok(res)
 }
 
 /// Check if a target can be reached
@@ -58,11 +48,7 @@ fn is_target_reachable(
 
 /// Match pattern from target, return remaining characters
 fn match_pattern<'a>(target: &'a str, pattern: &str) -> Option<&'a str> {
-    if pattern.len() <= target.len() && &target[0..pattern.len()] == pattern {
-        Some(&target[pattern.len()..])
-    } else {
-        None
-    }
+    target.strip_prefix(pattern)
 }
 
 #[cfg(test)]
@@ -76,6 +62,8 @@ mod tests {
             (("abcdef", "abcde"), Some("f")),
             (("g", "ubr"), None),
             (("ciaone", "test"), None),
+            (("éa", "a"), None),
+            (("éa", "é"), Some("a")),
         ];
 
         for ((target, pattern), exp) in tests {
@@ -1,28 +1,16 @@
-use std::{
-    collections::HashMap,
-    io::{stdin, BufReader},
-    process,
-};
+use std::{collections::HashMap, error::Error};
 
-use day19::parse_input;
+use crate::Towels;
+use common::parallel;
 
-fn main() {
-    // Read input
-    let (patterns, targets) = parse_input(BufReader::new(stdin())).unwrap_or_else(|e| {
-        eprintln!("Error reading input: {}", e);
-        process::exit(1);
+pub fn solve((patterns, targets): &Towels) -> Result<u32, Box<dyn Error>> {
+    // Count reachable targets, every thread has its own memo
+    let ways = parallel::map_with(targets, HashMap::new, |memo, target| {
+        ways_to_reach(target, patterns, memo)
     });
+    let res: u32 = ways.into_iter().sum();
 
-    let mut memo = HashMap::new();
-
-    // Count reachable targets
-    let res: u32 = targets
-        .iter()
-        .map(|target| ways_to_reach(target, &patterns, &mut memo))
-        .sum();
-
-    // Print result
-    println!("Result: {}", res);
+    Ok(res)
 }
 
 /// Check if a target can be reached
@@ -53,11 +41,7 @@ fn ways_to_reach(target: &str, patterns: &[String], memo: &mut HashMap<String, u
 
 /// Match pattern from target, return remaining characters
 fn match_pattern<'a>(target: &'a str, pattern: &str) -> Option<&'a str> {
-    if pattern.len() <= target.len() && &target[0..pattern.len()] == pattern {
-        Some(&target[pattern.len()..])
-    } else {
-        None
-    }
+    target.strip_prefix(pattern)
 }
 
 #[cfg(test)]
@@ -71,6 +55,8 @@ mod tests {
             (("abcdef", "abcde"), Some("f")),
             (("g", "ubr"), None),
             (("ciaone", "test"), None),
+            (("éa", "a"), None),
+            (("éa", "é"), Some("a")),
         ];
 
         for ((target, pattern), exp) in tests {
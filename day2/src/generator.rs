@@ -0,0 +1,35 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+/// Random input from `seed`, with `reports` reports (default: 1000)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["reports"])?;
+    let reports: usize = params.get("reports", 1000)?;
+    let mut rng = Rng::new(seed);
+
+    let mut input = String::new();
+    for _ in 0..reports {
+        // Steadily increasing or decreasing levels
+        let dir = *rng.pick(&[-1, 1]);
+        let mut level = rng.range(30..=70);
+        let mut levels: Vec<i64> = (0..rng.range(5..=8))
+            .map(|_| {
+                level += dir * rng.range(1..=3);
+                level
+            })
+            .collect();
+
+        // Half of the reports have a bad level
+        if rng.ratio(1, 2) {
+            let idx = rng.below(levels.len() as u64) as usize;
+            levels[idx] = rng.range(1..=99);
+        }
+
+        let levels: Vec<String> = levels.iter().map(|level| level.to_string()).collect();
+        input.push_str(&levels.join(" "));
+        input.push('\n');
+    }
+
+    Ok(input)
+}
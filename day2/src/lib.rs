@@ -1,38 +1,62 @@
-use core::panic;
-use std::io::{stdin, BufRead, BufReader};
+use std::{error::Error, io::BufRead};
+
+use common::{ParseError, Solution};
+
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
 
 pub type Report = Vec<i32>;
 
-/// Read lists from stdin
-pub fn read_reports() -> Vec<Report> {
-    // Open bufreader on stdin
-    let reader = BufReader::new(stdin());
+#[derive(Debug, Default)]
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Report>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: impl BufRead) -> Result<Vec<Report>, Box<dyn Error>> {
+        read_reports(input)
+    }
+
+    fn part1(&self, input: &Vec<Report>) -> Result<usize, Box<dyn Error>> {
+        part1::solve(input)
+    }
 
+    fn part2(&self, input: &Vec<Report>) -> Result<usize, Box<dyn Error>> {
+        part2::solve(input)
+    }
+}
+
+/// Read reports from input
+pub fn read_reports(reader: impl BufRead) -> Result<Vec<Report>, Box<dyn Error>> {
     // Parse all lines from input
     reader
         .lines()
-        .map(|line| match line {
-            Ok(line) => match parse_line(&line) {
-                Ok(res) => res,
-                Err(_) => panic!("Parse error!"),
-            },
-            Err(e) => panic!("I/O error: {}", e),
-        })
+        .enumerate()
+        .map(|(idx, line)| Ok(parse_line(idx, &line?)?))
         .collect()
 }
 
-/// Process line of input
-fn parse_line(line: &str) -> Result<Report, ()> {
+/// Process line of input, `idx` is its index in the input
+fn parse_line(idx: usize, line: &str) -> Result<Report, ParseError> {
     let mut res = Vec::new();
-    // Divide line by space
-    let split = line.split_whitespace();
+    let mut rest = line.trim_start();
 
     // Parse each word in a line
-    for word in split {
-        // Parse word
-        let num: i32 = word.parse().or(Err(()))?;
+    while !rest.is_empty() {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
 
+        // Parse word
+        let num: i32 = rest[..end]
+            .parse()
+            .map_err(|_| ParseError::new(idx, line, line.len() - rest.len(), end, "number"))?;
         res.push(num);
+
+        rest = rest[end..].trim_start();
     }
 
     Ok(res)
@@ -41,7 +65,8 @@ fn parse_line(line: &str) -> Result<Report, ()> {
 // Check if a sequence of parameters is safe
 pub fn is_safe(report: &Report) -> bool {
     for i in 1..report.len() {
-        let diff = report[i] - report[i - 1];
+        // Differences of `i32` values only fit an `i64`
+        let diff = i64::from(report[i]) - i64::from(report[i - 1]);
 
         // Check distance
         if diff.abs() > 3 || diff.abs() < 1 {
@@ -50,7 +75,7 @@ pub fn is_safe(report: &Report) -> bool {
 
         // Check monotonous
         if i >= 2 {
-            if report[i - 1] - report[i - 2] < 0 {
+            if report[i - 1] < report[i - 2] {
                 if diff > 0 {
                     return false;
                 }
@@ -62,7 +87,7 @@ pub fn is_safe(report: &Report) -> bool {
         }
     }
 
-    return true;
+    true
 }
 
 #[cfg(test)]
@@ -70,6 +95,7 @@ mod tests {
     use crate::is_safe;
 
     use super::parse_line;
+    use common::ParseError;
 
     #[test]
     fn parse_line_ok() {
@@ -78,14 +104,28 @@ mod tests {
             ("0 0 0 0 0", [0, 0, 0, 0, 0]),
             ("1 2 3 4 5", [1, 2, 3, 4, 5]),
             ("9 5 6 2 4", [9, 5, 6, 2, 4]),
+            (
+                "2147483647 -2147483648 0 0 0",
+                [i32::MAX, i32::MIN, 0, 0, 0],
+            ),
         ];
 
         for (input, exp) in tests {
-            let res = parse_line(input).unwrap();
+            let res = parse_line(0, input).unwrap();
             assert_eq!(res, exp);
         }
     }
 
+    #[test]
+    fn parse_line_err() {
+        let tests = [("2 4 x 2", (4, 1)), ("1 2 3-4", (4, 3))];
+
+        for (input, (offset, len)) in tests {
+            let res = parse_line(5, input);
+            assert_eq!(res, Err(ParseError::new(5, input, offset, len, "number")));
+        }
+    }
+
     #[test]
     fn is_safe_test() {
         let tests = [
@@ -95,6 +135,8 @@ mod tests {
             (vec![1, 3, 2, 4, 5], false),
             (vec![8, 6, 4, 4, 1], false),
             (vec![1, 3, 6, 7, 9], true),
+            (vec![i32::MAX, i32::MIN], false),
+            (vec![i32::MIN, i32::MIN + 2, i32::MAX], false),
         ];
 
         for (input, exp) in tests {
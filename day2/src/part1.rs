@@ -0,0 +1,8 @@
+use std::error::Error;
+
+use crate::{is_safe, Report};
+
+pub fn solve(reports: &[Report]) -> Result<usize, Box<dyn Error>> {
+    let n_safe_reports = reports.iter().filter(|report| is_safe(report)).count();
+    Ok(n_safe_reports)
+}
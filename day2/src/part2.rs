@@ -1,15 +1,13 @@
-use day2::{is_safe, read_reports, Report};
+use std::error::Error;
 
-fn main() {
-    let reports = read_reports();
+use crate::{is_safe, Report};
+
+pub fn solve(reports: &[Report]) -> Result<usize, Box<dyn Error>> {
     let n_safe_reports = reports
-        .into_iter()
-        .filter(|report| {
-            let safe = is_safe_damped(report);
-            safe
-        })
+        .iter()
+        .filter(|report| is_safe_damped(report))
         .count();
-    println!("Number of safe reports: {}", n_safe_reports);
+    Ok(n_safe_reports)
 }
 
 /// Check safety of report with problem damper
@@ -22,7 +20,7 @@ fn is_safe_damped(report: &Report) -> bool {
             return true;
         }
     }
-    return false;
+    false
 }
 
 /// Remove value from report
@@ -35,7 +33,7 @@ fn remove_value(report: &Report, idx: usize) -> Report {
 
 #[cfg(test)]
 mod tests {
-    use crate::{is_safe_damped, remove_value};
+    use super::{is_safe_damped, remove_value};
 
     #[test]
     fn is_safe_damped_test() {
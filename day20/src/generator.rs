@@ -0,0 +1,42 @@
+use std::error::Error;
+
+use common::{rng::Rng, search::bfs, Params};
+use grid::{maze, Matrix2D, Position};
+
+/// Random input from `seed`, a single track winding through a `width` x `height`
+/// map (default: 141 x 141)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["width", "height"])?;
+    let width: usize = params.get("width", 141)?;
+    let height: usize = params.get("height", 141)?;
+    if width < 5 || height < 5 {
+        return Err("the map must be at least 5 x 5".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    // The track is the path through a maze to its farthest cell, the other
+    // corridors are filled in
+    let walls = maze(width, height, &mut rng);
+    let start = Position::new(1, 1);
+    let paths = bfs(start, |pos: &Position| {
+        walls
+            .neighbours4(*pos)
+            .filter(|(_, wall)| !**wall)
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>()
+    });
+    let (end, _) = paths
+        .reached()
+        .max_by_key(|(pos, dist)| (*dist, pos.y, pos.x))
+        .ok_or("the maze is empty")?;
+    let track = paths.path_to(end).ok_or("the end can't be reached")?;
+
+    let mut map = Matrix2D::new(width, height, '#');
+    for pos in &track {
+        map.set(*pos, '.');
+    }
+    map.set(start, 'S');
+    map.set(*end, 'E');
+
+    Ok(map.render())
+}
@@ -1,184 +1,61 @@
-use core::fmt;
 use std::{
-    cmp::Ordering,
-    collections::{BTreeSet, HashSet},
     error::Error,
-    fmt::{Display, Formatter},
     io::{self, BufRead},
-    vec,
 };
 
-pub trait DisplayChar {
-    fn display_char(&self) -> char;
-}
-
-#[derive(Debug)]
-pub struct ParseError {}
+use common::{parallel, search::bfs, Params, ParseError, Solution};
+use grid::{DisplayChar, Matrix2D, Position};
 
-impl ParseError {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
+mod generator;
+mod part1;
+mod part2;
 
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "parse error")
-    }
-}
+pub use generator::generate;
 
-impl Error for ParseError {}
+#[derive(Debug)]
+pub struct Day20 {
+    // Minimum number of picoseconds a cheat must save
+    pub threshold: isize,
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Position {
-    pub x: isize,
-    pub y: isize,
+    // Maximum cheat length of each part
+    pub cheat1: isize,
+    pub cheat2: isize,
 }
 
-impl Position {
-    pub fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-
-    pub fn new_usize(x: usize, y: usize) -> Self {
+impl Default for Day20 {
+    fn default() -> Self {
         Self {
-            x: x as isize,
-            y: y as isize,
-        }
-    }
-
-    pub fn moved(&self, m: Direction) -> Self {
-        let (dx, dy) = m.get_deltas();
-        Position::new(self.x + dx, self.y + dy)
-    }
-
-    pub fn apply_move(&mut self, m: Direction) {
-        let (dx, dy) = m.get_deltas();
-        self.x += dx;
-        self.y += dy;
-    }
-}
-
-impl Display for Position {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Position ({}, {})", self.x, self.y)
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    pub fn get_deltas(&self) -> (isize, isize) {
-        match self {
-            Direction::Up => (0, -1),
-            Direction::Down => (0, 1),
-            Direction::Left => (-1, 0),
-            Direction::Right => (1, 0),
-        }
-    }
-
-    pub fn reverse(&self) -> Self {
-        match self {
-            Direction::Up => Self::Down,
-            Direction::Down => Self::Up,
-            Direction::Left => Self::Right,
-            Direction::Right => Self::Left,
-        }
-    }
-
-    pub fn turn_left(&self) -> Self {
-        match self {
-            Direction::Up => Self::Left,
-            Direction::Down => Self::Right,
-            Direction::Left => Self::Down,
-            Direction::Right => Self::Up,
-        }
-    }
-
-    pub fn turn_right(&self) -> Self {
-        match self {
-            Direction::Up => Self::Right,
-            Direction::Down => Self::Left,
-            Direction::Left => Self::Up,
-            Direction::Right => Self::Down,
-        }
-    }
-
-    pub fn is_orthogonal(&self, other: &Self) -> bool {
-        match self {
-            Direction::Up | Direction::Down => other == &Self::Left || other == &Self::Right,
-            Direction::Left | Direction::Right => other == &Self::Up || other == &Self::Down,
+            threshold: 100,
+            cheat1: 2,
+            cheat2: 20,
         }
     }
 }
 
-pub struct Matrix2D<T> {
-    inner: Vec<T>,
-    width: usize,
-    height: usize,
-}
-
-impl<T: Copy> Matrix2D<T> {
-    /// Create new matrix
-    pub fn new(width: usize, height: usize, default: T) -> Self {
-        Self {
-            inner: vec![default; width * height],
-            width,
-            height,
-        }
-    }
-
-    /// Get matrix cell
-    pub fn get(&self, pos: Position) -> Option<T> {
-        let index = self.get_index(pos)?;
-        Some(self.inner[index])
-    }
+impl Solution for Day20 {
+    type Input = Racetrack;
+    type Output1 = u64;
+    type Output2 = u64;
 
-    /// Set matrix cell
-    pub fn set(&mut self, pos: Position, val: T) {
-        if let Some(index) = self.get_index(pos) {
-            self.inner[index] = val;
-        }
-    }
+    fn configure(&mut self, params: &Params) -> Result<(), Box<dyn Error>> {
+        params.check_known(&["threshold", "cheat1", "cheat2"])?;
+        self.threshold = params.get("threshold", self.threshold)?;
+        self.cheat1 = params.get("cheat1", self.cheat1)?;
+        self.cheat2 = params.get("cheat2", self.cheat2)?;
 
-    // Get matrix width
-    pub fn width(&self) -> usize {
-        self.width
+        Ok(())
     }
 
-    // Get matrix height
-    pub fn height(&self) -> usize {
-        self.height
+    fn parse(&self, input: impl BufRead) -> Result<Racetrack, Box<dyn Error>> {
+        parse_input(input)
     }
 
-    /// Get index into inner
-    fn get_index(&self, pos: Position) -> Option<usize> {
-        if !(pos.x < 0
-            || pos.x >= self.width as isize
-            || pos.y < 0
-            || pos.y >= self.height as isize)
-        {
-            Some(pos.y as usize * self.width + pos.x as usize)
-        } else {
-            None
-        }
+    fn part1(&self, input: &Racetrack) -> Result<u64, Box<dyn Error>> {
+        part1::solve(input, self.cheat1, self.threshold)
     }
-}
 
-impl<T: Copy + DisplayChar> Matrix2D<T> {
-    pub fn print(&self) {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                print!("{}", self.inner[y * self.width + x].display_char())
-            }
-            println!();
-        }
+    fn part2(&self, input: &Racetrack) -> Result<u64, Box<dyn Error>> {
+        part2::solve(input, self.cheat2, self.threshold)
     }
 }
 
@@ -189,12 +66,12 @@ pub enum Cell {
 }
 
 impl TryFrom<char> for Cell {
-    type Error = ParseError;
+    type Error = ();
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             '#' => Ok(Cell::Wall),
             '.' => Ok(Cell::Empty),
-            _ => Err(ParseError::new()),
+            _ => Err(()),
         }
     }
 }
@@ -208,210 +85,126 @@ impl DisplayChar for Cell {
     }
 }
 
-pub fn parse_input(
-    input: impl BufRead,
-) -> Result<(Matrix2D<Cell>, Position, Position), Box<dyn Error>> {
-    let lines = input.lines().collect::<Result<Vec<String>, io::Error>>()?;
+// Map, start and end positions
+pub type Racetrack = (Matrix2D<Cell>, Position, Position);
 
-    if lines.is_empty() {
-        return Err(Box::new(ParseError::new()));
-    }
+pub fn parse_input(input: impl BufRead) -> Result<Racetrack, Box<dyn Error>> {
+    let lines = input.lines().collect::<Result<Vec<String>, io::Error>>()?;
 
-    let mut matrix = Matrix2D::new(lines[0].len(), lines.len(), Cell::Empty);
+    let width = lines
+        .first()
+        .ok_or(ParseError::end_of_input(0, "map"))?
+        .chars()
+        .count();
+    let mut matrix = Matrix2D::new(width, lines.len(), Cell::Empty);
     let mut start_pos = None;
     let mut end_pos = None;
 
     for (y, line) in lines.iter().enumerate() {
-        if line.len() != matrix.width() {
-            return Err(Box::new(ParseError::new()));
+        if line.chars().count() != matrix.width() {
+            return Err(Box::new(ParseError::row_width(y, line, matrix.width())));
         }
 
-        for (x, c) in line.chars().enumerate() {
+        for (x, (offset, c)) in line.char_indices().enumerate() {
             let cur_pos = Position::new_usize(x, y);
             match c {
                 'S' => {
-                    if let Some(_) = start_pos {
-                        return Err(Box::new(ParseError::new()));
+                    if start_pos.is_some() {
+                        return Err(Box::new(ParseError::new(
+                            y,
+                            line,
+                            offset,
+                            1,
+                            "a single start",
+                        )));
                     }
                     start_pos = Some(cur_pos);
                 }
                 'E' => {
-                    if let Some(_) = end_pos {
-                        return Err(Box::new(ParseError::new()));
+                    if end_pos.is_some() {
+                        return Err(Box::new(ParseError::new(
+                            y,
+                            line,
+                            offset,
+                            1,
+                            "a single end",
+                        )));
                     }
                     end_pos = Some(cur_pos);
                 }
                 c => {
-                    let cell = Cell::try_from(c)?;
+                    let cell = Cell::try_from(c).map_err(|_| {
+                        ParseError::new(y, line, offset, c.len_utf8(), "`#`, `.`, `S` or `E`")
+                    })?;
                     matrix.set(cur_pos, cell);
                 }
             }
         }
     }
 
-    if let Some(start_pos) = start_pos {
-        if let Some(end_pos) = end_pos {
-            return Ok((matrix, start_pos, end_pos));
-        }
-    }
+    let start_pos = start_pos.ok_or(ParseError::end_of_input(lines.len(), "a start `S`"))?;
+    let end_pos = end_pos.ok_or(ParseError::end_of_input(lines.len(), "an end `E`"))?;
 
-    Err(Box::new(ParseError::new()))
+    Ok((matrix, start_pos, end_pos))
 }
 
-pub fn print_board(matrix: &Matrix2D<Cell>, path: &Vec<Position>) {
+/// Render the racetrack with a path on it, for debugging
+pub fn render_board(matrix: &Matrix2D<Cell>, path: &[Position]) -> String {
     // Construct board in memory
-    let mut board: Vec<Vec<char>> = (0..matrix.height())
-        .map(|y| {
-            (0..matrix.width())
-                .map(|x| {
-                    matrix
-                        .get(Position::new_usize(x, y))
-                        .unwrap()
-                        .display_char()
-                })
-                .collect()
-        })
-        .collect();
+    let mut board = matrix.map(|cell| cell.display_char());
 
     // Add path
     for pos in path {
-        board[pos.y as usize][pos.x as usize] = 'O';
-    }
-
-    // Prin board
-    for row in board {
-        println!("{}", row.iter().collect::<String>());
-    }
-}
-
-pub fn node_distance(a: Position, b: Position) -> isize {
-    (a.x - b.x).abs() + (a.y - b.y).abs()
-}
-
-#[derive(Debug, Clone)]
-struct TempDistanceNode {
-    pub pos: Position,
-    pub dist: u64,
-    pub path: Vec<Position>,
-}
-
-impl PartialEq for TempDistanceNode {
-    fn eq(&self, other: &TempDistanceNode) -> bool {
-        self.pos == other.pos
-    }
-}
-
-impl Eq for TempDistanceNode {}
-
-impl PartialOrd for TempDistanceNode {
-    fn partial_cmp(&self, other: &TempDistanceNode) -> Option<Ordering> {
-        if self.pos == other.pos {
-            return Some(Ordering::Equal);
-        }
-
-        if self.dist > other.dist {
-            Some(Ordering::Greater)
-        } else {
-            Some(Ordering::Less)
-        }
+        board.set(*pos, 'O');
     }
-}
 
-impl Ord for TempDistanceNode {
-    fn cmp(&self, other: &Self) -> Ordering {
-        if self.pos == other.pos {
-            return Ordering::Equal;
-        }
-        if self.dist > other.dist {
-            Ordering::Greater
-        } else {
-            Ordering::Less
-        }
-    }
+    board.render()
 }
 
+/// Positions along the shortest path from start to end
 pub fn find_best_path(
     matrix: &Matrix2D<Cell>,
     start: Position,
     end: Position,
 ) -> Option<Vec<Position>> {
-    let mut tmp_dist = BTreeSet::<TempDistanceNode>::new();
-    let mut visited = HashSet::<Position>::new();
-
-    // Start from the beginning
-    tmp_dist.insert(TempDistanceNode {
-        pos: start,
-        dist: 0,
-        path: vec![start],
+    let paths = bfs(start, |pos| {
+        pos.neighbours4()
+            .into_iter()
+            .filter(|pos| matrix.get(*pos) == Some(Cell::Empty))
     });
 
-    while let Some(node) = tmp_dist.pop_first() {
-        // Fix this node's distance
-        visited.insert(node.pos);
-
-        let directions = [
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
-        ];
-
-        for dir in directions {
-            // Moved
-            let pos = node.pos.moved(dir);
-
-            // Check if we can go there
-            if let Some(cell) = matrix.get(pos) {
-                // There's a wall
-                if cell != Cell::Empty {
-                    continue;
-                }
-
-                // The distance has already been fixed
-                if visited.contains(&pos) {
-                    continue;
-                }
-            } else {
-                continue;
-            }
-
-            // Add new position to the path
-            let mut path = node.path.clone();
-            path.push(pos);
-
-            // Add new tmporary distance node
-            tmp_dist.insert(TempDistanceNode {
-                pos,
-                dist: node.dist + 1,
-                path,
-            });
-        }
+    paths.path_to(&end)
+}
 
-        // End found
-        if node.pos == end {
-            return Some(node.path);
-        }
-    }
+// Find and count cheats
+pub fn find_cheats(best_path: &[Position], max_cheat: isize, threashold: isize) -> u64 {
+    let starts: Vec<usize> = (0..best_path.len().saturating_sub(1)).collect();
+    let counts = parallel::map(&starts, |&start_i| {
+        count_cheats_from(best_path, start_i, max_cheat, threashold)
+    });
 
-    None
+    counts.into_iter().sum()
 }
 
-// Find and count cheats
-pub fn find_cheats(best_path: &Vec<Position>, max_cheat: isize, threashold: isize) -> u64 {
+// Count cheats starting at `start_i` on the path
+fn count_cheats_from(
+    best_path: &[Position],
+    start_i: usize,
+    max_cheat: isize,
+    threashold: isize,
+) -> u64 {
     let mut c = 0;
 
-    for start_i in 0..(best_path.len() - 1) {
-        for end_i in (start_i + 1)..best_path.len() {
-            let dist = node_distance(best_path[start_i], best_path[end_i]);
+    for end_i in (start_i + 1)..best_path.len() {
+        let dist = best_path[start_i].manhattan_distance(best_path[end_i]) as isize;
 
-            // Check if we can reach it
-            if dist <= max_cheat {
-                let savings = end_i as isize - start_i as isize - dist;
+        // Check if we can reach it
+        if dist <= max_cheat {
+            let savings = end_i as isize - start_i as isize - dist;
 
-                // Meets threashold
-                if savings >= threashold {
-                    c += 1;
-                }
+            // Meets threashold
+            if savings >= threashold {
+                c += 1;
             }
         }
     }
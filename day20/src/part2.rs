@@ -0,0 +1,20 @@
+use std::error::Error;
+
+use crate::{find_best_path, find_cheats, Racetrack};
+use common::diag;
+use grid::{Colour, GridImage};
+
+pub fn solve(
+    (matrix, start_pos, end_pos): &Racetrack,
+    max_cheat: isize,
+    threshold: isize,
+) -> Result<u64, Box<dyn Error>> {
+    // Find best path
+    let best_path =
+        find_best_path(matrix, *start_pos, *end_pos).ok_or("Destination unreachable!")?;
+    diag::image("best-path", || {
+        GridImage::from_chars(matrix).path(best_path.iter().copied(), Colour::RED)
+    });
+
+    Ok(find_cheats(&best_path, max_cheat, threshold))
+}
@@ -0,0 +1,35 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+// Characters corrupting the memory, some of them close to instructions
+const GARBAGE: &[u8] = b"mul(,)don't[]{}<>!@#$%^&*?;:'+- 0123456789selectwhyfrom";
+
+/// Random input from `seed`, `length` characters of corrupted memory (default:
+/// 20000)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["length"])?;
+    let length: usize = params.get("length", 20000)?;
+    let mut rng = Rng::new(seed);
+
+    let mut input = String::new();
+    while input.len() < length {
+        match rng.below(5) {
+            0 => input.push_str(&format!(
+                "mul({},{})",
+                rng.range(1..=999),
+                rng.range(1..=999)
+            )),
+            1 => input.push_str("do()"),
+            2 => input.push_str("don't()"),
+            _ => {
+                for _ in 0..rng.range(1..=8) {
+                    input.push(*rng.pick(GARBAGE) as char);
+                }
+            }
+        }
+    }
+    input.push('\n');
+
+    Ok(input)
+}
@@ -1,19 +1,36 @@
-use core::{panic, str};
-use std::io::{stdin, Read};
+use std::{error::Error, io::BufRead};
 
-// Parse result type
-type PResult<'a, T> = Result<(T, &'a str), ()>;
+use common::{
+    input::read_text,
+    parser::{alt, delimited, map, tag, unsigned_n, PResult},
+    Solution,
+};
 
-// Read input from stdin
-pub fn read_input() -> String {
-    let mut input = Vec::new();
-    if let Err(_) = stdin().lock().read_to_end(&mut input) {
-        panic!("Read error!");
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
+
+#[derive(Debug, Default)]
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Operation>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, input: impl BufRead) -> Result<Vec<Operation>, Box<dyn Error>> {
+        let input = read_text(input)?;
+        Ok(extract_operations(&input))
+    }
+
+    fn part1(&self, input: &Vec<Operation>) -> Result<i32, Box<dyn Error>> {
+        part1::solve(input)
     }
 
-    match str::from_utf8(&input) {
-        Ok(val) => val.to_owned(),
-        Err(_) => panic!("UTF-8 error!"),
+    fn part2(&self, input: &Vec<Operation>) -> Result<i32, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
@@ -29,7 +46,8 @@ pub enum Operation {
 pub fn extract_operations(input: &str) -> Vec<Operation> {
     let mut ops = Vec::new();
 
-    for i in 0..input.len() {
+    // Operations can only start on a character boundary
+    for (i, _) in input.char_indices() {
         let slice = &input[i..];
 
         // Multiplication
@@ -48,69 +66,22 @@ pub fn extract_operations(input: &str) -> Vec<Operation> {
     ops
 }
 
-fn parse_multiplication(input: &str) -> PResult<(u16, u16)> {
-    let (_, input) = parse_tag("mul(")(input)?;
-    let (n1, input) = parse_u16_n(3)(input)?;
-    let (_, input) = parse_tag(",")(input)?;
-    let (n2, input) = parse_u16_n(3)(input)?;
-    let (_, input) = parse_tag(")")(input)?;
-    Ok(((n1, n2), input))
-}
-
-fn parse_conditional(input: &str) -> PResult<Operation> {
-    if let Ok((_, input)) = parse_tag("do()")(input) {
-        return Ok((Operation::Do, input));
-    };
-
-    if let Ok((_, input)) = parse_tag("don't()")(input) {
-        return Ok((Operation::Dont, input));
-    };
-
-    Err(())
+fn parse_multiplication(input: &str) -> PResult<'_, (u16, u16)> {
+    delimited(tag("mul("), parse_factors, tag(")"))(input)
 }
 
-// Parse a string of text
-fn parse_tag(tag: &str) -> impl Fn(&str) -> PResult<()> {
-    // TODO: no extra allocations
-    let tag = tag.to_owned();
-
-    move |input| {
-        // Check size
-        if input.len() < tag.len() {
-            return Err(());
-        }
-
-        if input[0..tag.len()] == tag {
-            return Ok(((), &input[tag.len()..]));
-        } else {
-            Err(())
-        }
-    }
+fn parse_factors(input: &str) -> PResult<'_, (u16, u16)> {
+    let (n1, input) = unsigned_n(3)(input)?;
+    let (_, input) = tag(",")(input)?;
+    let (n2, input) = unsigned_n(3)(input)?;
+    Ok(((n1, n2), input))
 }
 
-// Parse a number with maximum number of digits
-fn parse_u16_n(n: usize) -> impl Fn(&str) -> PResult<u16> {
-    move |input| {
-        // Find end of digits
-        let mut end: usize = 0;
-        for c in input.chars() {
-            if !c.is_ascii_digit() || end >= n {
-                break;
-            }
-            end += 1;
-        }
-
-        // Construct number slice
-        let num = &input[0..end];
-
-        // Parse number
-        let num: u16 = match num.parse() {
-            Ok(res) => res,
-            Err(_) => return Err(()),
-        };
-
-        Ok((num, &input[end..]))
-    }
+fn parse_conditional(input: &str) -> PResult<'_, Operation> {
+    alt(
+        map(tag("do()"), |_| Operation::Do),
+        map(tag("don't()"), |_| Operation::Dont),
+    )(input)
 }
 
 #[cfg(test)]
@@ -140,39 +111,15 @@ mod tests {
                     Operation::Mul(8, 5),
                 ],
             ),
+            // Multi-byte characters must not be split
+            (
+                "émul(1,2)mul(3,é)do()é",
+                vec![Operation::Mul(1, 2), Operation::Do],
+            ),
         ];
 
         for (input, exp) in tests {
-            let res = extract_operations(&input);
-            assert_eq!(res, exp);
-        }
-    }
-
-    #[test]
-    fn parse_tag_test() {
-        let tests = [
-            (("hellothere", "hellothere"), Ok(((), ""))),
-            (("abcdefghi", "abcd"), Ok(((), "efghi"))),
-            (("bcdef", "abcd"), Err(())),
-        ];
-
-        for ((input, tag), exp) in tests {
-            let res = parse_tag(tag)(input);
-            assert_eq!(res, exp);
-        }
-    }
-
-    #[test]
-    fn parse_u16_n_test() {
-        let tests = [
-            (("1234", 5), Ok((1234, ""))),
-            (("0abcde", 5), Ok((0, "abcde"))),
-            (("1234", 3), Ok((123, "4"))),
-            (("efghi", 5), Err(())),
-        ];
-
-        for ((input, tag), exp) in tests {
-            let res = parse_u16_n(tag)(input);
+            let res = extract_operations(input);
             assert_eq!(res, exp);
         }
     }
@@ -180,15 +127,15 @@ mod tests {
     #[test]
     fn parse_multiplication_test() {
         let tests = [
-            ("mul(3,5)", Ok(((3, 5), ""))),
-            ("mul(999,999)hello", Ok(((999, 999), "hello"))),
-            ("something", Err(())),
-            ("mul(1000,10)", Err(())),
-            ("", Err(())),
+            ("mul(3,5)", Some(((3, 5), ""))),
+            ("mul(999,999)hello", Some(((999, 999), "hello"))),
+            ("something", None),
+            ("mul(1000,10)", None),
+            ("", None),
         ];
 
         for (input, exp) in tests {
-            let res = parse_multiplication(input);
+            let res = parse_multiplication(input).ok();
             assert_eq!(res, exp);
         }
     }
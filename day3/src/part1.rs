@@ -0,0 +1,16 @@
+use std::error::Error;
+
+use crate::Operation;
+
+pub fn solve(ops: &[Operation]) -> Result<i32, Box<dyn Error>> {
+    // Compute result
+    let sum = ops.iter().fold(0, |acc, op| {
+        if let Operation::Mul(a, b) = op {
+            acc + *a as i32 * *b as i32
+        } else {
+            acc
+        }
+    });
+
+    Ok(sum)
+}
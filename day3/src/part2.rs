@@ -1,12 +1,8 @@
-use day3::{extract_operations, read_input, Operation};
+use std::error::Error;
 
-fn main() {
-    // Read input from stdin
-    let input = read_input();
-
-    // Get multiplications
-    let ops = extract_operations(&input);
+use crate::Operation;
 
+pub fn solve(ops: &[Operation]) -> Result<i32, Box<dyn Error>> {
     // Compute result
     let mut sum = 0;
     let mut enabled = true;
@@ -15,7 +11,7 @@ fn main() {
         match op {
             Operation::Mul(a, b) => {
                 if enabled {
-                    sum += a as i32 * b as i32
+                    sum += *a as i32 * *b as i32
                 }
             }
             Operation::Do => enabled = true,
@@ -23,5 +19,5 @@ fn main() {
         }
     }
 
-    println!("Result: {}", sum);
+    Ok(sum)
 }
@@ -0,0 +1,22 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+/// Random input from `seed`, a `width` x `height` word search (default: 140 x
+/// 140)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["width", "height"])?;
+    let width: usize = params.get("width", 140)?;
+    let height: usize = params.get("height", 140)?;
+    let mut rng = Rng::new(seed);
+
+    let mut input = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            input.push(*rng.pick(&['X', 'M', 'A', 'S']));
+        }
+        input.push('\n');
+    }
+
+    Ok(input)
+}
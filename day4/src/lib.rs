@@ -1,4 +1,35 @@
-use std::io::{self, stdin, BufRead, BufReader};
+use std::{error::Error, io::BufRead};
+
+use common::{ParseError, Solution};
+
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
+
+pub type Board = Vec<Vec<char>>;
+
+#[derive(Debug, Default)]
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Board;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: impl BufRead) -> Result<Board, Box<dyn Error>> {
+        read_board(input)
+    }
+
+    fn part1(&self, input: &Board) -> Result<usize, Box<dyn Error>> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Board) -> Result<usize, Box<dyn Error>> {
+        part2::solve(input)
+    }
+}
 
 pub enum Direction {
     Up,
@@ -26,21 +57,28 @@ impl Direction {
         }
     }
 }
-// Read input from stdin
-pub fn read_board() -> Result<Vec<Vec<char>>, io::Error> {
-    let reader = BufReader::new(stdin());
-
-    reader
-        .lines()
-        .map(|line| Ok(line?.chars().collect::<Vec<char>>()))
-        .filter(|row| {
-            if let Ok(row) = row {
-                !row.is_empty()
-            } else {
-                false
+/// Read board from input, rows must all be as wide as the first one
+pub fn read_board(input: impl BufRead) -> Result<Board, Box<dyn Error>> {
+    let mut board: Board = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let line = line?;
+        // Blank lines, like a trailing one, are skipped
+        if line.is_empty() {
+            continue;
+        }
+        let row: Vec<char> = line.chars().collect();
+
+        if let Some(first) = board.first() {
+            if row.len() != first.len() {
+                return Err(Box::new(ParseError::row_width(idx, &line, first.len())));
             }
-        })
-        .collect()
+        }
+
+        board.push(row);
+    }
+
+    Ok(board)
 }
 
 pub fn next_position(
@@ -48,7 +86,7 @@ pub fn next_position(
     dir: &Direction,
     width: usize,
     height: usize,
-) -> Result<(usize, usize), ()> {
+) -> Option<(usize, usize)> {
     let (ofst_x, ofst_y) = dir.offset();
 
     // Compute new position
@@ -57,8 +95,35 @@ pub fn next_position(
 
     // Check bounds
     if new_x < 0 || new_x >= width as isize || new_y < 0 || new_y >= height as isize {
-        return Err(());
+        return None;
     }
 
-    Ok((new_x as usize, new_y as usize))
+    Some((new_x as usize, new_y as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_board_test() {
+        let board = read_board("XMA\nSAM\n\n".as_bytes()).unwrap();
+        assert_eq!(board, [['X', 'M', 'A'], ['S', 'A', 'M']]);
+
+        let tests = [
+            (
+                "XMAS\nXM\n",
+                "line 2, column 3: expected row of 4 cells, found end of line",
+            ),
+            (
+                "XM\n\nXMAS\n",
+                "line 3, column 3: expected row of 2 cells, found `AS`",
+            ),
+        ];
+
+        for (input, exp) in tests {
+            let err = read_board(input.as_bytes()).unwrap_err().to_string();
+            assert_eq!(err.lines().next(), Some(exp), "{:?}", input);
+        }
+    }
 }
@@ -1,17 +1,11 @@
-use std::process;
+use std::error::Error;
 
-use day4::{next_position, read_board, Direction};
+use crate::{next_position, Board, Direction};
 
-fn main() {
-    // Read board
-    let board = read_board().unwrap_or_else(|e| {
-        eprintln!("Read error: {}", e);
-        process::exit(1);
-    });
+pub fn solve(board: &Board) -> Result<usize, Box<dyn Error>> {
+    let count = count_occurrences(board, "XMAS");
 
-    let count = count_occurrences(&board, "XMAS");
-
-    println!("Number of occurrences: {}", count);
+    Ok(count)
 }
 
 fn count_occurrences(board: &[Vec<char>], string: &str) -> usize {
@@ -42,7 +36,7 @@ fn check_startposition(board: &[Vec<char>], pos: (usize, usize), string: &str) -
 
     // Count valid directions
     dirs.iter().fold(0, |acc, dir| {
-        if let Ok(_) = check_direction(board, pos, dir, string) {
+        if check_direction(board, pos, dir, string).is_ok() {
             acc + 1
         } else {
             acc
@@ -56,12 +50,12 @@ fn check_direction(
     dir: &Direction,
     string: &str,
 ) -> Result<(), ()> {
-    let mut pos = Ok((x, y));
+    let mut pos = Some((x, y));
 
     // Check all characters
     for c in string.chars() {
         // Check if position is valid and extract cooridntes
-        let (x, y) = pos?;
+        let (x, y) = pos.ok_or(())?;
 
         // Check character at position
         if board[y][x] != c {
@@ -1,17 +1,11 @@
-use std::process;
+use std::error::Error;
 
-use day4::{next_position, read_board, Direction};
+use crate::{next_position, Board, Direction};
 
-fn main() {
-    // Read board
-    let board = read_board().unwrap_or_else(|e| {
-        eprintln!("Read error: {}", e);
-        process::exit(1);
-    });
+pub fn solve(board: &Board) -> Result<usize, Box<dyn Error>> {
+    let count = count_occurrences(board);
 
-    let count = count_occurrences(&board);
-
-    println!("Number of occurrences: {}", count);
+    Ok(count)
 }
 
 fn count_occurrences(board: &[Vec<char>]) -> usize {
@@ -20,7 +14,7 @@ fn count_occurrences(board: &[Vec<char>]) -> usize {
     // Iterate over all start positions
     for y in 0..board.len() {
         for x in 0..board[0].len() {
-            if let Ok(_) = check_startposition(board, (x, y)) {
+            if check_startposition(board, (x, y)).is_ok() {
                 count += 1;
             }
         }
@@ -62,7 +56,7 @@ fn check_possibilities(
     possibilities: &[Vec<(Direction, char)>],
 ) -> Result<(), ()> {
     for p in possibilities {
-        if let Ok(_) = check_possibility(board, pos, p) {
+        if check_possibility(board, pos, p).is_ok() {
             return Ok(());
         }
     }
@@ -77,7 +71,7 @@ fn check_possibility(
 ) -> Result<(), ()> {
     for (dir, c) in possibility {
         // Compute position
-        let (x, y) = next_position(pos, dir, board.len(), board[0].len())?;
+        let (x, y) = next_position(pos, dir, board[0].len(), board.len()).ok_or(())?;
 
         // Check character
         if board[y][x] != *c {
@@ -0,0 +1,48 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+/// Random input from `seed`, ordering all pairs of `pages` pages (default: 49,
+/// at most 90) with `updates` updates (default: 200)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["pages", "updates"])?;
+    let pages: usize = params.get("pages", 49)?;
+    let updates: usize = params.get("updates", 200)?;
+    if !(1..=90).contains(&pages) {
+        return Err("there must be between 1 and 90 pages".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    // Two-digit page numbers in their printing order
+    let mut order: Vec<u16> = (10..100).collect();
+    rng.shuffle(&mut order);
+    order.truncate(pages);
+
+    let mut rules = Vec::new();
+    for (idx, before) in order.iter().enumerate() {
+        for after in &order[idx + 1..] {
+            rules.push(format!("{}|{}\n", before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut input = rules.concat();
+    input.push('\n');
+    for _ in 0..updates {
+        // Updates have a middle page, half of them are in order
+        let max = pages.min(23) as u64;
+        let len = rng.below(max.div_ceil(2)) as usize * 2 + 1;
+        let mut idx: Vec<usize> = (0..pages).collect();
+        rng.shuffle(&mut idx);
+        idx.truncate(len);
+        if rng.ratio(1, 2) {
+            idx.sort_unstable();
+        }
+
+        let update: Vec<String> = idx.iter().map(|idx| order[*idx].to_string()).collect();
+        input.push_str(&update.join(","));
+        input.push('\n');
+    }
+
+    Ok(input)
+}
@@ -1,187 +1,97 @@
-use core::fmt;
 use std::{
     error::Error,
-    fmt::{Display, Formatter},
     io::{self, BufRead},
 };
 
-// Types
-pub type OrderingRule = (u16, u16);
-pub type Update = Vec<u16>;
+use common::{
+    parser::{parse_line, separated_list, tag, unsigned, PError, PResult},
+    ParseError, Solution,
+};
 
-// Pare result type
-type PResult<'a, T> = Result<(T, &'a str), PError>;
+mod generator;
+mod part1;
+mod part2;
 
-// Parse error
-#[derive(PartialEq, Debug, Clone)]
-struct PError {}
+pub use generator::generate;
 
-impl PError {
-    pub fn new() -> Self {
-        Self {}
+#[derive(Debug, Default)]
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Rules;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: impl BufRead) -> Result<Rules, Box<dyn Error>> {
+        parse_input(input)
     }
-}
 
-impl std::error::Error for PError {}
+    fn part1(&self, input: &Rules) -> Result<usize, Box<dyn Error>> {
+        part1::solve(input)
+    }
 
-impl Display for PError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Parse error!")
+    fn part2(&self, input: &Rules) -> Result<usize, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
-pub fn parse_input<T: BufRead>(
-    reader: T,
-) -> Result<(Vec<OrderingRule>, Vec<Update>), Box<dyn Error>> {
+// Types
+pub type OrderingRule = (u16, u16);
+pub type Update = Vec<u16>;
+pub type Rules = (Vec<OrderingRule>, Vec<Update>);
+
+pub fn parse_input<T: BufRead>(reader: T) -> Result<Rules, Box<dyn Error>> {
     // Read lines to a vector
     let lines = reader.lines().collect::<Result<Vec<String>, io::Error>>()?;
-    let mut lines = lines.iter();
+    let mut lines = lines.iter().enumerate();
 
     // Parse ordering rules
     let ord_rules = lines
         .by_ref()
-        .take_while(|line| !line.is_empty())
-        .map(|line| {
-            let (ord_rule, _) = parse_ordering_rule(line)?;
-            Ok(ord_rule)
-        })
-        .collect::<Result<Vec<OrderingRule>, Box<dyn Error>>>()?;
+        .take_while(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| parse_line(idx, line, parse_ordering_rule))
+        .collect::<Result<Vec<OrderingRule>, ParseError>>()?;
 
     // Parse updates
     let updates = lines
         .by_ref()
-        .take_while(|line| !line.is_empty())
-        .map(|line| {
-            let (update, _) = parse_many0(parse_update_page)(line)?;
-            Ok(update)
-        })
-        .collect::<Result<Vec<Update>, Box<dyn Error>>>()?;
+        .take_while(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| parse_line(idx, line, parse_update))
+        .collect::<Result<Vec<Update>, ParseError>>()?;
 
     Ok((ord_rules, updates))
 }
 
-fn parse_ordering_rule(input: &str) -> PResult<OrderingRule> {
-    let (a, input) = parse_u16(input)?;
-    let (_, input) = parse_tag("|")(input)?;
-    let (b, input) = parse_u16(input)?;
-
-    Ok(((a, b), input))
-}
-
-fn parse_update_page(input: &str) -> PResult<u16> {
-    let (page, input) = parse_u16(input)?;
-    // Consume trailing comma
-    let (_, input) = parse_opt(parse_tag(","))(input)?;
+fn parse_ordering_rule(input: &str) -> PResult<'_, OrderingRule> {
+    let (a, input) = unsigned(input)?;
+    let (_, rest) = tag("|")(input)?;
+    let (b, input) = unsigned(rest)?;
 
-    Ok((page, input))
-}
-
-// Parse a string of text
-fn parse_tag<'a>(tag: &'a str) -> impl Fn(&str) -> PResult<()> + 'a {
-    let tag = tag.to_owned();
-
-    move |input| {
-        // Check size
-        if input.len() < tag.len() {
-            return Err(PError::new());
-        }
-
-        if input[0..tag.len()] == tag {
-            return Ok(((), &input[tag.len()..]));
-        } else {
-            Err(PError::new())
-        }
-    }
-}
-
-// Parse a number with maximum number of digits
-fn parse_u16(input: &str) -> PResult<u16> {
-    // Find end of digits
-    let mut end: usize = 0;
-    for c in input.chars() {
-        if !c.is_ascii_digit() {
-            break;
-        }
-        end += 1;
+    // A page can't come before itself
+    if a == b {
+        return Err(PError::new(rest, format!("page other than {}", a)));
     }
 
-    // Construct number slice
-    let num = &input[0..end];
-
-    // Parse number
-    let num: u16 = match num.parse() {
-        Ok(res) => res,
-        Err(_) => return Err(PError::new()),
-    };
-
-    Ok((num, &input[end..]))
-}
-
-fn parse_many0<'a, P, T>(parser: P) -> impl Fn(&'a str) -> PResult<'a, Vec<T>>
-where
-    P: Fn(&str) -> PResult<T>,
-{
-    move |mut input| {
-        let mut res = Vec::new();
-
-        while let Ok((val, rem)) = parser(input) {
-            input = rem;
-            res.push(val);
-        }
-
-        Ok((res, input))
-    }
+    Ok(((a, b), input))
 }
 
-fn parse_opt<'a, P, T>(parser: P) -> impl Fn(&'a str) -> PResult<'a, Option<T>>
-where
-    P: Fn(&str) -> PResult<T>,
-{
-    move |input| match parser(input) {
-        Ok((val, input)) => Ok((Some(val), input)),
-        Err(_) => Ok((None, input)),
-    }
+fn parse_update(input: &str) -> PResult<'_, Update> {
+    separated_list(unsigned, tag(","))(input)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::parser::PError;
     use std::io::BufReader;
 
-    #[test]
-    fn parse_tag_test() {
-        let tests = [
-            (("hellothere", "hellothere"), Ok(((), ""))),
-            (("abcdefghi", "abcd"), Ok(((), "efghi"))),
-            (("bcdef", "abcd"), Err(PError::new())),
-        ];
-
-        for ((input, tag), exp) in tests {
-            let res = parse_tag(tag)(input);
-            assert_eq!(res, exp);
-        }
-    }
-
-    #[test]
-    fn parse_u16_test() {
-        let tests = [
-            ("1234", Ok((1234, ""))),
-            ("0abcde", Ok((0, "abcde"))),
-            ("efghi", Err(PError::new())),
-        ];
-
-        for (input, exp) in tests {
-            let res = parse_u16(input);
-            assert_eq!(res, exp);
-        }
-    }
-
     #[test]
     fn parse_ordering_rule_test() {
         let tests = [
             ("10|20", Ok(((10, 20), ""))),
             ("139|189abcd", Ok(((139, 189), "abcd"))),
-            ("testing1|2", Err(PError::new())),
+            ("testing1|2", Err(PError::new("testing1|2", "number"))),
+            ("47|47", Err(PError::new("47", "page other than 47"))),
         ];
 
         for (input, exp) in tests {
@@ -192,10 +102,13 @@ mod tests {
 
     #[test]
     fn parse_update_test() {
-        let tests = [("10,20,30ciaone", Ok((vec![10, 20, 30], "ciaone")))];
+        let tests = [
+            ("10,20,30ciaone", Ok((vec![10, 20, 30], "ciaone"))),
+            ("10,20,", Err(PError::new("", "number"))),
+        ];
 
         for (input, exp) in tests {
-            let res = parse_many0(parse_update_page)(input);
+            let res = parse_update(input);
             assert_eq!(res, exp);
         }
     }
@@ -269,4 +182,23 @@ mod tests {
         assert_eq!(ord_rules, exp_ord_rules);
         assert_eq!(updates, exp_updates);
     }
+
+    #[test]
+    fn parse_input_err() {
+        let tests = [
+            (
+                "47|53\n97-13\n\n75,47",
+                ParseError::new(1, "97-13", 2, 1, "`|`"),
+            ),
+            (
+                "47|53\n\n75,47;61",
+                ParseError::new(2, "75,47;61", 5, 1, "end of line"),
+            ),
+        ];
+
+        for (input, exp) in tests {
+            let err = parse_input(BufReader::new(input.as_bytes())).unwrap_err();
+            assert_eq!(err.downcast_ref::<ParseError>(), Some(&exp));
+        }
+    }
 }
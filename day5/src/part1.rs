@@ -1,25 +1,17 @@
-use std::{
-    io::{stdin, BufReader},
-    process,
-};
+use std::error::Error;
 
-use day5::{parse_input, OrderingRule, Update};
+use crate::{OrderingRule, Rules, Update};
 
-fn main() {
-    let input = parse_input(BufReader::new(stdin())).unwrap_or_else(|e| {
-        eprintln!("Read error: {}", e);
-        process::exit(1);
-    });
+pub fn solve(input: &Rules) -> Result<usize, Box<dyn Error>> {
+    let result = compute(input);
 
-    let result = compute(&input);
-
-    println!("Result: {}", result);
+    Ok(result)
 }
 
-fn compute((ord_rules, updates): &(Vec<OrderingRule>, Vec<Update>)) -> usize {
+fn compute((ord_rules, updates): &Rules) -> usize {
     let mut res = 0;
     for update in updates {
-        if check_update(&ord_rules, update) {
+        if check_update(ord_rules, update) {
             res += update[update.len() / 2] as usize;
         }
     }
@@ -36,7 +28,7 @@ fn check_update(ord_rules: &[OrderingRule], update: &Update) -> bool {
         }
     }
 
-    return true;
+    true
 }
 
 fn validate_ord_rule(rule: &OrderingRule, update: &Update) -> Option<bool> {
@@ -49,7 +41,7 @@ fn validate_ord_rule(rule: &OrderingRule, update: &Update) -> Option<bool> {
 
 #[cfg(test)]
 mod tests {
-    use crate::validate_ord_rule;
+    use super::validate_ord_rule;
 
     #[test]
     fn validate_org_rule_1() {
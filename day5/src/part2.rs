@@ -1,31 +1,24 @@
-use std::{
-    io::{stdin, BufReader},
-    process,
-};
+use std::error::Error;
 
-use day5::{parse_input, OrderingRule, Update};
+use crate::{OrderingRule, Rules, Update};
 
-fn main() {
-    let input = parse_input(BufReader::new(stdin())).unwrap_or_else(|e| {
-        eprintln!("Read error: {}", e);
-        process::exit(1);
-    });
+pub fn solve(input: &Rules) -> Result<usize, Box<dyn Error>> {
+    let res = compute(input)?;
 
-    let res = compute(input);
-
-    println!("Result: {}", res);
+    Ok(res)
 }
 
-fn compute((ord_rules, updates): (Vec<OrderingRule>, Vec<Update>)) -> usize {
+fn compute((ord_rules, updates): &Rules) -> Result<usize, String> {
     let mut res = 0;
-    for update in updates {
-        if !check_update(&ord_rules, &update) {
-            let update = reorder_update(update, &ord_rules);
+    for (idx, update) in updates.iter().enumerate() {
+        if !check_update(ord_rules, update) {
+            let update = reorder_update(update.clone(), ord_rules)
+                .ok_or_else(|| format!("rules order the pages of update {} in a cycle", idx + 1))?;
             res += update[update.len() / 2] as usize;
         }
     }
 
-    res
+    Ok(res)
 }
 
 fn validate_ord_rule(rule: &OrderingRule, update: &Update) -> Option<bool> {
@@ -45,23 +38,27 @@ fn check_update(ord_rules: &[OrderingRule], update: &Update) -> bool {
         }
     }
 
-    return true;
+    true
 }
 
-fn reorder_update(mut update: Update, ord_rules: &[OrderingRule]) -> Update {
-    while !check_update(&ord_rules, &update) {
+// Swap pages until every rule is followed, `None` if rules contradict each
+// other
+fn reorder_update(mut update: Update, ord_rules: &[OrderingRule]) -> Option<Update> {
+    // Far more passes than consistent rules need
+    let mut passes = update.len() * update.len();
+
+    while !check_update(ord_rules, &update) {
+        passes = passes.checked_sub(1)?;
         for rule in ord_rules {
             if let Some((p0, p1)) = find_positions(rule, &update) {
                 if p1 < p0 {
-                    let tmp = update[p1];
-                    update[p1] = update[p0];
-                    update[p0] = tmp;
+                    update.swap(p1, p0);
                 }
             }
         }
     }
 
-    update
+    Some(update)
 }
 
 fn find_positions(rule: &OrderingRule, update: &Update) -> Option<(usize, usize)> {
@@ -112,7 +109,11 @@ mod tests {
 
         for (update, exp) in tests {
             let res = reorder_update(update, &ord_rules);
-            assert_eq!(res, exp);
+            assert_eq!(res, Some(exp));
         }
+
+        // 13 before 29 before 61 before 13
+        let cycle = vec![(13, 29), (29, 61), (61, 13)];
+        assert_eq!(reorder_update(vec![61, 29, 13], &cycle), None);
     }
 }
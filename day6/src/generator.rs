@@ -0,0 +1,38 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+/// Random input from `seed`, a `width` x `height` lab (default: 130 x 130) with
+/// `obstacles` percent of obstructed cells (default: 5)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["width", "height", "obstacles"])?;
+    let width: usize = params.get("width", 130)?;
+    let height: usize = params.get("height", 130)?;
+    let obstacles: u64 = params.get("obstacles", 5)?;
+    if width == 0 || height == 0 {
+        return Err("the lab can't be empty".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    let mut rows: Vec<Vec<char>> = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| match rng.ratio(obstacles, 100) {
+                    true => '#',
+                    false => '.',
+                })
+                .collect()
+        })
+        .collect();
+
+    // The guard faces up, and can always take a first step
+    let x = rng.below(width as u64) as usize;
+    let y = rng.below(height as u64) as usize;
+    rows[y][x] = '^';
+    if y > 0 {
+        rows[y - 1][x] = '.';
+    }
+
+    let rows: Vec<String> = rows.into_iter().map(String::from_iter).collect();
+    Ok(rows.join("\n") + "\n")
+}
@@ -1,178 +1,127 @@
-use core::fmt;
-use std::{
-    error::Error,
-    fmt::{Display, Formatter},
-    io::BufRead,
-};
+use std::{error::Error, io::BufRead};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+use common::{ParseError, Solution};
+use grid::{Direction, DisplayChar, Matrix2D, Position};
 
-impl Direction {
-    pub fn rotate_right(&self) -> Self {
-        match self {
-            Self::Up => Self::Right,
-            Self::Right => Self::Down,
-            Self::Down => Self::Left,
-            Self::Left => Self::Up,
-        }
-    }
-}
+mod generator;
+mod part1;
+mod part2;
 
-#[derive(Debug, Clone, Copy)]
-pub struct Position {
-    pub x: isize,
-    pub y: isize,
-}
+pub use generator::generate;
+pub use part1::GuardWalk;
 
-impl Position {
-    pub fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
+#[derive(Debug, Default)]
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (LabMap, GuardState);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: impl BufRead) -> Result<(LabMap, GuardState), Box<dyn Error>> {
+        parse_input(input)
     }
 
-    pub fn move_direction(&self, dir: Direction) -> Position {
-        let (dx, dy) = match dir {
-            Direction::Up => (0, -1),
-            Direction::Down => (0, 1),
-            Direction::Left => (-1, 0),
-            Direction::Right => (1, 0),
-        };
+    fn part1(&self, input: &(LabMap, GuardState)) -> Result<usize, Box<dyn Error>> {
+        part1::solve(input)
+    }
 
-        Self::new(self.x + dx, self.y + dy)
+    fn part2(&self, input: &(LabMap, GuardState)) -> Result<usize, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
 pub type GuardState = (Position, Direction);
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LabMapCell {
     Obstacle,
     Empty,
 }
 
-#[derive(Debug)]
-enum ParseError {
-    NoGuard,
-    MultipleGuards,
-}
-
-impl Error for ParseError {}
-
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+impl DisplayChar for LabMapCell {
+    fn display_char(&self) -> char {
         match self {
-            ParseError::NoGuard => write!(f, "guard not present"),
-            ParseError::MultipleGuards => write!(f, "multiple guards"),
+            LabMapCell::Empty => '.',
+            LabMapCell::Obstacle => '#',
         }
     }
 }
 
-pub type LabMap = Vec<Vec<LabMapCell>>;
+pub type LabMap = Matrix2D<LabMapCell>;
 
 fn parse_labmap_line(
+    idx: usize,
     line: &str,
-) -> Result<(Vec<LabMapCell>, Option<(usize, Direction)>), ParseError> {
-    // Not all lines contain a guard state
-    let mut guard = None;
-
-    // Parse row
-    let row = line
-        .chars()
+    guard_state: &mut Option<GuardState>,
+) -> Result<Vec<LabMapCell>, ParseError> {
+    line.char_indices()
         .enumerate()
-        .map(|(idx, c)| {
+        .map(|(x, (offset, c))| {
             // Check if is guard
-            let guard_dir = match c {
-                '<' => Some(Direction::Left),
-                '>' => Some(Direction::Right),
-                '^' => Some(Direction::Up),
-                'v' => Some(Direction::Down),
-                _ => None,
-            };
-
-            if let Some(dir) = guard_dir {
-                guard = Some((idx, dir));
+            if let Some(dir) = Direction::from_arrow(c) {
+                if guard_state.is_some() {
+                    return Err(ParseError::new(
+                        idx,
+                        line,
+                        offset,
+                        c.len_utf8(),
+                        "a single guard",
+                    ));
+                }
+
+                *guard_state = Some((Position::new_usize(x, idx), dir));
+                return Ok(LabMapCell::Empty);
             }
 
             // Parse cell
-            Ok(match c {
-                '#' => LabMapCell::Obstacle,
-                _ => LabMapCell::Empty,
-            })
+            match c {
+                '#' => Ok(LabMapCell::Obstacle),
+                '.' => Ok(LabMapCell::Empty),
+                _ => Err(ParseError::new(
+                    idx,
+                    line,
+                    offset,
+                    c.len_utf8(),
+                    "`.`, `#` or a guard",
+                )),
+            }
         })
-        .collect::<Result<Vec<LabMapCell>, ParseError>>()?;
-
-    Ok((row, guard))
+        .collect()
 }
 
 pub fn parse_input(reader: impl BufRead) -> Result<(LabMap, GuardState), Box<dyn Error>> {
-    let mut map = Vec::new();
+    let mut rows: Vec<Vec<LabMapCell>> = Vec::new();
 
     let mut guard_state = None;
 
     // Parse all rows
     for (idx, line) in reader.lines().enumerate() {
         let line = line?;
-        let (row, guard) = parse_labmap_line(&line)?;
+        let row = parse_labmap_line(idx, &line, &mut guard_state)?;
 
-        if let Some((x, dir)) = guard {
-            if let Some(_) = guard_state {
-                return Err(Box::new(ParseError::MultipleGuards));
+        // All rows must be as wide as the first one
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(Box::new(ParseError::row_width(idx, &line, first.len())));
             }
-
-            guard_state = Some((Position::new(x as isize, idx as isize), dir));
         }
 
-        map.push(row);
+        rows.push(row);
     }
 
-    let guard_state = match guard_state {
-        Some(s) => s,
-        None => return Err(Box::new(ParseError::NoGuard)),
-    };
+    let guard_state = guard_state.ok_or(ParseError::end_of_input(rows.len(), "a guard"))?;
+    let map = Matrix2D::from_rows(rows).ok_or("malformed map")?;
 
     Ok((map, guard_state))
 }
 
-pub fn labmap_get(labmap: &LabMap, pos: Position) -> Option<LabMapCell> {
-    if pos.y < 0
-        || pos.y >= labmap.len() as isize
-        || pos.x < 0
-        || pos.x >= labmap[pos.y as usize].len() as isize
-    {
-        None
-    } else {
-        Some(labmap[pos.y as usize][pos.x as usize])
-    }
-}
-
-pub fn print_board(labmap: &LabMap, guard_state: GuardState) {
+/// Render the lab with the guard in it, for debugging
+pub fn render_board(labmap: &LabMap, guard_state: GuardState) -> String {
     let (pos, dir) = guard_state;
 
-    println!("----");
+    let mut board = labmap.map(|cell| cell.display_char());
+    board.set(pos, dir.display_char());
 
-    for (y, row) in labmap.iter().enumerate() {
-        for (x, cell) in row.iter().enumerate() {
-            let c = if x as isize == pos.x && y as isize == pos.y {
-                match dir {
-                    Direction::Left => '<',
-                    Direction::Right => '>',
-                    Direction::Up => '^',
-                    Direction::Down => 'v',
-                }
-            } else {
-                match cell {
-                    LabMapCell::Empty => '.',
-                    LabMapCell::Obstacle => '#',
-                }
-            };
-
-            print!("{c}");
-        }
-        println!();
-    }
+    board.render()
 }
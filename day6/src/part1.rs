@@ -0,0 +1,112 @@
+use std::error::Error;
+
+use crate::{render_board, GuardState, LabMap, LabMapCell};
+use common::diag;
+use grid::{Colour, DisplayChar, GridImage, Matrix2D, Position, Simulation};
+
+pub fn solve((lab_map, guard_state): &(LabMap, GuardState)) -> Result<usize, Box<dyn Error>> {
+    let count =
+        count_visited(lab_map, *guard_state).ok_or("guard is trapped or walks in a loop")?;
+
+    Ok(count)
+}
+
+fn count_visited(lab_map: &LabMap, mut guard_state: GuardState) -> Option<usize> {
+    let mut visited = Matrix2D::new(lab_map.width(), lab_map.height(), false);
+    // A guard leaving the lab is never twice on a cell in the same direction
+    let mut steps = 4 * lab_map.width() * lab_map.height();
+
+    while {
+        steps = steps.checked_sub(1)?;
+        let (pos, _) = guard_state;
+        visited.set(pos, true);
+        diag::frame("guard", || render_board(lab_map, guard_state));
+        guard_state = solution_step(lab_map, guard_state)?;
+        let (pos, _) = guard_state;
+
+        lab_map.contains(pos)
+    } {}
+
+    diag::image("visited", || {
+        let visited = visited.iter().filter(|(_, visited)| **visited);
+        GridImage::from_chars(lab_map)
+            .cells(visited.map(|(pos, _)| pos), Colour::BLUE.with_alpha(128))
+    });
+
+    Some(visited.iter().filter(|(_, visited)| **visited).count())
+}
+
+/// Guard walking out of the lab, leaving a trail of visited cells
+pub struct GuardWalk {
+    lab_map: LabMap,
+    guard_state: GuardState,
+    visited: Matrix2D<bool>,
+}
+
+impl GuardWalk {
+    pub fn new((lab_map, guard_state): (LabMap, GuardState)) -> Self {
+        let visited = Matrix2D::new(lab_map.width(), lab_map.height(), false);
+        Self {
+            lab_map,
+            guard_state,
+            visited,
+        }
+    }
+}
+
+impl Simulation for GuardWalk {
+    fn grid(&self) -> Matrix2D<char> {
+        let mut grid = self.lab_map.map(|cell| cell.display_char());
+        for (pos, _) in self.visited.iter().filter(|(_, visited)| **visited) {
+            grid.set(pos, 'X');
+        }
+
+        let (pos, dir) = self.guard_state;
+        grid.set(pos, dir.display_char());
+        grid
+    }
+
+    fn actor(&self) -> Option<Position> {
+        let (pos, _) = self.guard_state;
+        self.lab_map.contains(pos).then_some(pos)
+    }
+
+    fn step(&mut self) -> Option<String> {
+        let (pos, dir) = self.guard_state;
+        if !self.lab_map.contains(pos) {
+            return None;
+        }
+
+        self.visited.set(pos, true);
+        self.guard_state = solution_step(&self.lab_map, self.guard_state)?;
+        let (pos, new_dir) = self.guard_state;
+
+        let turn = if new_dir != dir {
+            format!("turn {}, ", new_dir.display_char())
+        } else {
+            String::new()
+        };
+        if self.lab_map.contains(pos) {
+            Some(format!("{}step to {}", turn, pos))
+        } else {
+            Some(format!("{}leave the lab", turn))
+        }
+    }
+}
+
+/// Move guard one step, `None` if it's surrounded by obstacles
+fn solution_step(lab_map: &LabMap, guard_state: GuardState) -> Option<GuardState> {
+    let (pos, mut dir) = guard_state;
+    for _ in 0..4 {
+        let newpos = pos.moved(dir);
+
+        if let Some(LabMapCell::Obstacle) = lab_map.get(newpos) {
+            dir = dir.turn_right();
+            continue;
+        }
+
+        return Some((newpos, dir));
+    }
+
+    None
+}
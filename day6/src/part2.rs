@@ -0,0 +1,67 @@
+use std::error::Error;
+
+use crate::{GuardState, LabMap, LabMapCell};
+use common::parallel;
+use grid::{Direction, Matrix2D, Position};
+
+pub fn solve((lab_map, guard_state): &(LabMap, GuardState)) -> Result<usize, Box<dyn Error>> {
+    // Every empty cell but the guard's can get the new obstacle
+    let candidates: Vec<Position> = lab_map
+        .iter()
+        .filter(|(pos, cell)| **cell == LabMapCell::Empty && *pos != guard_state.0)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let loops = parallel::map(&candidates, |pos| {
+        let mut new = lab_map.clone();
+        new[*pos] = LabMapCell::Obstacle;
+
+        is_infinite_loop(&new, *guard_state)
+    });
+
+    Ok(loops.into_iter().filter(|is_loop| *is_loop).count())
+}
+
+fn is_infinite_loop(lab_map: &LabMap, mut guard_state: GuardState) -> bool {
+    let mut visits: Matrix2D<Vec<Direction>> =
+        Matrix2D::new(lab_map.width(), lab_map.height(), vec![]);
+
+    while {
+        let (pos, dir) = guard_state;
+
+        if visits[pos].contains(&dir) {
+            return true;
+        }
+
+        visits[pos].push(dir);
+
+        // diag::debug(|| render_board(lab_map, guard_state));
+        // A trapped guard never leaves
+        guard_state = match solution_step(lab_map, guard_state) {
+            Some(guard_state) => guard_state,
+            None => return true,
+        };
+        let (pos, _) = guard_state;
+
+        lab_map.contains(pos)
+    } {}
+
+    false
+}
+
+/// Move guard one step, `None` if it's surrounded by obstacles
+fn solution_step(lab_map: &LabMap, guard_state: GuardState) -> Option<GuardState> {
+    let (pos, mut dir) = guard_state;
+    for _ in 0..4 {
+        let newpos = pos.moved(dir);
+
+        if let Some(LabMapCell::Obstacle) = lab_map.get(newpos) {
+            dir = dir.turn_right();
+            continue;
+        }
+
+        return Some((newpos, dir));
+    }
+
+    None
+}
@@ -0,0 +1,47 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+// Targets stay well within a u64 when concatenated
+const MAX_TARGET: u64 = 10u64.pow(15);
+
+/// Random input from `seed`, `equations` equations (default: 850) of up to
+/// `operands` numbers (default: 12)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["equations", "operands"])?;
+    let equations: usize = params.get("equations", 850)?;
+    let operands: i64 = params.get("operands", 12)?;
+    if operands < 2 {
+        return Err("equations need at least 2 operands".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    let mut input = String::new();
+    for _ in 0..equations {
+        let nums: Vec<u64> = (0..rng.range(2..=operands))
+            .map(|_| rng.range(1..=999) as u64)
+            .collect();
+
+        // Combine the numbers with random operators, adding when it gets too big
+        let mut target = nums[0];
+        for num in &nums[1..] {
+            let concat = format!("{}{}", target, num).parse::<u64>().ok();
+            let res = match rng.below(3) {
+                0 => target.checked_mul(*num),
+                1 => concat,
+                _ => None,
+            };
+            target = res.filter(|res| *res < MAX_TARGET).unwrap_or(target + num);
+        }
+
+        // Half of the equations are most likely impossible
+        if rng.ratio(1, 2) {
+            target += rng.range(1..=9) as u64;
+        }
+
+        let nums: Vec<String> = nums.iter().map(|num| num.to_string()).collect();
+        input.push_str(&format!("{}: {}\n", target, nums.join(" ")));
+    }
+
+    Ok(input)
+}
@@ -1,109 +1,82 @@
-use core::fmt;
-use std::{
-    error::Error,
-    fmt::{Display, Formatter},
-    io::BufRead,
+use std::{error::Error, io::BufRead};
+
+use common::{
+    parser::{parse_line, separated_list, tag, unsigned, whitespace, PResult},
+    Solution,
 };
 
-// Pare result type
-type PResult<'a, T> = Result<(T, &'a str), PError>;
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
+
+#[derive(Debug, Default)]
+pub struct Day7;
 
-// Parse error
-#[derive(PartialEq, Debug, Clone)]
-struct PError {}
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+    type Output1 = u64;
+    type Output2 = u64;
 
-impl PError {
-    pub fn new() -> Self {
-        Self {}
+    fn parse(&self, input: impl BufRead) -> Result<Vec<Equation>, Box<dyn Error>> {
+        parse_input(input)
     }
-}
 
-impl std::error::Error for PError {}
+    fn part1(&self, input: &Vec<Equation>) -> Result<u64, Box<dyn Error>> {
+        part1::solve(input)
+    }
 
-impl Display for PError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Parse error!")
+    fn part2(&self, input: &Vec<Equation>) -> Result<u64, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
-pub fn parse_input(reader: impl BufRead) -> Result<Vec<(u64, Vec<u64>)>, Box<dyn Error>> {
+// Target value and factors
+pub type Equation = (u64, Vec<u64>);
+
+pub fn parse_input(reader: impl BufRead) -> Result<Vec<Equation>, Box<dyn Error>> {
     reader
         .lines()
-        .map(|line| {
-            let line = line?;
-            let (res, _) = parse_input_line(&line)?;
-            Ok(res)
-        })
+        .enumerate()
+        .map(|(idx, line)| Ok(parse_line(idx, &line?, parse_input_line)?))
         .collect()
 }
 
-fn parse_input_line(input: &str) -> PResult<(u64, Vec<u64>)> {
-    let (target, input) = parse_u64(input)?;
-    let (_, input) = parse_tag(":")(input)?;
-    let (factors, input) = parse_many0(parse_factor)(input)?;
+fn parse_input_line(input: &str) -> PResult<'_, Equation> {
+    let (target, input) = unsigned(input)?;
+    let (_, input) = tag(":")(input)?;
+    let (_, input) = whitespace(input)?;
+    let (factors, input) = separated_list(unsigned, tag(" "))(input)?;
 
     Ok(((target, factors), input))
 }
 
-fn parse_factor(input: &str) -> PResult<u64> {
-    let (_, input) = parse_tag(" ")(input)?;
-    let (n, input) = parse_u64(input)?;
-    Ok((n, input))
-}
-
-// Parse a string of text
-fn parse_tag<'a>(tag: &'a str) -> impl Fn(&str) -> PResult<()> + 'a {
-    let tag = tag.to_owned();
-
-    move |input| {
-        // Check size
-        if input.len() < tag.len() {
-            return Err(PError::new());
-        }
-
-        if input[0..tag.len()] == tag {
-            return Ok(((), &input[tag.len()..]));
-        } else {
-            Err(PError::new())
-        }
-    }
-}
-
-// Parse a number with maximum number of digits
-fn parse_u64(input: &str) -> PResult<u64> {
-    // Find end of digits
-    let mut end: usize = 0;
-    for c in input.chars() {
-        if !c.is_ascii_digit() {
-            break;
-        }
-        end += 1;
-    }
-
-    // Construct number slice
-    let num = &input[0..end];
-
-    // Parse number
-    let num: u64 = match num.parse() {
-        Ok(res) => res,
-        Err(_) => return Err(PError::new()),
-    };
-
-    Ok((num, &input[end..]))
-}
-
-fn parse_many0<'a, P, T>(parser: P) -> impl Fn(&'a str) -> PResult<'a, Vec<T>>
-where
-    P: Fn(&str) -> PResult<T>,
-{
-    move |mut input| {
-        let mut res = Vec::new();
-
-        while let Ok((val, rem)) = parser(input) {
-            input = rem;
-            res.push(val);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_test() {
+        // Parsed values can be anything up to `u64::MAX`
+        let tests = [
+            (
+                "18446744073709551615: 18446744073709551615 18446744073709551615\n",
+                Some(0),
+                Some(0),
+            ),
+            (
+                "18446744073709551615: 18446744073709551615\n1: 1\n",
+                None,
+                None,
+            ),
+            ("0: 0 0\n10: 1 0\n", Some(0), Some(10)),
+        ];
+
+        for (input, exp1, exp2) in tests {
+            let equations = Day7.parse(input.as_bytes()).unwrap();
+            assert_eq!(Day7.part1(&equations).ok(), exp1);
+            assert_eq!(Day7.part2(&equations).ok(), exp2);
         }
-
-        Ok((res, input))
     }
 }
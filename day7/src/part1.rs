@@ -0,0 +1,53 @@
+use std::error::Error;
+
+use crate::Equation;
+use common::parallel;
+
+pub fn solve(equations: &[Equation]) -> Result<u64, Box<dyn Error>> {
+    let values = parallel::map(equations, |(target, factors)| {
+        let factors: Vec<u64> = factors.iter().rev().copied().collect();
+        if is_solvable(*target, &factors) {
+            *target
+        } else {
+            0
+        }
+    });
+
+    let total = values
+        .into_iter()
+        .try_fold(0, u64::checked_add)
+        .ok_or("total calibration result overflows")?;
+
+    Ok(total)
+}
+
+fn is_solvable(target: u64, factors: &[u64]) -> bool {
+    // Empty target is an error
+    if factors.is_empty() {
+        return false;
+    }
+
+    // Done!
+    if factors.len() == 1 {
+        return target == factors[0];
+    }
+
+    let next = factors[0];
+    let factors = &factors[1..];
+
+    // Addition
+    if target >= next && is_solvable(target - next, factors) {
+        return true;
+    }
+
+    // Multiplication, anything times 0 is 0
+    if next == 0 {
+        if target == 0 {
+            return true;
+        }
+    } else if target.is_multiple_of(next) && is_solvable(target / next, factors) {
+        return true;
+    }
+
+    false
+}
@@ -0,0 +1,97 @@
+use std::error::Error;
+
+use crate::Equation;
+use common::parallel;
+
+pub fn solve(equations: &[Equation]) -> Result<u64, Box<dyn Error>> {
+    let values = parallel::map(equations, |(target, factors)| {
+        let factors: Vec<u64> = factors.iter().rev().copied().collect();
+        if is_solvable(*target, &factors) {
+            *target
+        } else {
+            0
+        }
+    });
+
+    let total = values
+        .into_iter()
+        .try_fold(0, u64::checked_add)
+        .ok_or("total calibration result overflows")?;
+
+    Ok(total)
+}
+
+fn is_solvable(target: u64, factors: &[u64]) -> bool {
+    // Empty target is an error
+    if factors.is_empty() {
+        return false;
+    }
+
+    // Done!
+    if factors.len() == 1 {
+        return target == factors[0];
+    }
+
+    let next = factors[0];
+    let factors = &factors[1..];
+
+    // Addition
+    if target >= next && is_solvable(target - next, factors) {
+        return true;
+    }
+
+    // Multiplication, anything times 0 is 0
+    if next == 0 {
+        if target == 0 {
+            return true;
+        }
+    } else if target.is_multiple_of(next) && is_solvable(target / next, factors) {
+        return true;
+    }
+
+    // Concatenation
+    if let Ok(target) = unconcatenate(target, next) {
+        if is_solvable(target, factors) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn unconcatenate(cat: u64, b: u64) -> Result<u64, ()> {
+    // 10 to the number of digits of `b`, which overflows `u64` for 20 digits
+    let digits = b.checked_ilog10().map_or(1, |exp| exp + 1);
+    let pow = 10_u128.pow(digits);
+
+    if u128::from(cat) % pow == u128::from(b) {
+        Ok((u128::from(cat) / pow) as u64)
+    } else {
+        Err(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unconcatenate;
+
+    #[test]
+    fn test_unconcatenate() {
+        let tests = [
+            ((1020, 20), Ok(10)),
+            ((156, 6), Ok(15)),
+            ((12349, 9), Ok(1234)),
+            ((12341000, 1000), Ok(1234)),
+            ((123456, 999), Err(())),
+            ((10, 0), Ok(1)),
+            ((100000000000000000, 100000000000000000), Ok(0)),
+            ((u64::MAX, u64::MAX), Ok(0)),
+            ((u64::MAX, 5), Ok(1844674407370955161)),
+        ];
+
+        for ((cat, b), exp) in tests {
+            let res = unconcatenate(cat, b);
+            assert_eq!(res, exp);
+        }
+    }
+}
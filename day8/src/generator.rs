@@ -0,0 +1,40 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+// Frequencies, in the order they're used
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Random input from `seed`, a `width` x `height` map (default: 50 x 50) with
+/// `antennas` antennas (default: 4) of each of `frequencies` frequencies
+/// (default: 36, at most 62)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["width", "height", "frequencies", "antennas"])?;
+    let width: usize = params.get("width", 50)?;
+    let height: usize = params.get("height", 50)?;
+    let frequencies: usize = params.get("frequencies", 36)?;
+    let antennas: usize = params.get("antennas", 4)?;
+    if frequencies > FREQUENCIES.len() {
+        return Err(format!("there are at most {} frequencies", FREQUENCIES.len()).into());
+    }
+    if frequencies * antennas > width * height {
+        return Err("too many antennas for the map".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    // Antennas go to distinct random cells
+    let mut cells = vec!['.'; width * height];
+    let mut idx: Vec<usize> = (0..cells.len()).collect();
+    rng.shuffle(&mut idx);
+    for (n, idx) in idx.iter().take(frequencies * antennas).enumerate() {
+        cells[*idx] = FREQUENCIES[n / antennas] as char;
+    }
+
+    let mut input = String::new();
+    for row in cells.chunks(width) {
+        input.extend(row);
+        input.push('\n');
+    }
+
+    Ok(input)
+}
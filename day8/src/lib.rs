@@ -1,26 +1,32 @@
-use core::fmt;
-use std::{
-    error::Error,
-    fmt::{Display, Formatter},
-    io::BufRead,
-};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Position {
-    pub x: isize,
-    pub y: isize,
-}
+use std::{error::Error, io::BufRead};
+
+use common::{ParseError, Solution};
+use grid::Position;
+
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
+
+#[derive(Debug, Default)]
+pub struct Day8;
 
-impl Position {
-    pub fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
+impl Solution for Day8 {
+    type Input = ParseResult;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: impl BufRead) -> Result<ParseResult, Box<dyn Error>> {
+        parse_input(input)
     }
 
-    pub fn new_usize(x: usize, y: usize) -> Self {
-        Self {
-            x: x as isize,
-            y: y as isize,
-        }
+    fn part1(&self, input: &ParseResult) -> Result<usize, Box<dyn Error>> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &ParseResult) -> Result<usize, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
@@ -43,28 +49,6 @@ pub struct ParseResult {
     pub antennas: Vec<Antenna>,
 }
 
-/// Parse result type
-#[derive(Debug)]
-pub struct ParseError {
-    msg: String,
-}
-
-impl ParseError {
-    pub fn new(msg: &str) -> Self {
-        Self {
-            msg: msg.to_owned(),
-        }
-    }
-}
-
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "parse error: {}", self.msg)
-    }
-}
-
-impl Error for ParseError {}
-
 /// Parse input from bufreader
 pub fn parse_input(input: impl BufRead) -> Result<ParseResult, Box<dyn Error>> {
     let mut board_width = 0;
@@ -80,10 +64,11 @@ pub fn parse_input(input: impl BufRead) -> Result<ParseResult, Box<dyn Error>> {
         }
 
         // Update board width
+        let width = line.chars().count();
         if board_width == 0 {
-            board_width = line.len();
-        } else if line.len() != board_width {
-            return Err(Box::new(ParseError::new("malformed board")));
+            board_width = width;
+        } else if width != board_width {
+            return Err(Box::new(ParseError::row_width(y, &line, board_width)));
         }
 
         // Find antennas
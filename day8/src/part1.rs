@@ -1,26 +1,19 @@
 use std::{
     collections::{HashMap, HashSet},
-    io::{stdin, BufReader},
-    process,
+    error::Error,
 };
 
-use day8::{parse_input, Antenna, Pairs, ParseResult, Position};
-
-fn main() {
-    // Parse input
-    let input = parse_input(BufReader::new(stdin())).unwrap_or_else(|e| {
-        eprintln!("Error reading input: {}", e);
-        process::exit(1);
-    });
+use crate::{Antenna, Pairs, ParseResult};
+use grid::Position;
 
+pub fn solve(input: &ParseResult) -> Result<usize, Box<dyn Error>> {
     // Compute solution
     let result = compute_solution(input);
 
-    // Print result
-    println!("Result: {}", result);
+    Ok(result)
 }
 
-fn compute_solution(input: ParseResult) -> usize {
+fn compute_solution(input: &ParseResult) -> usize {
     // Divide antennas into groups of frequencies
     let buckets = separate_antennas_by_frequency(&input.antennas);
 
@@ -67,20 +60,18 @@ fn separate_antennas_by_frequency(antennas: &[Antenna]) -> HashMap<char, Vec<Pos
 fn find_antinodes(a: Position, b: Position) -> Vec<Position> {
     let mut antinodes = Vec::new();
 
-    // Compute distance deltas
-    let delta_x = b.x - a.x;
-    let delta_y = b.y - a.y;
+    // Compute distance delta
+    let delta = b - a;
 
     // Add external antinodes
-    antinodes.push(Position::new(a.x - delta_x, a.y - delta_y));
-    antinodes.push(Position::new(b.x + delta_x, b.y + delta_y));
+    antinodes.push(a - delta);
+    antinodes.push(b + delta);
 
     // Add internal antinodes
-    if delta_x % 3 == 0 && delta_y % 3 == 0 {
-        let delta_x = delta_x / 3;
-        let delta_y = delta_y / 3;
-        antinodes.push(Position::new(a.x + delta_x, a.y + delta_y));
-        antinodes.push(Position::new(b.x - delta_x, b.y - delta_y));
+    if delta.x % 3 == 0 && delta.y % 3 == 0 {
+        let delta = Position::new(delta.x / 3, delta.y / 3);
+        antinodes.push(a + delta);
+        antinodes.push(b - delta);
     }
 
     antinodes
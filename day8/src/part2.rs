@@ -1,26 +1,19 @@
 use std::{
     collections::{HashMap, HashSet},
-    io::{stdin, BufReader},
-    process,
+    error::Error,
 };
 
-use day8::{gcd, parse_input, Antenna, Pairs, ParseResult, Position};
-
-fn main() {
-    // Parse input
-    let input = parse_input(BufReader::new(stdin())).unwrap_or_else(|e| {
-        eprintln!("Error reading input: {}", e);
-        process::exit(1);
-    });
+use crate::{gcd, Antenna, Pairs, ParseResult};
+use grid::Position;
 
+pub fn solve(input: &ParseResult) -> Result<usize, Box<dyn Error>> {
     // Compute solution
     let result = compute_solution(input);
 
-    // Print result
-    println!("Result: {}", result);
+    Ok(result)
 }
 
-fn compute_solution(input: ParseResult) -> usize {
+fn compute_solution(input: &ParseResult) -> usize {
     // Divide antennas into groups of frequencies
     let buckets = separate_antennas_by_frequency(&input.antennas);
 
@@ -64,44 +57,25 @@ fn find_antinodes(
 ) -> Vec<Position> {
     let mut antinodes = Vec::new();
 
-    // Compute distance deltas
-    let dist_x = b.x - a.x;
-    let dist_y = b.y - a.y;
+    // Compute distance
+    let dist = b - a;
 
     // Compute CCD
-    let gcd = gcd(dist_x, dist_y);
+    let gcd = gcd(dist.x, dist.y);
+
+    // Find delta
+    let delta = Position::new(dist.x / gcd, dist.y / gcd);
 
-    // Find deltas
-    let delta_x = dist_x / gcd;
-    let delta_y = dist_y / gcd;
+    // Check if position is in bounds
+    let in_bounds = |pos: &Position| {
+        pos.x >= 0 && pos.x < board_width as isize && pos.y >= 0 && pos.y < board_height as isize
+    };
 
     // Forward direction
-    antinodes.extend((0..).map_while(|i| {
-        // Compute new position
-        let x = a.x + delta_x * i;
-        let y = a.y + delta_y * i;
-
-        // Check if position is in bounds
-        if x >= 0 && x < board_width as isize && y >= 0 && y < board_height as isize {
-            Some(Position::new(x, y))
-        } else {
-            None
-        }
-    }));
+    antinodes.extend((0..).map(|i| a + delta * i).take_while(in_bounds));
 
     // Backward direction
-    antinodes.extend((1..).map_while(|i| {
-        // Compute new position
-        let x = a.x - delta_x * i;
-        let y = a.y - delta_y * i;
-
-        // Check if position is in bounds
-        if x >= 0 && x < board_width as isize && y >= 0 && y < board_height as isize {
-            Some(Position::new(x, y))
-        } else {
-            None
-        }
-    }));
+    antinodes.extend((1..).map(|i| a - delta * i).take_while(in_bounds));
 
     antinodes
 }
@@ -0,0 +1,25 @@
+use std::error::Error;
+
+use common::{rng::Rng, Params};
+
+/// Random input from `seed`, a disk map of `length` digits (default: 19999)
+pub fn generate(seed: u64, params: &Params) -> Result<String, Box<dyn Error>> {
+    params.check_known(&["length"])?;
+    let length: usize = params.get("length", 19999)?;
+    if length == 0 {
+        return Err("the disk map must have at least 1 digit".into());
+    }
+    let mut rng = Rng::new(seed);
+
+    // Files alternate with free space, only the latter can be empty
+    let mut input: String = (0..length)
+        .map(|idx| match idx % 2 {
+            0 => rng.range(1..=9),
+            _ => rng.range(0..=9),
+        })
+        .map(|len| char::from(b'0' + len as u8))
+        .collect();
+    input.push('\n');
+
+    Ok(input)
+}
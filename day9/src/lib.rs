@@ -1,64 +1,81 @@
-use core::fmt;
-use std::{
-    cmp::Ordering,
-    error::Error,
-    fmt::{Display, Formatter},
-    io::{self, ErrorKind, Read},
-};
-
-pub trait IntoAsciiChars<T: Read> {
-    fn ascii_chars(&mut self) -> AsciiChars<T>;
-}
+use std::{cmp::Ordering, error::Error, io::BufRead};
 
-pub struct AsciiChars<'a, T: Read> {
-    inner: &'a mut T,
-}
+use common::{input::read_text, ParseError, Solution};
+
+mod generator;
+mod part1;
+mod part2;
+
+pub use generator::generate;
+
+#[derive(Debug, Default)]
+pub struct Day9;
 
-impl<'a, T: Read> AsciiChars<'a, T> {
-    pub fn new(inner: &'a mut T) -> Self {
-        Self { inner }
+impl Solution for Day9 {
+    type Input = DiskMap;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: impl BufRead) -> Result<DiskMap, Box<dyn Error>> {
+        parse_input(input)
     }
-}
 
-impl<'a, T: Read> Iterator for AsciiChars<'a, T> {
-    type Item = Result<char, io::Error>;
-    fn next(&mut self) -> Option<Self::Item> {
-        let mut byte = [0];
-        match self.inner.read_exact(&mut byte) {
-            Ok(_) => Some(Ok(byte[0] as char)),
-            Err(e) => {
-                if let ErrorKind::UnexpectedEof = e.kind() {
-                    None
-                } else {
-                    Some(Err(e))
-                }
-            }
-        }
+    fn part1(&self, input: &DiskMap) -> Result<u64, Box<dyn Error>> {
+        part1::solve(input)
     }
-}
 
-impl<T: Read> IntoAsciiChars<T> for T {
-    fn ascii_chars(&mut self) -> AsciiChars<T> {
-        AsciiChars::new(self)
+    fn part2(&self, input: &DiskMap) -> Result<u64, Box<dyn Error>> {
+        part2::solve(input)
     }
 }
 
-#[derive(Debug)]
-pub struct ParseError {}
+// Array length, files and empty spaces
+pub type DiskMap = (usize, Vec<Span>, Vec<Span>);
+
+pub fn parse_input(input: impl BufRead) -> Result<DiskMap, Box<dyn Error>> {
+    let text = read_text(input)?;
+    let mut pos = 0;
+    let mut files: Vec<Span> = Vec::new();
+    let mut empty_spaces: Vec<Span> = Vec::new();
+
+    // The disk map ends with the line
+    let line = text.split(['\n', '\r']).next().unwrap_or_default();
+
+    for (idx, (offset, c)) in line.char_indices().enumerate() {
+        let len = c
+            .to_digit(10)
+            .ok_or_else(|| ParseError::new(0, line, offset, c.len_utf8(), "digit"))?
+            as usize;
+
+        if idx % 2 == 0 {
+            // File
+            files.push(Span::new(pos, len));
+        } else {
+            empty_spaces.push(Span::new(pos, len));
+        }
 
-impl ParseError {
-    pub fn new() -> Self {
-        Self {}
+        pos += len;
     }
-}
 
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "parse error")
+    if line.is_empty() {
+        return Err(ParseError::end_of_input(0, "disk map").into());
     }
-}
 
-impl Error for ParseError {}
+    // Compute array size
+    let arr_size = if let Some(span) = files.last() {
+        span.pos + span.len
+    } else {
+        0
+    };
+
+    // Solvers need a block to move
+    if files.iter().all(|file| file.len == 0) {
+        let err = ParseError::new(0, line, 0, line.len(), "disk map with a file block");
+        return Err(err.into());
+    }
+
+    Ok((arr_size, files, empty_spaces))
+}
 
 pub fn build_array(len: usize, files: &[Span]) -> Vec<Option<usize>> {
     let mut res = vec![None; len];
@@ -72,11 +89,12 @@ pub fn build_array(len: usize, files: &[Span]) -> Vec<Option<usize>> {
     res
 }
 
-pub fn compute_checksum(arr: &[Option<usize>]) -> usize {
+// u64 so that checksums of real inputs fit on 32 bit targets too
+pub fn compute_checksum(arr: &[Option<usize>]) -> u64 {
     arr.iter()
         .enumerate()
         .map(|(idx, el)| match el {
-            Some(block_id) => idx * block_id,
+            Some(block_id) => idx as u64 * *block_id as u64,
             None => 0,
         })
         .sum()
@@ -104,7 +122,7 @@ impl Eq for Span {}
 
 impl PartialOrd for Span {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.pos.cmp(&other.pos))
+        Some(self.cmp(other))
     }
 }
 
@@ -113,3 +131,36 @@ impl Ord for Span {
         self.pos.cmp(&other.pos)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_input_err() {
+        let tests = [
+            (
+                "",
+                "line 1, column 1: expected disk map, found end of input",
+            ),
+            (
+                "\n",
+                "line 1, column 1: expected disk map, found end of input",
+            ),
+            (
+                "0",
+                "line 1, column 1: expected disk map with a file block, found `0`",
+            ),
+            (
+                "0203",
+                "line 1, column 1: expected disk map with a file block, found `0203`",
+            ),
+            ("12x4", "line 1, column 3: expected digit, found `x`"),
+        ];
+
+        for (input, exp) in tests {
+            let err = parse_input(input.as_bytes()).unwrap_err().to_string();
+            assert_eq!(err.lines().next(), Some(exp), "{:?}", input);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::error::Error;
+
+use crate::{build_array, compute_checksum, DiskMap};
+
+pub fn solve((arr_len, files, _): &DiskMap) -> Result<u64, Box<dyn Error>> {
+    // Build array from files list
+    let mut arr = build_array(*arr_len, files);
+
+    // Fragmentate
+    fragment(&mut arr);
+
+    // Compute checksum
+    let res = compute_checksum(&arr);
+
+    Ok(res)
+}
+
+fn fragment(arr: &mut [Option<usize>]) {
+    // Indexes
+    let mut pick = arr.len() - 1;
+    let mut place = 0;
+
+    while pick != place {
+        // Find element to pick
+        let mut pick_el = None;
+        if let Some(i) = (0..pick).find(|i| arr[pick - i].is_some()) {
+            pick -= i;
+
+            pick_el = arr[pick];
+            arr[pick] = None;
+        }
+
+        // Stop if no element to pick
+        if pick_el.is_none() {
+            break;
+        }
+
+        // Find spot to place
+        if let Some(i) = arr[place..].iter().position(|el| el.is_none()) {
+            place += i;
+        }
+
+        // Place element in new spot
+        arr[place] = pick_el;
+    }
+}
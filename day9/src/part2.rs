@@ -0,0 +1,116 @@
+use std::{collections::BTreeSet, error::Error};
+
+use crate::{build_array, compute_checksum, DiskMap, Span};
+
+pub fn solve((arr_len, files, empty_spaces): &DiskMap) -> Result<u64, Box<dyn Error>> {
+    // Compact filesystem without fragmentation
+    let files = compact_nofragment(files.clone(), empty_spaces.clone());
+
+    // Build file array
+    let arr = build_array(*arr_len, &files);
+
+    // Compute checksum
+    let res = compute_checksum(&arr);
+
+    Ok(res)
+}
+
+fn compact_nofragment(mut files: Vec<Span>, empty_spaces: Vec<Span>) -> Vec<Span> {
+    let mut empty_spaces = BTreeSet::from_iter(empty_spaces);
+
+    for i in (0..files.len()).rev() {
+        // Find suitable empty space
+        let space = get_empty_space(&mut empty_spaces, files[i]);
+
+        let space = match space {
+            Some(space) => space,
+            None => continue,
+        };
+
+        // Add empty space where the file was
+        empty_spaces.insert(files[i]);
+
+        // Modify file
+        files[i].pos = space.pos;
+
+        if space.len > files[i].len {
+            // Divide space
+            empty_spaces.insert(Span::new(
+                space.pos + files[i].len,
+                space.len - files[i].len,
+            ));
+        }
+    }
+
+    files
+}
+
+fn get_empty_space(spaces: &mut BTreeSet<Span>, file: Span) -> Option<Span> {
+    let space = spaces
+        .iter()
+        .filter(|space| space.len >= file.len && space.pos < file.pos)
+        .copied()
+        .next()?;
+
+    spaces.remove(&space);
+
+    Some(space)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+    use common::{prop, rng::Rng};
+
+    // Move whole files block by block to the leftmost free run they fit in
+    fn naive_checksum(disk_map: &[u8]) -> u64 {
+        let mut blocks = Vec::new();
+        for (idx, len) in disk_map.iter().enumerate() {
+            let id = idx.is_multiple_of(2).then_some(idx / 2);
+            blocks.extend((0..*len).map(|_| id));
+        }
+
+        for id in (0..disk_map.len().div_ceil(2)).rev() {
+            let Some(start) = blocks.iter().position(|b| *b == Some(id)) else {
+                continue;
+            };
+            let len = disk_map[id * 2] as usize;
+
+            let free = (0..start).find(|pos| blocks[*pos..*pos + len].iter().all(Option::is_none));
+            if let Some(free) = free {
+                blocks[free..free + len].fill(Some(id));
+                blocks[start..start + len].fill(None);
+            }
+        }
+
+        compute_checksum(&blocks)
+    }
+
+    #[test]
+    fn compact_matches_naive() {
+        // Lengths of the files and the spaces after them
+        let gen = |rng: &mut Rng| {
+            (0..rng.range(1..=19))
+                .map(|idx| match idx % 2 {
+                    0 => rng.range(1..=9) as u8,
+                    _ => rng.range(0..=9) as u8,
+                })
+                .collect::<Vec<u8>>()
+        };
+
+        prop::check(500, gen, |disk_map| {
+            // Files in puzzle inputs are never empty, and there's at least one
+            if disk_map.is_empty()
+                || disk_map.iter().step_by(2).any(|len| !(1..=9).contains(len))
+                || disk_map.iter().any(|len| *len > 9)
+            {
+                return Ok(());
+            }
+
+            let digits: String = disk_map.iter().map(|len| (b'0' + len) as char).collect();
+            let res = solve(&parse_input(digits.as_bytes()).unwrap()).unwrap();
+            prop::same(res, naive_checksum(disk_map))
+        });
+    }
+}
@@ -0,0 +1,13 @@
+#![no_main]
+
+use common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Both parts must handle every input the parser accepts
+    let solution = day11::Day11::default();
+    if let Ok(input) = solution.parse(data) {
+        let _ = solution.part1(&input);
+        let _ = solution.part2(&input);
+    }
+});
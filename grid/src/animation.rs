@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use crate::{gif, Colour};
+
+// Hundredths of a second per frame
+const DEFAULT_DELAY: u16 = 5;
+
+/// Animation of text frames, such as recorded by `diag::frame`, where every
+/// character is a square cell
+#[derive(Debug, Clone)]
+pub struct Animation {
+    frames: Vec<String>,
+    cell_size: usize,
+    delay: u16,
+}
+
+impl Animation {
+    pub fn new(frames: Vec<String>) -> Self {
+        Self {
+            frames,
+            cell_size: 4,
+            delay: DEFAULT_DELAY,
+        }
+    }
+
+    /// Side of a cell in pixels
+    pub fn cell_size(mut self, cell_size: usize) -> Self {
+        self.cell_size = cell_size.max(1);
+        self
+    }
+
+    /// Time every frame is shown, in hundredths of a second
+    pub fn delay(mut self, delay: u16) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Size in cells of the largest frame
+    pub fn size(&self) -> (usize, usize) {
+        let width = self.frames.iter().flat_map(|frame| frame.lines());
+        let width = width.map(|line| line.chars().count()).max().unwrap_or(0);
+        let height = self.frames.iter().map(|frame| frame.lines().count());
+        (width.max(1), height.max().unwrap_or(0).max(1))
+    }
+
+    /// Looping GIF, cells are coloured with `Colour::for_char` and missing ones
+    /// are left as floor
+    pub fn to_gif(&self) -> Vec<u8> {
+        let (cols, rows) = self.size();
+        // GIF dimensions are 16-bit
+        let cell_size = self
+            .cell_size
+            .min(u16::MAX as usize / cols.max(rows))
+            .max(1);
+        let (width, height) = (cols * cell_size, rows * cell_size);
+
+        let mut palette = vec![Colour::FLOOR];
+        let mut indices = HashMap::new();
+        let mut frames = Vec::with_capacity(self.frames.len());
+
+        for frame in &self.frames {
+            let mut pixels = vec![0; width * height];
+            for (y, line) in frame.lines().take(rows).enumerate() {
+                for (x, c) in line.chars().take(cols).enumerate() {
+                    let colour = Colour::for_char(c);
+                    let idx = *indices.entry(colour).or_insert_with(|| {
+                        // Colours past the table size share its last entry
+                        if palette.len() < 256 {
+                            palette.push(colour);
+                        }
+                        palette.len() as u8 - 1
+                    });
+
+                    for row in y * cell_size..(y + 1) * cell_size {
+                        pixels[row * width + x * cell_size..][..cell_size].fill(idx);
+                    }
+                }
+            }
+            frames.push(pixels);
+        }
+
+        let palette: Vec<[u8; 3]> = palette.iter().map(|c| [c.r, c.g, c.b]).collect();
+        gif::encode(width as u16, height as u16, &palette, &frames, self.delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_gif_test() {
+        let animation = Animation::new(vec!["#.\n".to_owned(), "#^\n..\n".to_owned()]).cell_size(2);
+        assert_eq!(animation.size(), (2, 2));
+
+        let gif = animation.to_gif();
+        assert_eq!(&gif[6..10], &[4, 0, 4, 0]);
+
+        // Floor comes first, then colours in order of appearance
+        let floor = Colour::FLOOR;
+        assert_eq!(&gif[13..19], &[floor.r, floor.g, floor.b, 60, 60, 70]);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::DisplayChar;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// All directions, in the 4-neighbourhood order
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// Parse a direction from an arrow character (`^`, `v`, `<`, `>`)
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Arrow character pointing in this direction
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+
+    pub fn get_deltas(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            Direction::Up => Self::Down,
+            Direction::Down => Self::Up,
+            Direction::Left => Self::Right,
+            Direction::Right => Self::Left,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::Up => Self::Left,
+            Direction::Down => Self::Right,
+            Direction::Left => Self::Down,
+            Direction::Right => Self::Up,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Self::Right,
+            Direction::Down => Self::Left,
+            Direction::Left => Self::Up,
+            Direction::Right => Self::Down,
+        }
+    }
+
+    pub fn is_orthogonal(&self, other: &Self) -> bool {
+        match self {
+            Direction::Up | Direction::Down => other == &Self::Left || other == &Self::Right,
+            Direction::Left | Direction::Right => other == &Self::Up || other == &Self::Down,
+        }
+    }
+}
+
+impl DisplayChar for Direction {
+    fn display_char(&self) -> char {
+        self.arrow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_arrow_test() {
+        let tests = [
+            ('^', Some(Direction::Up)),
+            ('v', Some(Direction::Down)),
+            ('<', Some(Direction::Left)),
+            ('>', Some(Direction::Right)),
+            ('.', None),
+        ];
+
+        for (input, exp) in tests {
+            let res = Direction::from_arrow(input);
+            assert_eq!(res, exp);
+            if let Some(dir) = res {
+                assert_eq!(dir.arrow(), input);
+            }
+        }
+    }
+
+    #[test]
+    fn turn_test() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert!(dir.is_orthogonal(&dir.turn_left()));
+            assert!(!dir.is_orthogonal(&dir.reverse()));
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Minimal animated GIF encoder, pixel data is stored as uncompressed LZW
+//! literals and frames after the first only cover what changed
+
+// LZW codes of 8-bit pixels
+const MIN_CODE_SIZE: u8 = 8;
+const CLEAR: u16 = 1 << MIN_CODE_SIZE;
+const END: u16 = CLEAR + 1;
+const CODE_BITS: u32 = MIN_CODE_SIZE as u32 + 1;
+// The decoder's table grows with every code, clearing it keeps codes 9 bits wide
+const CODES_PER_CLEAR: usize = (1 << MIN_CODE_SIZE) - 2;
+
+// Largest data sub-block
+const MAX_SUB_BLOCK: usize = 255;
+
+/// Encode `frames` of `width * height` indices into `palette` as a looping GIF
+/// file, showing every frame for `delay` hundredths of a second
+pub fn encode(
+    width: u16,
+    height: u16,
+    palette: &[[u8; 3]],
+    frames: &[Vec<u8>],
+    delay: u16,
+) -> Vec<u8> {
+    debug_assert!(palette.len() <= 256);
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend_from_slice(&width.to_le_bytes());
+    gif.extend_from_slice(&height.to_le_bytes());
+    // Global colour table of 256 entries, 8 bits per primary colour
+    gif.extend_from_slice(&[0xf7, 0, 0]);
+    for idx in 0..256 {
+        gif.extend_from_slice(palette.get(idx).unwrap_or(&[0; 3]));
+    }
+
+    // Loop forever
+    gif.extend_from_slice(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    let mut prev: Option<&Vec<u8>> = None;
+    for frame in frames {
+        debug_assert_eq!(frame.len(), width as usize * height as usize);
+        let (x, y, w, h) = match prev {
+            Some(prev) => changed_rect(width as usize, prev, frame),
+            None => (0, 0, width as usize, height as usize),
+        };
+        prev = Some(frame);
+
+        // Graphic control extension, frames are drawn over the previous ones
+        gif.extend_from_slice(&[0x21, 0xf9, 4, 1 << 2]);
+        gif.extend_from_slice(&delay.to_le_bytes());
+        gif.extend_from_slice(&[0, 0]);
+
+        // Image descriptor without a local colour table
+        gif.push(0x2c);
+        for val in [x, y, w, h] {
+            gif.extend_from_slice(&(val as u16).to_le_bytes());
+        }
+        gif.push(0);
+
+        let pixels = (y..y + h).flat_map(|row| &frame[row * width as usize + x..][..w]);
+        gif.push(MIN_CODE_SIZE);
+        for block in lzw_literals(pixels).chunks(MAX_SUB_BLOCK) {
+            gif.push(block.len() as u8);
+            gif.extend_from_slice(block);
+        }
+        gif.push(0);
+    }
+
+    gif.push(0x3b);
+    gif
+}
+
+// Bounding box (x, y, width, height) of the pixels that differ, a single pixel
+// if none do since every frame needs some image data
+fn changed_rect(width: usize, prev: &[u8], cur: &[u8]) -> (usize, usize, usize, usize) {
+    let mut rect: Option<(usize, usize, usize, usize)> = None;
+
+    for (idx, _) in prev
+        .iter()
+        .zip(cur)
+        .enumerate()
+        .filter(|(_, (a, b))| a != b)
+    {
+        let (x, y) = (idx % width, idx / width);
+        rect = Some(match rect {
+            None => (x, y, x, y),
+            Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+        });
+    }
+
+    match rect {
+        Some((x0, y0, x1, y1)) => (x0, y0, x1 - x0 + 1, y1 - y0 + 1),
+        None => (0, 0, 1, 1),
+    }
+}
+
+// LZW stream of every pixel as a literal code, packed least significant bit first
+fn lzw_literals<'a>(pixels: impl IntoIterator<Item = &'a u8>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let (mut acc, mut bits) = (0u32, 0u32);
+    let mut push = |code: u16| {
+        acc |= (code as u32) << bits;
+        bits += CODE_BITS;
+        while bits >= 8 {
+            bytes.push(acc as u8);
+            acc >>= 8;
+            bits -= 8;
+        }
+    };
+
+    push(CLEAR);
+    for (idx, pixel) in pixels.into_iter().enumerate() {
+        if idx > 0 && idx % CODES_PER_CLEAR == 0 {
+            push(CLEAR);
+        }
+        push(*pixel as u16);
+    }
+    push(END);
+
+    if bits > 0 {
+        bytes.push(acc as u8);
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lzw_literals_test() {
+        // 9-bit codes 256, 1, 2 and 257
+        assert_eq!(lzw_literals(&[1, 2]), [0x00, 0x03, 0x08, 0x08, 0x08]);
+
+        // A clear code is added after every CODES_PER_CLEAR pixels
+        let pixels = vec![0; CODES_PER_CLEAR * 2];
+        let codes = 1 + CODES_PER_CLEAR * 2 + 1 + 1;
+        let bits = codes * CODE_BITS as usize;
+        assert_eq!(lzw_literals(&pixels).len(), bits.div_ceil(8));
+    }
+
+    #[test]
+    fn changed_rect_test() {
+        let prev = [0, 0, 0, 0, 0, 0];
+        let tests = [
+            ([0, 0, 0, 0, 0, 0], (0, 0, 1, 1)),
+            ([0, 1, 0, 0, 0, 0], (1, 0, 1, 1)),
+            ([1, 0, 0, 0, 0, 1], (0, 0, 3, 2)),
+            ([0, 0, 0, 0, 1, 0], (1, 1, 1, 1)),
+        ];
+
+        for (cur, exp) in tests {
+            assert_eq!(changed_rect(3, &prev, &cur), exp);
+        }
+    }
+
+    #[test]
+    fn encode_test() {
+        let gif = encode(2, 1, &[[255, 0, 0]], &[vec![0, 0], vec![0, 1]], 10);
+
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..10], &[2, 0, 1, 0]);
+        assert_eq!(&gif[13..16], &[255, 0, 0]);
+        assert_eq!(gif.last(), Some(&0x3b));
+
+        // The second frame only covers the changed pixel
+        let descriptors: Vec<_> = gif.windows(10).filter(|w| w[0] == 0x2c).collect();
+        assert_eq!(descriptors[1], &[0x2c, 1, 0, 0, 0, 1, 0, 1, 0, 0]);
+    }
+}
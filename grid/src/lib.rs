@@ -0,0 +1,37 @@
+mod animation;
+mod direction;
+mod gif;
+mod matrix;
+mod maze;
+mod png;
+mod position;
+mod render;
+mod stepper;
+
+pub use animation::Animation;
+pub use direction::Direction;
+pub use matrix::Matrix2D;
+pub use maze::maze;
+pub use position::Position;
+pub use render::{Colour, Colourable, GridImage, Overlay};
+pub use stepper::{Breakpoint, Simulation, Stepper};
+
+/// Single character representation of a grid cell
+pub trait DisplayChar {
+    fn display_char(&self) -> char;
+}
+
+impl DisplayChar for bool {
+    fn display_char(&self) -> char {
+        match self {
+            true => '#',
+            false => '.',
+        }
+    }
+}
+
+impl DisplayChar for char {
+    fn display_char(&self) -> char {
+        *self
+    }
+}
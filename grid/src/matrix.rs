@@ -0,0 +1,297 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{DisplayChar, Position};
+
+/// Dense 2D grid, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix2D<T> {
+    inner: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Matrix2D<T> {
+    /// Create new matrix
+    pub fn new(width: usize, height: usize, default: T) -> Self {
+        Self {
+            inner: vec![default; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Matrix2D<T> {
+    /// Create matrix from a list of rows, fails if the rows have different lengths
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            inner: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    // Get matrix width
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    // Get matrix height
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Check if a position is inside the matrix
+    pub fn contains(&self, pos: Position) -> bool {
+        self.get_index(pos).is_some()
+    }
+
+    /// Get reference to matrix cell
+    pub fn get_ref(&self, pos: Position) -> Option<&T> {
+        let index = self.get_index(pos)?;
+        Some(&self.inner[index])
+    }
+
+    /// Get mutable reference to matrix cell
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        let index = self.get_index(pos)?;
+        Some(&mut self.inner[index])
+    }
+
+    /// Set matrix cell, positions outside the matrix are ignored
+    pub fn set(&mut self, pos: Position, val: T) {
+        if let Some(index) = self.get_index(pos) {
+            self.inner[index] = val;
+        }
+    }
+
+    /// Iterate over rows
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Chunk size can't be 0, an empty matrix just has no rows
+        self.inner.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// Get a single row
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.inner[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the cells of a column, from top to bottom
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.inner[y * self.width + x])
+    }
+
+    /// Iterate over all positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position::new_usize(x, y)))
+    }
+
+    /// Iterate over all cells together with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.inner.iter())
+    }
+
+    /// Iterate over the cells sharing an edge with a position
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        pos.neighbours4()
+            .into_iter()
+            .filter_map(|pos| Some((pos, self.get_ref(pos)?)))
+    }
+
+    /// Iterate over the cells sharing an edge or a corner with a position
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        pos.neighbours8()
+            .into_iter()
+            .filter_map(|pos| Some((pos, self.get_ref(pos)?)))
+    }
+
+    /// Find position of the first cell matching a predicate
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    /// Create new matrix of the same size by applying a function to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Matrix2D<U> {
+        Matrix2D {
+            inner: self.inner.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Get index into inner
+    fn get_index(&self, pos: Position) -> Option<usize> {
+        if !(pos.x < 0
+            || pos.x >= self.width as isize
+            || pos.y < 0
+            || pos.y >= self.height as isize)
+        {
+            Some(pos.y as usize * self.width + pos.x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Copy> Matrix2D<T> {
+    /// Get matrix cell
+    pub fn get(&self, pos: Position) -> Option<T> {
+        let index = self.get_index(pos)?;
+        Some(self.inner[index])
+    }
+}
+
+impl<T: DisplayChar> Matrix2D<T> {
+    /// Render the matrix as text, one line per row
+    pub fn render(&self) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(|c| c.display_char()));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Unchecked access, panics if the position is outside the matrix
+impl<T> Index<Position> for Matrix2D<T> {
+    type Output = T;
+    fn index(&self, pos: Position) -> &T {
+        match self.get_index(pos) {
+            Some(index) => &self.inner[index],
+            None => panic!("position {} outside of matrix", pos),
+        }
+    }
+}
+
+/// Unchecked access, panics if the position is outside the matrix
+impl<T> IndexMut<Position> for Matrix2D<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        match self.get_index(pos) {
+            Some(index) => &mut self.inner[index],
+            None => panic!("position {} outside of matrix", pos),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_matrix() -> Matrix2D<u8> {
+        Matrix2D::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn from_rows_test() {
+        let m = test_matrix();
+        assert_eq!(m.width(), 3);
+        assert_eq!(m.height(), 2);
+
+        assert_eq!(Matrix2D::from_rows(vec![vec![1, 2], vec![3]]), None);
+
+        let empty: Matrix2D<u8> = Matrix2D::from_rows(vec![]).unwrap();
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.positions().count(), 0);
+    }
+
+    #[test]
+    fn get_test() {
+        let tests = [
+            (Position::new(0, 0), Some(1)),
+            (Position::new(2, 1), Some(6)),
+            (Position::new(3, 0), None),
+            (Position::new(0, 2), None),
+            (Position::new(-1, 0), None),
+        ];
+
+        let m = test_matrix();
+        for (pos, exp) in tests {
+            assert_eq!(m.get(pos), exp);
+            assert_eq!(m.get_ref(pos).copied(), exp);
+            assert_eq!(m.contains(pos), exp.is_some());
+        }
+    }
+
+    #[test]
+    fn set_test() {
+        let mut m = test_matrix();
+        m.set(Position::new(1, 1), 10);
+        m.set(Position::new(5, 5), 20);
+        m[Position::new(0, 1)] += 1;
+        *m.get_mut(Position::new(2, 0)).unwrap() = 0;
+
+        assert_eq!(
+            m,
+            Matrix2D::from_rows(vec![vec![1, 2, 0], vec![5, 10, 6]]).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let m = test_matrix();
+        let _ = m[Position::new(3, 0)];
+    }
+
+    #[test]
+    fn iterators_test() {
+        let m = test_matrix();
+
+        let rows: Vec<&[u8]> = m.rows().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        assert_eq!(m.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(m.row(2), None);
+
+        let col: Vec<u8> = m.column(1).copied().collect();
+        assert_eq!(col, vec![2, 5]);
+        assert_eq!(m.column(3).count(), 0);
+
+        let cells: Vec<(Position, u8)> = m.iter().map(|(pos, c)| (pos, *c)).collect();
+        assert_eq!(cells[4], (Position::new(1, 1), 5));
+        assert_eq!(cells.len(), 6);
+
+        assert_eq!(m.find(|c| *c == 6), Some(Position::new(2, 1)));
+        assert_eq!(m.find(|c| *c == 7), None);
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let m = test_matrix();
+
+        let n4: Vec<u8> = m
+            .neighbours4(Position::new(0, 0))
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(n4, vec![4, 2]);
+
+        let mut n8: Vec<u8> = m
+            .neighbours8(Position::new(1, 0))
+            .map(|(_, c)| *c)
+            .collect();
+        n8.sort();
+        assert_eq!(n8, vec![1, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn map_test() {
+        let m = test_matrix().map(|c| c % 2 == 0);
+        assert_eq!(m.get(Position::new(1, 0)), Some(true));
+        assert_eq!(m.get(Position::new(0, 1)), Some(true));
+        assert_eq!(m.get(Position::new(2, 1)), Some(true));
+        assert_eq!(m.get(Position::new(0, 0)), Some(false));
+    }
+}
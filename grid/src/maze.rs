@@ -0,0 +1,76 @@
+//! Random mazes, to generate puzzle inputs
+
+use common::rng::Rng;
+
+use crate::{Matrix2D, Position};
+
+/// Perfect maze filling a `width` x `height` grid, `true` cells are walls.
+/// Corridors join the cells at odd coordinates, with exactly one path between
+/// any two of them, and the border is always wall
+pub fn maze(width: usize, height: usize, rng: &mut Rng) -> Matrix2D<bool> {
+    let mut walls = Matrix2D::new(width, height, true);
+    if width < 3 || height < 3 {
+        return walls;
+    }
+
+    // Depth-first carving, moving to a random unvisited cell two steps away
+    let start = Position::new(1, 1);
+    walls.set(start, false);
+    let mut stack = vec![start];
+
+    while let Some(&pos) = stack.last() {
+        let next: Vec<Position> = [(2, 0), (-2, 0), (0, 2), (0, -2)]
+            .iter()
+            .map(|(dx, dy)| Position::new(pos.x + dx, pos.y + dy))
+            .filter(|next| {
+                next.x > 0
+                    && next.y > 0
+                    && (next.x as usize) < width - 1
+                    && (next.y as usize) < height - 1
+                    && walls.get(*next) == Some(true)
+            })
+            .collect();
+
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let next = *rng.pick(&next);
+
+        walls.set(
+            Position::new((pos.x + next.x) / 2, (pos.y + next.y) / 2),
+            false,
+        );
+        walls.set(next, false);
+        stack.push(next);
+    }
+
+    walls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maze_test() {
+        for (width, height) in [(3, 3), (9, 5), (10, 8), (31, 31)] {
+            let walls = maze(width, height, &mut Rng::new(1));
+            let open: Vec<Position> = walls
+                .iter()
+                .filter(|(_, wall)| !**wall)
+                .map(|(pos, _)| pos)
+                .collect();
+
+            // Every odd cell is open, and the corridors form a tree
+            let cells = (width - 1) / 2 * ((height - 1) / 2);
+            let odd = open.iter().filter(|pos| pos.x % 2 == 1 && pos.y % 2 == 1);
+            assert_eq!(odd.count(), cells, "{}x{}", width, height);
+            assert_eq!(open.len(), cells * 2 - 1, "{}x{}", width, height);
+            assert!(open.iter().all(|pos| {
+                (1..width as isize - 1).contains(&pos.x)
+                    && (1..height as isize - 1).contains(&pos.y)
+            }));
+        }
+    }
+}
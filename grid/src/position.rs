@@ -0,0 +1,185 @@
+use core::fmt;
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::Direction;
+
+// Offsets of the 8-neighbourhood, clockwise from the top-left corner
+const NEIGHBOURS8_DELTAS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Position {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Position {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn new_usize(x: usize, y: usize) -> Self {
+        Self {
+            x: x as isize,
+            y: y as isize,
+        }
+    }
+
+    pub fn moved(&self, m: Direction) -> Self {
+        let (dx, dy) = m.get_deltas();
+        Position::new(self.x + dx, self.y + dy)
+    }
+
+    pub fn apply_move(&mut self, m: Direction) {
+        let (dx, dy) = m.get_deltas();
+        self.x += dx;
+        self.y += dy;
+    }
+
+    /// Manhattan distance between two positions
+    pub fn manhattan_distance(&self, other: Position) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Positions sharing an edge with this one, in `Direction::ALL` order
+    pub fn neighbours4(&self) -> [Position; 4] {
+        Direction::ALL.map(|dir| self.moved(dir))
+    }
+
+    /// Positions sharing an edge or a corner with this one
+    pub fn neighbours8(&self) -> [Position; 8] {
+        NEIGHBOURS8_DELTAS.map(|(dx, dy)| Position::new(self.x + dx, self.y + dy))
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Position {
+    type Output = Position;
+    fn add(self, rhs: Position) -> Position {
+        Position::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Position {
+    type Output = Position;
+    fn sub(self, rhs: Position) -> Position {
+        Position::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl AddAssign for Position {
+    fn add_assign(&mut self, rhs: Position) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl SubAssign for Position {
+    fn sub_assign(&mut self, rhs: Position) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl Add<Direction> for Position {
+    type Output = Position;
+    fn add(self, rhs: Direction) -> Position {
+        self.moved(rhs)
+    }
+}
+
+impl AddAssign<Direction> for Position {
+    fn add_assign(&mut self, rhs: Direction) {
+        self.apply_move(rhs);
+    }
+}
+
+impl Mul<isize> for Position {
+    type Output = Position;
+    fn mul(self, rhs: isize) -> Position {
+        Position::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Position {
+    type Output = Position;
+    fn neg(self) -> Position {
+        Position::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_test() {
+        let a = Position::new(3, -2);
+        let b = Position::new(-1, 5);
+
+        assert_eq!(a + b, Position::new(2, 3));
+        assert_eq!(a - b, Position::new(4, -7));
+        assert_eq!(a * 3, Position::new(9, -6));
+        assert_eq!(-a, Position::new(-3, 2));
+        assert_eq!(a + Direction::Up, Position::new(3, -3));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c += Direction::Right;
+        assert_eq!(c, Position::new(0, 5));
+    }
+
+    #[test]
+    fn manhattan_distance_test() {
+        let tests = [
+            ((Position::new(0, 0), Position::new(0, 0)), 0),
+            ((Position::new(1, 2), Position::new(4, 6)), 7),
+            ((Position::new(-3, 2), Position::new(3, -2)), 10),
+        ];
+
+        for ((a, b), exp) in tests {
+            assert_eq!(a.manhattan_distance(b), exp);
+            assert_eq!(b.manhattan_distance(a), exp);
+        }
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let pos = Position::new(1, 1);
+
+        let n4 = pos.neighbours4();
+        assert_eq!(
+            n4,
+            [
+                Position::new(1, 0),
+                Position::new(1, 2),
+                Position::new(0, 1),
+                Position::new(2, 1),
+            ]
+        );
+
+        let n8 = pos.neighbours8();
+        assert_eq!(n8.len(), 8);
+        assert!(n4.iter().all(|n| n8.contains(n)));
+        assert!(n8
+            .iter()
+            .all(|n| n.manhattan_distance(pos) <= 2 && *n != pos));
+    }
+}
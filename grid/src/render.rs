@@ -0,0 +1,375 @@
+use std::fmt::Write;
+
+use common::diag;
+
+use crate::{png, DisplayChar, Matrix2D, Position};
+
+/// RGBA colour, the alpha channel is only used by overlays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Colour {
+    pub const BLACK: Colour = Colour::rgb(0, 0, 0);
+    pub const WHITE: Colour = Colour::rgb(255, 255, 255);
+    pub const WALL: Colour = Colour::rgb(60, 60, 70);
+    pub const FLOOR: Colour = Colour::rgb(235, 235, 225);
+    pub const RED: Colour = Colour::rgb(220, 40, 40);
+    pub const GREEN: Colour = Colour::rgb(40, 170, 60);
+    pub const BLUE: Colour = Colour::rgb(40, 90, 220);
+    pub const ORANGE: Colour = Colour::rgb(245, 150, 20);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    /// Same colour with another opacity
+    pub const fn with_alpha(self, a: u8) -> Self {
+        Self { a, ..self }
+    }
+
+    /// Colour of a cell shown as `c`, walls are dark and floors light
+    pub fn for_char(c: char) -> Self {
+        const PALETTE: [Colour; 6] = [
+            Colour::RED,
+            Colour::GREEN,
+            Colour::BLUE,
+            Colour::ORANGE,
+            Colour::rgb(150, 60, 200),
+            Colour::rgb(30, 170, 180),
+        ];
+
+        match c {
+            '#' => Self::WALL,
+            '.' | ' ' => Self::FLOOR,
+            c => PALETTE[c as usize % PALETTE.len()],
+        }
+    }
+
+    // `#rrggbb` notation
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    // Draw this colour over `below`, according to its opacity
+    fn blend(&self, below: [u8; 3]) -> [u8; 3] {
+        let a = self.a as u32;
+        let mix = |top: u8, below: u8| ((top as u32 * a + below as u32 * (255 - a)) / 255) as u8;
+        [
+            mix(self.r, below[0]),
+            mix(self.g, below[1]),
+            mix(self.b, below[2]),
+        ]
+    }
+}
+
+/// Cells with a colour of their own in rendered images
+pub trait Colourable {
+    fn colour(&self) -> Colour;
+}
+
+impl Colourable for bool {
+    fn colour(&self) -> Colour {
+        Colour::for_char(self.display_char())
+    }
+}
+
+impl Colourable for char {
+    fn colour(&self) -> Colour {
+        Colour::for_char(*self)
+    }
+}
+
+impl Colourable for Colour {
+    fn colour(&self) -> Colour {
+        *self
+    }
+}
+
+/// Drawing on top of the grid cells
+#[derive(Debug, Clone)]
+pub enum Overlay {
+    /// Cells filled with a colour, e.g. a visited set or highlighted cells
+    Cells(Vec<Position>, Colour),
+    /// Line through the centres of consecutive cells
+    Path(Vec<Position>, Colour),
+}
+
+/// Image of a grid, with overlays, that can be saved as SVG or PNG
+#[derive(Debug, Clone)]
+pub struct GridImage {
+    cells: Matrix2D<Colour>,
+    overlays: Vec<Overlay>,
+    cell_size: usize,
+}
+
+impl GridImage {
+    /// Image of a grid of colourable cells
+    pub fn new<T: Colourable>(matrix: &Matrix2D<T>) -> Self {
+        Self {
+            cells: matrix.map(T::colour),
+            overlays: Vec::new(),
+            cell_size: 8,
+        }
+    }
+
+    /// Image of a grid coloured by the character of each cell
+    pub fn from_chars<T: DisplayChar>(matrix: &Matrix2D<T>) -> Self {
+        Self::new(&matrix.map(DisplayChar::display_char))
+    }
+
+    /// Size of a cell side in pixels
+    pub fn cell_size(mut self, cell_size: usize) -> Self {
+        self.cell_size = cell_size.max(1);
+        self
+    }
+
+    /// Draw an overlay above the cells and previous overlays
+    pub fn overlay(mut self, overlay: Overlay) -> Self {
+        self.overlays.push(overlay);
+        self
+    }
+
+    /// Fill cells with a colour
+    pub fn cells(self, cells: impl IntoIterator<Item = Position>, colour: Colour) -> Self {
+        self.overlay(Overlay::Cells(cells.into_iter().collect(), colour))
+    }
+
+    /// Draw a path through cells
+    pub fn path(self, path: impl IntoIterator<Item = Position>, colour: Colour) -> Self {
+        self.overlay(Overlay::Path(path.into_iter().collect(), colour))
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width() * self.cell_size
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height() * self.cell_size
+    }
+
+    // Path line width in pixels
+    fn line_width(&self) -> usize {
+        (self.cell_size / 3).max(1)
+    }
+
+    pub fn to_svg(&self) -> String {
+        let size = self.cell_size;
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" shape-rendering="crispEdges">"#,
+            w = self.width(),
+            h = self.height()
+        );
+        svg.push('\n');
+
+        // Runs of cells of the same colour are drawn as a single rectangle
+        for (y, row) in self.cells.rows().enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    x * size,
+                    y * size,
+                    run.len() * size,
+                    size,
+                    run[0].hex()
+                )
+                .unwrap();
+                x += run.len();
+            }
+        }
+
+        for overlay in &self.overlays {
+            match overlay {
+                Overlay::Cells(cells, colour) => {
+                    writeln!(svg, r#"<g fill="{}"{}>"#, colour.hex(), opacity(colour)).unwrap();
+                    for pos in cells.iter().filter(|pos| self.cells.contains(**pos)) {
+                        writeln!(
+                            svg,
+                            r#"<rect x="{}" y="{}" width="{size}" height="{size}"/>"#,
+                            pos.x as usize * size,
+                            pos.y as usize * size,
+                        )
+                        .unwrap();
+                    }
+                    svg.push_str("</g>\n");
+                }
+                Overlay::Path(path, colour) => {
+                    let points: Vec<String> = path
+                        .iter()
+                        .map(|pos| {
+                            let (x, y) = self.centre(*pos);
+                            format!("{},{}", x, y)
+                        })
+                        .collect();
+                    writeln!(
+                        svg,
+                        r#"<polyline points="{}" fill="none" stroke="{}"{} stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" shape-rendering="auto"/>"#,
+                        points.join(" "),
+                        colour.hex(),
+                        opacity(colour),
+                        self.line_width()
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let (width, height) = (self.width(), self.height());
+        let mut pixels = vec![[0u8; 3]; width * height];
+
+        for (pos, colour) in self.cells.iter() {
+            self.fill_cell(&mut pixels, pos, colour);
+        }
+
+        for overlay in &self.overlays {
+            match overlay {
+                Overlay::Cells(cells, colour) => {
+                    for pos in cells.iter().filter(|pos| self.cells.contains(**pos)) {
+                        self.fill_cell(&mut pixels, *pos, colour);
+                    }
+                }
+                Overlay::Path(path, colour) => {
+                    // Every pixel is drawn once, so translucent lines blend evenly
+                    let mut line = vec![false; width * height];
+                    for segment in path.windows(2) {
+                        self.draw_segment(&mut line, segment[0], segment[1]);
+                    }
+                    if let [pos] = path.as_slice() {
+                        self.draw_segment(&mut line, *pos, *pos);
+                    }
+
+                    for (pixel, _) in pixels.iter_mut().zip(line).filter(|(_, on)| *on) {
+                        *pixel = colour.blend(*pixel);
+                    }
+                }
+            }
+        }
+
+        png::encode(width as u32, height as u32, pixels.as_flattened())
+    }
+
+    // Pixel coordinates of the centre of a cell
+    fn centre(&self, pos: Position) -> (f64, f64) {
+        let size = self.cell_size as f64;
+        ((pos.x as f64 + 0.5) * size, (pos.y as f64 + 0.5) * size)
+    }
+
+    fn fill_cell(&self, pixels: &mut [[u8; 3]], pos: Position, colour: &Colour) {
+        let size = self.cell_size;
+        let (x0, y0) = (pos.x as usize * size, pos.y as usize * size);
+
+        for y in y0..y0 + size {
+            for pixel in &mut pixels[y * self.width() + x0..][..size] {
+                *pixel = colour.blend(*pixel);
+            }
+        }
+    }
+
+    // Mark the pixels of a thick line between the centres of two cells
+    fn draw_segment(&self, line: &mut [bool], from: Position, to: Position) {
+        let (x0, y0) = self.centre(from);
+        let (x1, y1) = self.centre(to);
+        let half = self.line_width() as f64 / 2.0;
+
+        let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil().max(1.0) as usize;
+        for step in 0..=steps {
+            let t = step as f64 / steps as f64;
+            let (x, y) = (x0 + (x1 - x0) * t, y0 + (y1 - y0) * t);
+
+            // Square brush, clipped to the image
+            let xs = (x - half).round().max(0.0) as usize
+                ..((x + half).round() as usize).min(self.width());
+            let ys = (y - half).round().max(0.0) as usize
+                ..((y + half).round() as usize).min(self.height());
+            for py in ys {
+                for px in xs.clone() {
+                    line[py * self.width() + px] = true;
+                }
+            }
+        }
+    }
+}
+
+impl diag::Image for GridImage {
+    fn svg(&self) -> String {
+        self.to_svg()
+    }
+
+    fn png(&self) -> Vec<u8> {
+        self.to_png()
+    }
+}
+
+// SVG opacity attribute of a translucent colour
+fn opacity(colour: &Colour) -> String {
+    if colour.a == 255 {
+        String::new()
+    } else {
+        format!(r#" opacity="{:.2}""#, colour.a as f64 / 255.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> GridImage {
+        let matrix = Matrix2D::from_rows(vec![vec!['#', '#', '.'], vec!['.', '.', '.']]).unwrap();
+        GridImage::from_chars(&matrix).cell_size(2)
+    }
+
+    #[test]
+    fn svg_test() {
+        let svg = image()
+            .cells([Position::new(2, 0)], Colour::RED.with_alpha(128))
+            .path([Position::new(0, 1), Position::new(2, 1)], Colour::BLUE)
+            .to_svg();
+
+        let exp = r##"<svg xmlns="http://www.w3.org/2000/svg" width="6" height="4" viewBox="0 0 6 4" shape-rendering="crispEdges">
+<rect x="0" y="0" width="4" height="2" fill="#3c3c46"/>
+<rect x="4" y="0" width="2" height="2" fill="#ebebe1"/>
+<rect x="0" y="2" width="6" height="2" fill="#ebebe1"/>
+<g fill="#dc2828" opacity="0.50">
+<rect x="4" y="0" width="2" height="2"/>
+</g>
+<polyline points="1,3 5,3" fill="none" stroke="#285adc" stroke-width="1" stroke-linecap="round" stroke-linejoin="round" shape-rendering="auto"/>
+</svg>
+"##;
+        assert_eq!(svg, exp);
+    }
+
+    #[test]
+    fn pixels_test() {
+        let image = image()
+            .cells([Position::new(2, 0)], Colour::WHITE.with_alpha(0))
+            .cells([Position::new(0, 1)], Colour::BLACK);
+        let png = image.to_png();
+
+        // 6x4 image, every row is a filter byte and 6 RGB pixels
+        let raw = &png[8 + 25 + 8 + 2 + 5..][..4 * (1 + 6 * 3)];
+        let pixel = |x: usize, y: usize| &raw[y * 19 + 1 + x * 3..][..3];
+
+        assert_eq!(pixel(0, 0), [60, 60, 70]);
+        assert_eq!(pixel(5, 1), [235, 235, 225]);
+        assert_eq!(pixel(1, 3), [0, 0, 0]);
+        assert_eq!(pixel(2, 3), [235, 235, 225]);
+    }
+
+    #[test]
+    fn blend_test() {
+        assert_eq!(Colour::RED.blend([0, 0, 0]), [220, 40, 40]);
+        assert_eq!(Colour::WHITE.with_alpha(0).blend([1, 2, 3]), [1, 2, 3]);
+        assert_eq!(Colour::WHITE.with_alpha(51).blend([0, 0, 0]), [51, 51, 51]);
+    }
+}
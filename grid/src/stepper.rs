@@ -0,0 +1,391 @@
+//! Stepping forward and back through grid simulations, with breakpoints
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{Matrix2D, Position};
+
+/// Simulation on a grid that can be stepped through
+pub trait Simulation {
+    /// Current state, one character per cell
+    fn grid(&self) -> Matrix2D<char>;
+
+    /// Position of the guard, robot... moving around, if any
+    fn actor(&self) -> Option<Position>;
+
+    /// Advance one step, returns what was done or `None` once the simulation is
+    /// over
+    fn step(&mut self) -> Option<String>;
+}
+
+impl<S: Simulation + ?Sized> Simulation for Box<S> {
+    fn grid(&self) -> Matrix2D<char> {
+        (**self).grid()
+    }
+
+    fn actor(&self) -> Option<Position> {
+        (**self).actor()
+    }
+
+    fn step(&mut self) -> Option<String> {
+        (**self).step()
+    }
+}
+
+/// Condition to stop at when running forward
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Step changing the number of cells showing a character
+    CountChanged(char),
+    /// Actor moving to a cell it visited before
+    Revisit,
+    /// Step changing a cell
+    Cell(Position),
+    /// Step whose description contains a text
+    Label(String),
+}
+
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid breakpoint: `{}`", s);
+        let (kind, arg) = s.trim().split_once(' ').unwrap_or((s.trim(), ""));
+        let arg = arg.trim();
+
+        match kind {
+            "count" => {
+                let mut chars = arg.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(Self::CountChanged(c)),
+                    _ => Err(err()),
+                }
+            }
+            "revisit" if arg.is_empty() => Ok(Self::Revisit),
+            "cell" => {
+                let (x, y) = arg.split_once(',').ok_or_else(err)?;
+                let x = x.trim().parse().map_err(|_| err())?;
+                let y = y.trim().parse().map_err(|_| err())?;
+                Ok(Self::Cell(Position::new(x, y)))
+            }
+            "label" if !arg.is_empty() => Ok(Self::Label(arg.to_owned())),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CountChanged(c) => write!(f, "count {}", c),
+            Self::Revisit => write!(f, "revisit"),
+            Self::Cell(pos) => write!(f, "cell {}", pos),
+            Self::Label(text) => write!(f, "label {}", text),
+        }
+    }
+}
+
+// Step taken, with the changes needed to undo or redo it
+#[derive(Debug, Clone)]
+struct Step {
+    label: String,
+    // Changed cells with their old and new characters
+    changes: Vec<(Position, char, char)>,
+    // Actor after the step
+    actor: Option<Position>,
+}
+
+/// Simulation that can be stepped back, every step is recorded as the cells it
+/// changed
+pub struct Stepper<S> {
+    sim: S,
+    // Grid at the current step
+    grid: Matrix2D<char>,
+    steps: Vec<Step>,
+    // Current step, 0 is the initial state
+    cur: usize,
+    finished: bool,
+    // First step every actor position was reached at
+    visits: HashMap<Position, usize>,
+}
+
+impl<S: Simulation> Stepper<S> {
+    pub fn new(sim: S) -> Self {
+        let actor = sim.actor();
+        let start = Step {
+            label: "start".to_owned(),
+            changes: Vec::new(),
+            actor,
+        };
+
+        Self {
+            grid: sim.grid(),
+            sim,
+            steps: vec![start],
+            cur: 0,
+            finished: false,
+            visits: actor.map(|pos| (pos, 0)).into_iter().collect(),
+        }
+    }
+
+    /// Index of the current step, 0 is the initial state
+    pub fn index(&self) -> usize {
+        self.cur
+    }
+
+    /// Number of steps simulated so far, `None` while there may be more
+    pub fn total(&self) -> Option<usize> {
+        self.finished.then_some(self.steps.len() - 1)
+    }
+
+    pub fn grid(&self) -> &Matrix2D<char> {
+        &self.grid
+    }
+
+    /// What the current step did
+    pub fn label(&self) -> &str {
+        &self.steps[self.cur].label
+    }
+
+    pub fn actor(&self) -> Option<Position> {
+        self.steps[self.cur].actor
+    }
+
+    /// Cells changed by the current step
+    pub fn changed_cells(&self) -> impl Iterator<Item = Position> + '_ {
+        self.steps[self.cur].changes.iter().map(|(pos, _, _)| *pos)
+    }
+
+    /// Go to the next step, returns false at the end of the simulation
+    pub fn forward(&mut self) -> bool {
+        if self.cur + 1 == self.steps.len() && !self.simulate() {
+            return false;
+        }
+
+        self.cur += 1;
+        for (pos, _, new) in &self.steps[self.cur].changes {
+            self.grid.set(*pos, *new);
+        }
+        true
+    }
+
+    /// Go to the previous step, returns false at the initial state
+    pub fn back(&mut self) -> bool {
+        if self.cur == 0 {
+            return false;
+        }
+
+        for (pos, old, _) in &self.steps[self.cur].changes {
+            self.grid.set(*pos, *old);
+        }
+        self.cur -= 1;
+        true
+    }
+
+    /// Go to step `idx`, or as close as possible, returns true if it was reached
+    pub fn goto(&mut self, idx: usize) -> bool {
+        while self.cur > idx {
+            self.back();
+        }
+        while self.cur < idx {
+            if !self.forward() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Run forward until a step hits one of `breakpoints`, returns its index or
+    /// `None` if the simulation ended first
+    pub fn run_until(&mut self, breakpoints: &[Breakpoint]) -> Option<usize> {
+        while self.forward() {
+            if let Some(idx) = breakpoints.iter().position(|bp| self.is_hit(bp)) {
+                return Some(idx);
+            }
+        }
+        None
+    }
+
+    /// Whether the current step hits a breakpoint
+    pub fn is_hit(&self, breakpoint: &Breakpoint) -> bool {
+        let step = &self.steps[self.cur];
+
+        match breakpoint {
+            Breakpoint::CountChanged(c) => {
+                let change: isize = step
+                    .changes
+                    .iter()
+                    .map(|(_, old, new)| (new == c) as isize - (old == c) as isize)
+                    .sum();
+                change != 0
+            }
+            Breakpoint::Revisit => {
+                let prev = self
+                    .cur
+                    .checked_sub(1)
+                    .and_then(|idx| self.steps[idx].actor);
+                match step.actor {
+                    Some(pos) if Some(pos) != prev => self.visits[&pos] < self.cur,
+                    _ => false,
+                }
+            }
+            Breakpoint::Cell(cell) => step.changes.iter().any(|(pos, _, _)| pos == cell),
+            Breakpoint::Label(text) => step.label.contains(text.as_str()),
+        }
+    }
+
+    // Advance the simulation and record the step, returns false if it's over
+    fn simulate(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
+        let Some(label) = self.sim.step() else {
+            self.finished = true;
+            return false;
+        };
+
+        // Only called at the last step, so the grid is the previous state
+        let grid = self.sim.grid();
+        let changes = grid
+            .iter()
+            .filter_map(|(pos, new)| {
+                let old = self.grid.get(pos).unwrap_or(' ');
+                (old != *new).then_some((pos, old, *new))
+            })
+            .collect();
+
+        let actor = self.sim.actor();
+        if let Some(pos) = actor {
+            self.visits.entry(pos).or_insert(self.steps.len());
+        }
+        self.steps.push(Step {
+            label,
+            changes,
+            actor,
+        });
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Robot bouncing between the ends of a corridor, leaving a trail
+    struct Corridor {
+        grid: Matrix2D<char>,
+        pos: Position,
+        dx: isize,
+        steps: usize,
+    }
+
+    impl Simulation for Corridor {
+        fn grid(&self) -> Matrix2D<char> {
+            let mut grid = self.grid.clone();
+            grid.set(self.pos, '@');
+            grid
+        }
+
+        fn actor(&self) -> Option<Position> {
+            Some(self.pos)
+        }
+
+        fn step(&mut self) -> Option<String> {
+            if self.steps == 0 {
+                return None;
+            }
+            self.steps -= 1;
+
+            if !self.grid.contains(Position::new(self.pos.x + self.dx, 0)) {
+                self.dx = -self.dx;
+            }
+            self.grid.set(self.pos, 'x');
+            self.pos.x += self.dx;
+            Some(format!("to {}", self.pos.x))
+        }
+    }
+
+    fn stepper(steps: usize) -> Stepper<Corridor> {
+        Stepper::new(Corridor {
+            grid: Matrix2D::new(3, 1, '.'),
+            pos: Position::new(0, 0),
+            dx: 1,
+            steps,
+        })
+    }
+
+    #[test]
+    fn forward_back_test() {
+        let mut stepper = stepper(3);
+        assert_eq!(stepper.grid().render(), "@..\n");
+        assert_eq!(stepper.label(), "start");
+
+        assert!(stepper.forward());
+        assert_eq!(stepper.grid().render(), "x@.\n");
+        assert_eq!(stepper.label(), "to 1");
+        assert_eq!(stepper.changed_cells().count(), 2);
+
+        assert!(stepper.goto(3));
+        assert_eq!(stepper.grid().render(), "x@x\n");
+        assert!(!stepper.forward());
+        assert_eq!(stepper.total(), Some(3));
+
+        assert!(stepper.back());
+        assert!(stepper.back());
+        assert_eq!(stepper.grid().render(), "x@.\n");
+        assert_eq!(stepper.actor(), Some(Position::new(1, 0)));
+        assert!(stepper.goto(0));
+        assert!(!stepper.back());
+        assert_eq!(stepper.grid().render(), "@..\n");
+
+        // Steps are replayed once simulated
+        assert!(!stepper.goto(5));
+        assert_eq!(stepper.index(), 3);
+    }
+
+    #[test]
+    fn breakpoints_test() {
+        let tests = [
+            (vec![Breakpoint::Revisit], Some((0, 3))),
+            (vec![Breakpoint::Cell(Position::new(2, 0))], Some((0, 2))),
+            (vec![Breakpoint::Label("to 0".to_owned())], Some((0, 4))),
+            (
+                vec![Breakpoint::CountChanged('.'), Breakpoint::Revisit],
+                Some((0, 1)),
+            ),
+            (vec![Breakpoint::CountChanged('@')], None),
+        ];
+
+        for (breakpoints, exp) in tests {
+            let mut stepper = stepper(5);
+            let res = stepper
+                .run_until(&breakpoints)
+                .map(|idx| (idx, stepper.index()));
+            assert_eq!(res, exp, "{:?}", breakpoints);
+        }
+    }
+
+    #[test]
+    fn parse_breakpoint_test() {
+        let tests = [
+            ("count O", Ok(Breakpoint::CountChanged('O'))),
+            ("revisit", Ok(Breakpoint::Revisit)),
+            ("cell 3, 4", Ok(Breakpoint::Cell(Position::new(3, 4)))),
+            ("label move ^", Ok(Breakpoint::Label("move ^".to_owned()))),
+            ("count OO", Err("invalid breakpoint: `count OO`".to_owned())),
+            ("cell 3", Err("invalid breakpoint: `cell 3`".to_owned())),
+            ("jump", Err("invalid breakpoint: `jump`".to_owned())),
+        ];
+
+        for (input, exp) in tests {
+            let res = input.parse::<Breakpoint>();
+            assert_eq!(res, exp);
+            if let Ok(bp) = res {
+                assert_eq!(bp.to_string().parse(), Ok(bp));
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// JS wrapper of the solvers compiled to WebAssembly, no bindings generator
+// needed:
+//
+//   const aoc = await load(await (await fetch("aoc_wasm.wasm")).arrayBuffer());
+//   aoc.solve(5, 1, input); // "143"
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+// Instantiate the module from its bytes, returns an object with `solve`
+export async function load(bytes) {
+  const { instance } = await WebAssembly.instantiate(bytes);
+  const exports = instance.exports;
+
+  // Copy bytes into a buffer of the module
+  const write = (buffer, data) => {
+    const ptr = buffer(data.length);
+    new Uint8Array(exports.memory.buffer, ptr, data.length).set(data);
+  };
+
+  // Answer of a day's part as a string, throws the solver's error. `params`
+  // overrides puzzle parameters, e.g. `{ width: 11, height: 7 }`
+  const solve = (day, part, input, params = {}) => {
+    const lines = Object.entries(params).map(([name, value]) => `${name}=${value}\n`);
+    write(exports.input_buffer, encoder.encode(input));
+    write(exports.params_buffer, encoder.encode(lines.join("")));
+
+    let status;
+    try {
+      status = exports.run(day, part);
+    } catch (e) {
+      throw new Error(`day ${day} part ${part} panicked: ${e.message}`);
+    }
+
+    const output = new Uint8Array(exports.memory.buffer, exports.output_ptr(), exports.output_len());
+    const text = decoder.decode(output);
+    if (status !== 0) {
+      throw new Error(text);
+    }
+    return text;
+  };
+
+  return { solve };
+}
@@ -0,0 +1,149 @@
+//! The solvers compiled to WebAssembly, `aoc.js` wraps the exports in a
+//! `solve(day, part, input)` function callable from JS
+//!
+//! JS writes the input and parameters into buffers of the module's memory,
+//! calls `run`, then reads the answer or error back from the output buffer.
+
+use std::{cell::RefCell, error::Error};
+
+use aoc::solution;
+use common::Params;
+
+thread_local! {
+    // Written by JS before running a part
+    static INPUT: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    static PARAMS: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    // Answer or error of the last run
+    static OUTPUT: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Solve a day's part, parameters are `name=value` lines
+pub fn solve(day: u8, part: u8, input: &[u8], params: &str) -> Result<String, Box<dyn Error>> {
+    let mut solution = solution(day).ok_or_else(|| format!("day {} is not solved", day))?;
+
+    let mut parsed = Params::new();
+    for line in params
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        match line.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => {
+                parsed.insert(name.trim(), value.trim())
+            }
+            _ => {
+                return Err(format!("invalid parameter: `{}`, expected `NAME=VALUE`", line).into())
+            }
+        }
+    }
+    solution.configure(&parsed)?;
+
+    let input = solution.parse(&mut &input[..])?;
+    solution.part(part, &input)
+}
+
+/// Resize the input buffer to `len` bytes for JS to fill
+#[no_mangle]
+pub extern "C" fn input_buffer(len: usize) -> *mut u8 {
+    INPUT.with_borrow_mut(|input| resize(input, len))
+}
+
+/// Resize the parameters buffer to `len` bytes for JS to fill
+#[no_mangle]
+pub extern "C" fn params_buffer(len: usize) -> *mut u8 {
+    PARAMS.with_borrow_mut(|params| resize(params, len))
+}
+
+/// Solve a part of the input buffer, returns 0 if the output is the answer
+/// and 1 if it's an error
+#[no_mangle]
+pub extern "C" fn run(day: u32, part: u32) -> u32 {
+    // Nothing stays borrowed while solving, so the module can still be used
+    // after a solver panicked and aborted
+    let input = INPUT.take();
+    let params = PARAMS.take();
+    let res = String::from_utf8(params)
+        .map_err(|_| "parameters are not valid UTF-8".into())
+        .and_then(|params| {
+            let day = u8::try_from(day).map_err(|_| format!("day {} is not solved", day))?;
+            let part = u8::try_from(part).map_err(|_| format!("invalid part: {}", part))?;
+            solve(day, part, &input, &params)
+        });
+
+    let (output, status) = match res {
+        Ok(answer) => (answer, 0),
+        Err(e) => (e.to_string(), 1),
+    };
+    OUTPUT.set(output);
+    status
+}
+
+#[no_mangle]
+pub extern "C" fn output_ptr() -> *const u8 {
+    OUTPUT.with_borrow(|output| output.as_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn output_len() -> usize {
+    OUTPUT.with_borrow(|output| output.len())
+}
+
+fn resize(buf: &mut Vec<u8>, len: usize) -> *mut u8 {
+    buf.clear();
+    buf.resize(len, 0);
+    buf.as_mut_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY1: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    const DAY14: &str = "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2\np=2,0 v=2,-1\n\
+                         p=0,0 v=1,3\np=3,0 v=-2,-2\np=7,6 v=-1,-3\np=3,0 v=-1,-2\n\
+                         p=9,3 v=2,3\np=7,3 v=-1,2\np=2,4 v=2,-3\np=9,5 v=-3,-3\n";
+
+    #[test]
+    fn solve_test() {
+        let tests = [
+            ((1, 1, DAY1, ""), Ok("11")),
+            ((1, 2, DAY1, ""), Ok("31")),
+            ((14, 1, DAY14, "width=11\nheight=7\n"), Ok("12")),
+            ((1, 3, DAY1, ""), Err("invalid part: 3")),
+            ((25, 1, DAY1, ""), Err("day 25 is not solved")),
+            (
+                (14, 1, DAY14, "width"),
+                Err("invalid parameter: `width`, expected `NAME=VALUE`"),
+            ),
+        ];
+
+        for ((day, part, input, params), exp) in tests {
+            let res = solve(day, part, input.as_bytes(), params).map_err(|e| e.to_string());
+            assert_eq!(res, exp.map(str::to_owned).map_err(str::to_owned));
+        }
+    }
+
+    #[test]
+    fn run_test() {
+        // Same steps as aoc.js, through the buffers
+        let write = |buf: *mut u8, data: &[u8]| {
+            // SAFETY: the buffer was just resized to the data's length
+            unsafe { buf.copy_from_nonoverlapping(data.as_ptr(), data.len()) }
+        };
+        let output = || {
+            // SAFETY: the output isn't changed until the next run
+            let bytes = unsafe { std::slice::from_raw_parts(output_ptr(), output_len()) };
+            String::from_utf8(bytes.to_vec()).unwrap()
+        };
+
+        write(input_buffer(DAY14.len()), DAY14.as_bytes());
+        write(params_buffer(17), b"width=11\nheight=7");
+        assert_eq!(run(14, 1), 0);
+        assert_eq!(output(), "12");
+
+        write(input_buffer(DAY14.len()), DAY14.as_bytes());
+        write(params_buffer(8), b"width=-1");
+        assert_eq!(run(14, 1), 1);
+        assert_eq!(output(), "board size must be positive");
+    }
+}
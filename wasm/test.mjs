@@ -0,0 +1,73 @@
+// Check the WebAssembly build against every fixture, headless with node:
+//
+//   cargo build --release --target wasm32-unknown-unknown -p aoc-wasm
+//   node wasm/test.mjs
+
+import { readdirSync, readFileSync } from "node:fs";
+import { dirname, join } from "node:path";
+import { fileURLToPath } from "node:url";
+
+import { load } from "./aoc.js";
+
+const root = join(dirname(fileURLToPath(import.meta.url)), "..");
+const wasm = process.argv[2] ?? join(root, "target/wasm32-unknown-unknown/release/aoc_wasm.wasm");
+const aoc = await load(readFileSync(wasm));
+
+// Same format as the fixtures of the runner: parameters, `part1` and `part2`
+const parseAnswers = (text) => {
+  const params = {};
+  const answers = {};
+  for (const line of text.split("\n").map((line) => line.trim())) {
+    if (line === "" || line.startsWith("#")) {
+      continue;
+    }
+    const [name, value] = line.split("=").map((word) => word.trim());
+    if (name === "part1" || name === "part2") {
+      answers[name.at(-1)] = value;
+    } else {
+      params[name] = value;
+    }
+  }
+  return { params, answers };
+};
+
+let checked = 0;
+const failures = [];
+const fixtures = join(root, "fixtures");
+for (const dir of readdirSync(fixtures).sort()) {
+  const day = Number(dir.replace("day", ""));
+  for (const file of readdirSync(join(fixtures, dir)).filter((file) => file.endsWith(".txt"))) {
+    const input = readFileSync(join(fixtures, dir, file), "utf8");
+    const answersPath = join(fixtures, dir, file.replace(/\.txt$/, ".answers"));
+    const { params, answers } = parseAnswers(readFileSync(answersPath, "utf8"));
+
+    for (const [part, exp] of Object.entries(answers)) {
+      let res;
+      try {
+        res = aoc.solve(day, Number(part), input, params);
+      } catch (e) {
+        res = `error: ${e.message}`;
+      }
+      checked += 1;
+      if (res !== exp) {
+        failures.push(`${dir}/${file} part ${part}: got ${res}, expected ${exp}`);
+      }
+    }
+  }
+}
+
+// Errors come back as exceptions
+try {
+  aoc.solve(25, 1, "");
+  failures.push("day 25 didn't fail");
+} catch (e) {
+  if (e.message !== "day 25 is not solved") {
+    failures.push(`day 25: unexpected error: ${e.message}`);
+  }
+}
+
+for (const failure of failures) {
+  console.error(failure);
+}
+console.log(`${checked - failures.length}/${checked} fixture answers match`);
+process.exit(failures.length === 0 ? 0 : 1);